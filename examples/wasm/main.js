@@ -0,0 +1,29 @@
+import init, { evaluate_expression, parse_expression_to_ast } from "./pkg/findit_wasm.js";
+
+const output = document.getElementById("output");
+
+function show(action) {
+    try {
+        output.className = "";
+        output.textContent = JSON.stringify(action(), null, 2);
+    } catch (error) {
+        output.className = "error";
+        output.textContent = error.message ?? String(error);
+    }
+}
+
+await init();
+
+document.getElementById("evaluate").addEventListener("click", () =>
+    show(() =>
+        evaluate_expression(
+            document.getElementById("expression").value,
+            document.getElementById("path").value,
+            document.getElementById("metadata").value,
+        ),
+    ),
+);
+
+document.getElementById("ast").addEventListener("click", () =>
+    show(() => parse_expression_to_ast(document.getElementById("expression").value)),
+);
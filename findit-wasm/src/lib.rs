@@ -0,0 +1,105 @@
+#![deny(warnings)]
+
+//! JavaScript bindings for the findit expression language.
+//!
+//! There is no file system in a browser, so the file is described by its path and a JSON object
+//! with its metadata (see `FileMetadata::from_json`) instead of being read from the disk.
+
+use std::path::Path;
+
+use findit_cli::{FileMetadata, FindItError, evaluate, expression_ast};
+use wasm_bindgen::prelude::*;
+
+/// Evaluate `expr` for the file in `path` with the metadata in `metadata_json`, and return the
+/// value as JSON.
+///
+/// # Errors
+///
+/// Will return `Err` if the expression or the metadata are invalid.
+pub fn evaluate_to_json(
+    expr: &str,
+    path: &str,
+    metadata_json: &str,
+) -> Result<String, FindItError> {
+    let metadata = FileMetadata::from_json(metadata_json)?;
+    let value = evaluate(expr, Path::new(path), metadata)?;
+
+    serde_json::to_string(&value).map_err(|err| FindItError::BadExpression(err.to_string()))
+}
+
+fn to_js(json: Result<String, FindItError>) -> Result<JsValue, JsError> {
+    let json = json.map_err(|err| JsError::new(&err.to_string()))?;
+    js_sys::JSON::parse(&json).map_err(|_| JsError::new("Can not parse the result"))
+}
+
+/// Evaluate an expression for one file, given its metadata as a JSON string, like:
+/// `evaluate_expression("size > 10", "src/main.rs", '{"size": 12}')`.
+///
+/// Throws an error if the expression or the metadata are invalid.
+#[wasm_bindgen]
+pub fn evaluate_expression(
+    expr: &str,
+    path: &str,
+    metadata_json: &str,
+) -> Result<JsValue, JsError> {
+    to_js(evaluate_to_json(expr, path, metadata_json))
+}
+
+/// The syntax tree of an expression, for tools that need to understand expressions.
+///
+/// Throws an error if the expression is invalid.
+#[wasm_bindgen]
+pub fn parse_expression_to_ast(expr: &str) -> Result<JsValue, JsError> {
+    to_js(expression_ast(expr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluate_to_json_of_a_filter() -> Result<(), FindItError> {
+        let json = evaluate_to_json(
+            "size > 10 AND extension = \"rs\"",
+            "src/main.rs",
+            r#"{"size": 12}"#,
+        )?;
+
+        assert_eq!(json, "true");
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_to_json_of_a_class() -> Result<(), FindItError> {
+        let json = evaluate_to_json(
+            "{:name name, :lines content.lines().length()}",
+            "notes/todo.txt",
+            r#"{"content": "one\ntwo\n"}"#,
+        )?;
+
+        assert_eq!(json, r#"{"name":"todo.txt","lines":2}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_to_json_of_a_missing_value() -> Result<(), FindItError> {
+        let json = evaluate_to_json("owner", "a.txt", "{}")?;
+
+        assert_eq!(json, "null");
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_to_json_with_bad_metadata() {
+        let err = evaluate_to_json("size", "a.txt", r#"{"size": "big"}"#).err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(_))));
+    }
+
+    #[test]
+    fn evaluate_to_json_with_bad_expression() {
+        let err = evaluate_to_json("size >", "a.txt", "{}").err();
+
+        assert!(err.is_some());
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError},
+};
+
+use crate::parser::{
+    ast::expression::Expression, include::includes_read, parse_expression,
+    parser_error::ContextualParserError,
+};
+
+/// The number of expressions the cache keeps. Once it is full, the expression that was not
+/// used for the longest time is dropped.
+const CAPACITY: usize = 256;
+
+#[derive(Default)]
+struct Entries {
+    expressions: HashMap<String, (Arc<Expression>, u64)>,
+    /// Increases with every use, to know which expression was used last.
+    clock: u64,
+}
+impl Entries {
+    fn get(&mut self, source: &str) -> Option<Arc<Expression>> {
+        self.clock += 1;
+        let (expression, last_used) = self.expressions.get_mut(source)?;
+        *last_used = self.clock;
+        Some(expression.clone())
+    }
+
+    fn insert(&mut self, source: &str, expression: Arc<Expression>, capacity: usize) {
+        if self.expressions.len() >= capacity
+            && !self.expressions.contains_key(source)
+            && let Some(oldest) = self
+                .expressions
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(source, _)| source.clone())
+        {
+            self.expressions.remove(&oldest);
+        }
+        self.clock += 1;
+        self.expressions
+            .insert(source.to_string(), (expression, self.clock));
+    }
+}
+
+/// Keeps the parsed AST of the expressions that were read, so reading the same
+/// expression again (for example, when `run` is called in a loop) will only build it.
+pub(crate) struct ExpressionCache {
+    entries: Mutex<Entries>,
+    capacity: usize,
+}
+impl Default for ExpressionCache {
+    fn default() -> Self {
+        Self::with_capacity(CAPACITY)
+    }
+}
+
+impl ExpressionCache {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::default(),
+            capacity,
+        }
+    }
+
+    pub(crate) fn get_or_parse(
+        &self,
+        source: &str,
+    ) -> Result<Arc<Expression>, ContextualParserError> {
+        if let Some(expression) = self.lock().get(source) {
+            return Ok(expression);
+        }
+        let includes = includes_read();
+        let expression = Arc::new(parse_expression(source)?);
+        // The AST of an expression with an `INCLUDE` depends on the included files.
+        if includes_read() == includes {
+            self.lock()
+                .insert(source, expression.clone(), self.capacity);
+        }
+        Ok(expression)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub(crate) fn expression_cache() -> &'static ExpressionCache {
+    static CACHE: OnceLock<ExpressionCache> = OnceLock::new();
+    CACHE.get_or_init(ExpressionCache::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn same_source_is_parsed_once() {
+        let cache = ExpressionCache::default();
+
+        let first = cache.get_or_parse("size > 10").unwrap();
+        let second = cache.get_or_parse("size > 10").unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.lock().expressions.len(), 1);
+    }
+
+    #[test]
+    fn different_sources_are_cached_separately() {
+        let cache = ExpressionCache::default();
+
+        let first = cache.get_or_parse("size > 10").unwrap();
+        let second = cache.get_or_parse("size > 11").unwrap();
+
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.lock().expressions.len(), 2);
+    }
+
+    #[test]
+    fn least_recently_used_is_dropped_when_full() {
+        let cache = ExpressionCache::with_capacity(2);
+
+        let first = cache.get_or_parse("size > 1").unwrap();
+        cache.get_or_parse("size > 2").unwrap();
+        cache.get_or_parse("size > 1").unwrap();
+        cache.get_or_parse("size > 3").unwrap();
+
+        let entries = cache.lock();
+        assert_eq!(entries.expressions.len(), 2);
+        assert!(entries.expressions.contains_key("size > 1"));
+        assert!(!entries.expressions.contains_key("size > 2"));
+        assert!(entries.expressions.contains_key("size > 3"));
+        drop(entries);
+        assert!(Arc::ptr_eq(
+            &first,
+            &cache.get_or_parse("size > 1").unwrap()
+        ));
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let cache = ExpressionCache::default();
+
+        assert!(cache.get_or_parse("size >").is_err());
+        assert!(cache.get_or_parse("size >").is_err());
+        assert!(cache.lock().expressions.is_empty());
+    }
+}
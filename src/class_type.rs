@@ -1,4 +1,4 @@
-use std::{fmt::Display, rc::Rc};
+use std::{cmp::Ordering, fmt::Display, hash::Hash, sync::Arc};
 
 use ordermap::OrderMap;
 
@@ -8,8 +8,11 @@ use crate::{
 };
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
-pub(crate) struct ClassType {
-    details: Rc<OrderMap<String, ValueType>>,
+pub struct ClassType {
+    details: Arc<OrderMap<String, ValueType>>,
+    /// The indices of the fields, sorted by their names, to compare classes regardless of the
+    /// fields order.
+    sorted: Arc<[usize]>,
 }
 
 impl ClassType {
@@ -19,8 +22,12 @@ impl ClassType {
             names.insert(name.clone(), value.clone());
         }
 
+        let mut sorted = (0..names.len()).collect::<Vec<_>>();
+        sorted.sort_by_key(|index| names.get_index(*index).map(|(name, _)| name));
+
         Self {
-            details: Rc::new(names),
+            details: Arc::new(names),
+            sorted: sorted.into(),
         }
     }
     pub(crate) fn get_index_and_type(&self, name: &str) -> Result<(usize, ValueType), FindItError> {
@@ -29,6 +36,12 @@ impl ClassType {
             .ok_or(FindItError::NoSuchField(name.into()))
             .map(|(index, _, value)| (index, value.clone()))
     }
+    pub(crate) fn has_field(&self, name: &str) -> bool {
+        self.details.contains_key(name)
+    }
+    pub(crate) fn fields(&self) -> impl Iterator<Item = (&String, &ValueType)> {
+        self.details.iter()
+    }
 }
 impl Display for ClassType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -44,17 +57,47 @@ impl Display for ClassType {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
-pub(crate) struct Class {
-    class: Rc<ClassType>,
-    details: Rc<Vec<Value>>,
+#[derive(Debug, Clone)]
+pub struct Class {
+    class: Arc<ClassType>,
+    details: Arc<Vec<Value>>,
+}
+
+// Two classes are equal if they have the same fields with the same values, regardless of the fields order.
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Class {}
+
+impl PartialOrd for Class {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Class {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sorted_fields().cmp(other.sorted_fields())
+    }
+}
+
+impl Hash for Class {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.details.len());
+        for field in self.sorted_fields() {
+            field.hash(state);
+        }
+    }
 }
 
 impl Class {
-    pub fn new(class: &Rc<ClassType>, details: Vec<Value>) -> Self {
+    pub fn new(class: &Arc<ClassType>, details: Vec<Value>) -> Self {
         Self {
             class: class.clone(),
-            details: Rc::new(details),
+            details: Arc::new(details),
         }
     }
     pub(crate) fn is_empty(&self) -> bool {
@@ -66,6 +109,27 @@ impl Class {
     pub(crate) fn get(self, index: usize) -> Value {
         self.details.get(index).cloned().unwrap_or(Value::Empty)
     }
+    pub(crate) fn values(&self) -> impl Iterator<Item = &Value> {
+        self.details.iter()
+    }
+    /// The names and values of the fields, in the order they were defined.
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.class
+            .details
+            .keys()
+            .map(String::as_str)
+            .zip(self.details.iter())
+    }
+    fn sorted_fields(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.class.sorted.iter().map(|index| {
+            let name = self
+                .class
+                .details
+                .get_index(*index)
+                .map_or("", |(name, _)| name.as_str());
+            (name, self.details.get(*index).unwrap_or(&Value::Empty))
+        })
+    }
 }
 
 impl Display for Class {
@@ -92,7 +156,11 @@ impl Display for Class {
 }
 #[cfg(test)]
 mod tests {
-    use std::{rc::Rc, vec};
+    use std::{
+        hash::{DefaultHasher, Hash, Hasher},
+        sync::Arc,
+        vec,
+    };
 
     use crate::{
         class_type::{Class, ClassType},
@@ -168,7 +236,7 @@ mod tests {
             Value::Bool(true),
             Value::Number(1),
         ];
-        let inst = Class::new(&Rc::new(cls), details);
+        let inst = Class::new(&Arc::new(cls), details);
 
         assert!(!inst.is_empty());
 
@@ -180,7 +248,7 @@ mod tests {
         let fields = vec![];
         let cls = ClassType::new(&fields);
         let details = vec![];
-        let inst = Class::new(&Rc::new(cls), details);
+        let inst = Class::new(&Arc::new(cls), details);
 
         assert!(inst.is_empty());
 
@@ -200,7 +268,7 @@ mod tests {
             Value::Bool(true),
             Value::Number(1),
         ];
-        let inst = Class::new(&Rc::new(cls), details);
+        let inst = Class::new(&Arc::new(cls), details);
 
         assert_eq!(inst.len(), 3);
 
@@ -220,7 +288,7 @@ mod tests {
             Value::Bool(true),
             Value::Number(1),
         ];
-        let inst = Class::new(&Rc::new(cls), details);
+        let inst = Class::new(&Arc::new(cls), details);
 
         assert_eq!(inst.get(1), Value::Bool(true));
 
@@ -239,10 +307,57 @@ mod tests {
             Value::Bool(true),
             Value::Number(1),
         ];
-        let inst = Class::new(&Rc::new(cls), details);
+        let inst = Class::new(&Arc::new(cls), details);
 
         assert_eq!(format!("{}", inst), "{\"one\":test, \"a2\":true, \"a0\":1}");
 
         Ok(())
     }
+
+    #[test]
+    fn eq_ignores_fields_order() {
+        let first = ClassType::new(&[
+            ("one".into(), ValueType::String),
+            ("two".into(), ValueType::Number),
+        ]);
+        let second = ClassType::new(&[
+            ("two".into(), ValueType::Number),
+            ("one".into(), ValueType::String),
+        ]);
+        let first = Class::new(
+            &Arc::new(first),
+            vec![Value::String("test".into()), Value::Number(2)],
+        );
+        let second = Class::new(
+            &Arc::new(second),
+            vec![Value::Number(2), Value::String("test".into())],
+        );
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn cmp_and_hash_follow_the_fields_names() {
+        let first = Arc::new(ClassType::new(&[
+            ("b".into(), ValueType::Number),
+            ("a".into(), ValueType::Number),
+        ]));
+        let second = Arc::new(ClassType::new(&[
+            ("a".into(), ValueType::Number),
+            ("b".into(), ValueType::Number),
+        ]));
+        let hash = |class: &Class| {
+            let mut hasher = DefaultHasher::new();
+            class.hash(&mut hasher);
+            hasher.finish()
+        };
+        // Compares a first, so {b: 1, a: 2} is after {a: 1, b: 5}.
+        let first = Class::new(&first, vec![Value::Number(1), Value::Number(2)]);
+        let smaller = Class::new(&second, vec![Value::Number(1), Value::Number(5)]);
+        let same = Class::new(&second, vec![Value::Number(2), Value::Number(1)]);
+
+        assert!(first > smaller);
+        assert_eq!(first, same);
+        assert_eq!(hash(&first), hash(&same));
+    }
 }
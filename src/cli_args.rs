@@ -1,13 +1,28 @@
-use std::path::PathBuf;
+use std::{env, ffi::OsStr, num::NonZeroUsize, path::PathBuf};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+
+use crate::content::DEFAULT_MMAP_THRESHOLD;
+
+/// Setting this environment variable (to any non empty value) includes hidden files by default.
+pub(crate) const INCLUDE_HIDDEN_ENV: &str = "FINDIT_INCLUDE_HIDDEN";
+
+/// When to colorize the file names in the output.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) enum ColorChoice {
+    /// Only when writing to a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
 
 /// Find files using powerful filtering expressions
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None )]
 pub struct CliArgs {
-    /// Root directory to search (default: current directory)
-    pub(crate) root: Option<PathBuf>,
+    /// Root directories to search (default: current directory)
+    pub(crate) roots: Vec<PathBuf>,
 
     /// Filter which files to display using an expression
     ///
@@ -23,11 +38,21 @@ pub struct CliArgs {
     )]
     pub(crate) filter: Vec<String>,
 
-    /// Sort results by an expression (add DESC for descending order)
+    /// Read a filter expression from a file, the same as `--where` (`#` starts a comment up to the end of the line)
+    #[arg(
+        long,
+        value_name = "PATH",
+        visible_alias = "input-from-file",
+        help_heading = "Filtering Options"
+    )]
+    pub(crate) expr_file: Vec<PathBuf>,
+
+    /// Sort results by an expression (add DESC for descending order), can be repeated to add more keys
     ///
     /// Examples:
     ///   -o 'size DESC'
     ///   -o 'modified, name'
+    ///   --order-by 'extension asc' --order-by 'size desc'
     #[arg(
         short,
         long,
@@ -37,7 +62,7 @@ pub struct CliArgs {
         visible_alias = "sort-by",
         help_heading = "Output Ordering"
     )]
-    pub(crate) order_by: Option<String>,
+    pub(crate) order_by: Vec<String>,
 
     /// Maximum depth to recurse into directories
     #[arg(short = 'x', long, help_heading = "Filtering Options")]
@@ -47,6 +72,47 @@ pub struct CliArgs {
     #[arg(short = 'n', long, help_heading = "Filtering Options")]
     pub(crate) min_depth: Option<usize>,
 
+    /// Include hidden files and directories (names starting with `.`). This is the default if `FINDIT_INCLUDE_HIDDEN` is set
+    #[arg(
+        long,
+        default_value_t = false,
+        visible_alias = "include-hidden",
+        conflicts_with = "no_hidden",
+        help_heading = "Filtering Options"
+    )]
+    pub(crate) hidden: bool,
+
+    /// Skip hidden files and directories, including everything under a hidden directory. This is the default unless `FINDIT_INCLUDE_HIDDEN` is set
+    #[arg(
+        long,
+        default_value_t = false,
+        visible_alias = "ignore-hidden",
+        help_heading = "Filtering Options"
+    )]
+    pub(crate) no_hidden: bool,
+
+    /// Skip files and directories that are ignored by `.gitignore` files found while walking
+    #[arg(
+        long,
+        default_value_t = false,
+        visible_alias = "gitignore",
+        help_heading = "Filtering Options"
+    )]
+    pub(crate) respect_gitignore: bool,
+
+    /// Memory map files that are at least that large (in bytes) when reading their content or lines
+    #[arg(
+        long,
+        value_name = "BYTES",
+        default_value_t = DEFAULT_MMAP_THRESHOLD,
+        help_heading = "Filtering Options"
+    )]
+    pub(crate) mmap_threshold: u64,
+
+    /// Replace invalid UTF-8 sequences when reading the content of files, instead of treating the content as empty
+    #[arg(long, default_value_t = false, help_heading = "Filtering Options")]
+    pub(crate) lossy_content: bool,
+
     /// Maximum number of results to display
     #[arg(short, long, help_heading = "Filtering Options")]
     pub(crate) limit: Option<usize>,
@@ -65,6 +131,29 @@ pub struct CliArgs {
     )]
     pub(crate) display: Option<String>,
 
+    /// Group the results by an expression and show the files of each group under the value of the expression
+    ///
+    /// Example:
+    ///   --group-by 'extension'
+    #[arg(
+        short,
+        long,
+        value_name = "GROUP BY",
+        visible_alias = "group",
+        help_heading = "Output Formatting"
+    )]
+    pub(crate) group_by: Option<String>,
+
+    /// Only show the number of results (or the number of results in each group with `--group-by`)
+    #[arg(
+        short,
+        long,
+        default_value_t = false,
+        conflicts_with = "display",
+        help_heading = "Output Formatting"
+    )]
+    pub(crate) count: bool,
+
     /// Start marker for expressions in display format
     #[arg(
         long,
@@ -83,6 +172,23 @@ pub struct CliArgs {
     )]
     pub(crate) interpolation_end: String,
 
+    /// Colorize the file names by their type (directories, links and executables), like `ls` does
+    #[arg(
+        long,
+        value_enum,
+        value_name = "WHEN",
+        default_value_t = ColorChoice::Auto,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "always",
+        help_heading = "Output Formatting"
+    )]
+    pub(crate) color: ColorChoice,
+
+    /// Whether the output is written to a terminal (used by `--color=auto`)
+    #[arg(skip)]
+    pub(crate) terminal_output: bool,
+
     /// Process files before their parent directories
     #[arg(
         long,
@@ -93,6 +199,14 @@ pub struct CliArgs {
     )]
     pub(crate) node_first: bool,
 
+    /// Number of threads to use when evaluating the filters and the order (default: 1)
+    #[arg(short, long, value_name = "JOBS", help_heading = "Filtering Options")]
+    pub(crate) jobs: Option<NonZeroUsize>,
+
+    /// Fail if any evaluation failed (for example, an unreadable file or a bad regular expression), instead of silently using an empty value
+    #[arg(long, default_value_t = false, help_heading = "Developer Options")]
+    pub(crate) strict: bool,
+
     /// Write debug information to a file
     #[arg(
         long,
@@ -102,7 +216,80 @@ pub struct CliArgs {
     )]
     pub(crate) debug_output_file: Option<PathBuf>,
 
+    /// Also write the output of the `debug` method to the standard error, labeled by the name of its parameter
+    #[arg(
+        long,
+        default_value_t = false,
+        visible_alias = "debug-to-stderr",
+        help_heading = "Developer Options"
+    )]
+    pub(crate) debug_methods: bool,
+
     /// Show syntax help and examples
     #[arg(long, help_heading = "Developer Options")]
     pub(crate) help_syntax: bool,
 }
+
+impl CliArgs {
+    /// Mark the output as written to a terminal (or not), so `--color=auto` will know whether to
+    /// colorize it.
+    #[must_use]
+    pub fn with_terminal_output(mut self, terminal_output: bool) -> Self {
+        self.terminal_output = terminal_output;
+        self
+    }
+
+    /// Whether hidden files should be skipped while walking, taking the `FINDIT_INCLUDE_HIDDEN`
+    /// environment variable into account when neither `--hidden` nor `--no-hidden` is used.
+    pub(crate) fn skip_hidden(&self) -> bool {
+        skip_hidden(
+            self.hidden,
+            self.no_hidden,
+            env::var_os(INCLUDE_HIDDEN_ENV).as_deref(),
+        )
+    }
+}
+
+pub(crate) fn skip_hidden(
+    hidden: bool,
+    no_hidden: bool,
+    include_hidden_env: Option<&OsStr>,
+) -> bool {
+    if hidden {
+        false
+    } else if no_hidden {
+        true
+    } else {
+        include_hidden_env.is_none_or(OsStr::is_empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn skip_hidden_by_default() {
+        assert!(skip_hidden(false, false, None));
+        assert!(skip_hidden(false, false, Some(OsStr::new(""))));
+        assert!(!skip_hidden(false, false, Some(OsStr::new("1"))));
+    }
+
+    #[test]
+    fn hidden_flags_override_the_environment() {
+        assert!(!skip_hidden(true, false, None));
+        assert!(skip_hidden(false, true, Some(OsStr::new("1"))));
+        assert!(!CliArgs::parse_from(["findit", "--hidden"]).skip_hidden());
+        assert!(CliArgs::parse_from(["findit", "--no-hidden"]).skip_hidden());
+    }
+
+    #[test]
+    fn hidden_and_no_hidden_conflict() {
+        assert!(CliArgs::try_parse_from(["findit", "--hidden", "--no-hidden"]).is_err());
+        assert!(CliArgs::try_parse_from(["findit", "--include-hidden"]).is_ok_and(|a| a.hidden));
+    }
+}
@@ -0,0 +1,111 @@
+use std::{env, sync::OnceLock};
+
+use chrono::{DateTime, Local};
+
+use crate::errors::FindItError;
+
+/// Setting this environment variable to an RFC 3339 date (like `2025-01-01T12:00:00Z`) fixes the
+/// time that is used as "now", so date expressions give the same results on every run.
+pub(crate) const NOW_ENV: &str = "FINDIT_NOW";
+
+#[cfg(test)]
+thread_local! {
+    static TEST_NOW: std::cell::Cell<Option<DateTime<Local>>> = const { std::cell::Cell::new(None) };
+}
+
+/// Fix the time returned by `now` for the current thread (`None` to go back to the real clock).
+#[cfg(test)]
+pub(crate) fn set_test_now(now: Option<DateTime<Local>>) {
+    TEST_NOW.set(now);
+}
+
+/// The current time, unless it was fixed with the `FINDIT_NOW` environment variable.
+pub(crate) fn now() -> DateTime<Local> {
+    #[cfg(test)]
+    if let Some(now) = TEST_NOW.get() {
+        return now;
+    }
+    fixed_now()
+        .as_ref()
+        .ok()
+        .copied()
+        .flatten()
+        .unwrap_or_else(Local::now)
+}
+
+/// Make sure `FINDIT_NOW` is not silently ignored: an invalid value is a warning (and the real
+/// clock is used), or an error in strict mode.
+pub(crate) fn check_now_env(strict: bool) -> Result<(), FindItError> {
+    check_fixed_now(fixed_now(), strict)
+}
+
+fn check_fixed_now(
+    fixed_now: &Result<Option<DateTime<Local>>, String>,
+    strict: bool,
+) -> Result<(), FindItError> {
+    match fixed_now {
+        Err(err) if strict => Err(FindItError::BadNow(err.clone())),
+        Err(err) => {
+            eprintln!("Warning: ignoring invalid {NOW_ENV}: {err}");
+            Ok(())
+        }
+        Ok(_) => Ok(()),
+    }
+}
+
+fn fixed_now() -> &'static Result<Option<DateTime<Local>>, String> {
+    static FIXED_NOW: OnceLock<Result<Option<DateTime<Local>>, String>> = OnceLock::new();
+    FIXED_NOW.get_or_init(|| parse_now(env::var(NOW_ENV).ok().as_deref()))
+}
+
+fn parse_now(now_env: Option<&str>) -> Result<Option<DateTime<Local>>, String> {
+    now_env
+        .map(|now| {
+            DateTime::parse_from_rfc3339(now.trim())
+                .map(Into::into)
+                .map_err(|err| format!("[{now}] is not an RFC 3339 date ({err})"))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn fixed_now_from_env_value() {
+        assert_eq!(
+            parse_now(Some("2025-01-01T12:00:00Z")),
+            Ok(Some(
+                Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap().into()
+            ))
+        );
+        assert!(parse_now(Some("yesterday")).is_err());
+        assert_eq!(parse_now(None), Ok(None));
+    }
+
+    #[test]
+    fn invalid_now_env_is_an_error_only_in_strict_mode() {
+        let invalid = parse_now(Some("yesterday"));
+
+        assert!(check_fixed_now(&invalid, false).is_ok());
+        assert!(matches!(
+            check_fixed_now(&invalid, true),
+            Err(FindItError::BadNow(msg)) if msg.contains("yesterday")
+        ));
+        assert!(check_fixed_now(&parse_now(None), true).is_ok());
+    }
+
+    #[test]
+    fn test_now_override() {
+        let fixed = Local.with_ymd_and_hms(2024, 2, 29, 10, 30, 0).unwrap();
+
+        set_test_now(Some(fixed));
+        assert_eq!(now(), fixed);
+
+        set_test_now(None);
+        assert!((now() - Local::now()).num_seconds().abs() < 5);
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    borrow::Cow,
+    fs::{self, File},
+    io::{BufRead, BufReader, Error as IoError, ErrorKind},
+    path::Path,
+};
+
+use memmap2::{Mmap, MmapOptions};
+
+use crate::errors::FindItError;
+
+/// The content and lines of files that are at least that large are read from a memory map,
+/// instead of through a buffer.
+pub(crate) const DEFAULT_MMAP_THRESHOLD: u64 = 4 * 1024 * 1024;
+
+/// How to read the content of files.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ContentOptions {
+    pub(crate) mmap_threshold: u64,
+    pub(crate) lossy: bool,
+}
+impl Default for ContentOptions {
+    fn default() -> Self {
+        Self {
+            mmap_threshold: DEFAULT_MMAP_THRESHOLD,
+            lossy: false,
+        }
+    }
+}
+
+/// The content of a memory mapped file.
+struct MmapContent {
+    mmap: Mmap,
+}
+impl MmapContent {
+    fn open(path: &Path) -> Result<Self, FindItError> {
+        let file = File::open(path)?;
+        // Safety: the map is read only, and the content (or every line) is copied out of it as
+        // soon as it is read. A file that is truncated while its lines are read might still crash the
+        // process, which is the trade off of mapping files.
+        let mmap = unsafe { MmapOptions::new().map(&file)? };
+        Ok(Self { mmap })
+    }
+
+    /// The content as text, borrowed from the map unless invalid sequences were replaced.
+    fn text(&self, lossy: bool) -> Result<Cow<'_, str>, FindItError> {
+        if lossy {
+            return Ok(String::from_utf8_lossy(&self.mmap));
+        }
+        str::from_utf8(&self.mmap)
+            .map(Cow::Borrowed)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err).into())
+    }
+}
+
+/// The lines of a memory mapped file.
+struct MmapLines {
+    content: MmapContent,
+    position: usize,
+    lossy: bool,
+}
+impl Iterator for MmapLines {
+    type Item = String;
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.content.mmap.get(self.position..)?;
+        if rest.is_empty() {
+            return None;
+        }
+        let length = rest.iter().position(|b| *b == b'\n');
+        self.position += length.map_or(rest.len(), |length| length + 1);
+        let line = &rest[..length.unwrap_or(rest.len())];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if self.lossy {
+            Some(String::from_utf8_lossy(line).into_owned())
+        } else {
+            // Same as `BufRead::lines`, stop at the first line that is not valid UTF-8.
+            str::from_utf8(line).ok().map(str::to_string)
+        }
+    }
+}
+
+fn is_large(path: &Path, threshold: u64) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() >= threshold)
+}
+
+impl ContentOptions {
+    /// The whole content of the file. A large file is decoded straight from its memory map, so
+    /// its bytes are not read into a buffer before they are copied into the `String`.
+    pub(crate) fn read(&self, path: &Path) -> Result<String, FindItError> {
+        if is_large(path, self.mmap_threshold) {
+            let content = MmapContent::open(path)?;
+            return content.text(self.lossy).map(Cow::into_owned);
+        }
+        if self.lossy {
+            let bytes = fs::read(path)?;
+            return Ok(String::from_utf8_lossy(&bytes).into_owned());
+        }
+        Ok(fs::read_to_string(path)?)
+    }
+
+    pub(crate) fn lines(
+        &self,
+        path: &Path,
+    ) -> Result<Box<dyn Iterator<Item = String> + Send>, FindItError> {
+        if is_large(path, self.mmap_threshold) {
+            return Ok(Box::new(MmapLines {
+                content: MmapContent::open(path)?,
+                position: 0,
+                lossy: self.lossy,
+            }));
+        }
+        let buf = BufReader::new(File::open(path)?);
+        if self.lossy {
+            return Ok(Box::new(buf.split(b'\n').map_while(Result::ok).map(
+                |line| {
+                    let line = line.strip_suffix(b"\r").unwrap_or(&line);
+                    String::from_utf8_lossy(line).into_owned()
+                },
+            )));
+        }
+        Ok(Box::new(buf.lines().map_while(Result::ok)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn mapped() -> ContentOptions {
+        ContentOptions {
+            mmap_threshold: 0,
+            lossy: false,
+        }
+    }
+
+    #[test]
+    fn read_small_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "one\ntwo\n")?;
+
+        assert_eq!(ContentOptions::default().read(&path)?, "one\ntwo\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_large_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "one\ntwo\n")?;
+
+        assert_eq!(mapped().read(&path)?, "one\ntwo\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_mapped_empty_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "")?;
+
+        assert_eq!(mapped().read(&path)?, "");
+        assert_eq!(mapped().lines(&path)?.count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_mapped_file_that_is_not_utf8_fails() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.bin");
+        fs::write(&path, b"one\xfftwo")?;
+
+        assert!(mapped().read(&path).is_err());
+        assert!(ContentOptions::default().read(&path).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_lossy() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.bin");
+        fs::write(&path, b"one\xfftwo")?;
+        let lossy = ContentOptions {
+            lossy: true,
+            ..ContentOptions::default()
+        };
+        let mapped_lossy = ContentOptions {
+            lossy: true,
+            ..mapped()
+        };
+
+        assert_eq!(lossy.read(&path)?, "one\u{FFFD}two");
+        assert_eq!(mapped_lossy.read(&path)?, "one\u{FFFD}two");
+
+        Ok(())
+    }
+
+    #[test]
+    fn mapped_lines() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "one\r\n\ntwo\nthree")?;
+
+        let lines = mapped().lines(&path)?.collect::<Vec<_>>();
+
+        assert_eq!(lines, vec!["one", "", "two", "three"]);
+        assert_eq!(
+            ContentOptions::default().lines(&path)?.collect::<Vec<_>>(),
+            lines
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mapped_lines_stop_at_bad_utf8() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.bin");
+        fs::write(&path, b"one\nt\xffwo\nthree\n")?;
+        let mapped_lossy = ContentOptions {
+            lossy: true,
+            ..mapped()
+        };
+
+        assert_eq!(mapped().lines(&path)?.collect::<Vec<_>>(), vec!["one"]);
+        assert_eq!(
+            mapped_lossy.lines(&path)?.collect::<Vec<_>>(),
+            vec!["one", "t\u{FFFD}wo", "three"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_files_from_the_threshold_are_large() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "12345")?;
+
+        assert!(is_large(&path, 5));
+        assert!(!is_large(&path, 6));
+        assert!(!is_large(dir.path(), 0));
+        assert!(!is_large(Path::new("no/such/file"), 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_such_file() {
+        assert!(mapped().read(Path::new("no/such/file")).is_err());
+        assert!(mapped().lines(Path::new("no/such/file")).is_err());
+    }
+}
@@ -2,11 +2,27 @@ use std::fmt::Debug;
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::{Mutex, PoisonError};
 
 use crate::errors::FindItError;
 
-pub(crate) trait Debugger: Debug {
+pub(crate) trait Debugger: Debug + Send + Sync {
     fn log(&self, f: &dyn Fn() -> String);
+
+    /// Called when an evaluation failed and an empty value is used instead.
+    fn report(&self, failure: &dyn Fn() -> String) {
+        self.log(&|| format!("Evaluation failed: {}", failure()));
+    }
+
+    /// The failures that were reported so far (only kept in strict mode).
+    fn failures(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// True if the run should fail once an evaluation failed.
+    fn is_strict(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug)]
@@ -27,6 +43,57 @@ impl Debugger for FileDebugger {
         writeln!(file, "{}", msg).ok();
     }
 }
+/// The number of failures the strict debugger keeps, the rest are only counted.
+const MAX_FAILURES: usize = 100;
+
+#[derive(Debug, Default)]
+struct Failures {
+    kept: Vec<String>,
+    dropped: usize,
+}
+
+/// Keeps the reported failures (up to `MAX_FAILURES` of them), so the run can fail once it is done.
+#[derive(Debug)]
+struct StrictDebugger {
+    debugger: Box<dyn Debugger>,
+    failures: Mutex<Failures>,
+}
+
+impl Debugger for StrictDebugger {
+    fn log(&self, f: &dyn Fn() -> String) {
+        self.debugger.log(f);
+    }
+    fn report(&self, failure: &dyn Fn() -> String) {
+        let failure = failure();
+        self.debugger
+            .log(&|| format!("Evaluation failed: {failure}"));
+        let mut failures = self.failures.lock().unwrap_or_else(PoisonError::into_inner);
+        if failures.kept.len() < MAX_FAILURES {
+            failures.kept.push(failure);
+        } else {
+            failures.dropped += 1;
+        }
+    }
+    fn failures(&self) -> Vec<String> {
+        let failures = self.failures.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut all = failures.kept.clone();
+        if failures.dropped > 0 {
+            all.push(format!("... and {} more failures", failures.dropped));
+        }
+        all
+    }
+    fn is_strict(&self) -> bool {
+        true
+    }
+}
+
+pub(crate) fn create_strict_debugger(debugger: Box<dyn Debugger>) -> Box<dyn Debugger> {
+    Box::new(StrictDebugger {
+        debugger,
+        failures: Mutex::default(),
+    })
+}
+
 pub(crate) fn create_debugger(path: Option<&PathBuf>) -> Result<Box<dyn Debugger>, FindItError> {
     if let Some(p) = path {
         fs::create_dir_all(p.parent().unwrap())?;
@@ -59,4 +126,57 @@ mod tests {
         assert_eq!(log_contents, expected_contents);
         Ok(())
     }
+    #[test]
+    fn test_report_is_logged() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let log_path = temp_dir.path().join("debug.log");
+        let debugger = super::create_debugger(Some(&log_path))?;
+
+        debugger.report(&|| "Bad regex".to_string());
+
+        assert!(debugger.failures().is_empty());
+        drop(debugger);
+        assert_eq!(
+            fs::read_to_string(&log_path)?,
+            "Evaluation failed: Bad regex\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_debugger_keeps_failures() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let log_path = temp_dir.path().join("debug.log");
+        let debugger = super::create_strict_debugger(super::create_debugger(Some(&log_path))?);
+
+        debugger.log(&|| "Not a failure".to_string());
+        debugger.report(&|| "Bad regex".to_string());
+        debugger.report(&|| "No such file".to_string());
+
+        assert_eq!(debugger.failures(), vec!["Bad regex", "No such file"]);
+        drop(debugger);
+        assert_eq!(
+            fs::read_to_string(&log_path)?,
+            "Not a failure\nEvaluation failed: Bad regex\nEvaluation failed: No such file\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_debugger_counts_the_failures_it_does_not_keep() {
+        let debugger = super::create_strict_debugger(Box::new(super::EmptyDebugger));
+
+        for index in 0..super::MAX_FAILURES + 3 {
+            debugger.report(&|| format!("Failure {index}"));
+        }
+
+        let failures = debugger.failures();
+        assert_eq!(failures.len(), super::MAX_FAILURES + 1);
+        assert_eq!(failures[0], "Failure 0");
+        assert_eq!(
+            failures[super::MAX_FAILURES - 1],
+            format!("Failure {}", super::MAX_FAILURES - 1)
+        );
+        assert_eq!(failures[super::MAX_FAILURES], "... and 3 more failures");
+    }
 }
@@ -1,7 +1,7 @@
 use std::{io::Error as IoError, num::ParseIntError, path::PathBuf, process::ExitStatus};
 use thiserror::Error;
 
-use crate::parser::parser_error::ParserError;
+use crate::parser::parser_error::ContextualParserError;
 
 #[derive(Error, Debug)]
 pub enum FindItError {
@@ -19,10 +19,84 @@ pub enum FindItError {
     BadExpression(String),
     #[error("Could not parse `{0}` because : `{0}`")]
     DisplayParserError(String, String),
-    #[error("Expression parse error: `{0}`")]
-    ParserError(#[from] ParserError),
+    #[error("Expression parse error: {0}")]
+    ParserError(#[from] ContextualParserError),
     #[error("Cannot find field name: `{0}`")]
     NoSuchField(String),
+    #[error("Evaluation failed:\n{}", .0.join("\n"))]
+    EvaluationFailed(Vec<String>),
+    #[error("Bad metadata: `{0}`")]
+    BadMetadata(String),
+    #[error("Bad FINDIT_NOW: {0}")]
+    BadNow(String),
     #[error("Pager failed: `{0}`")]
     PagerFailed(ExitStatus),
 }
+
+/// The number of single character insertions, deletions or substitutions needed to turn one
+/// string into the other.
+pub(crate) fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(a != *b);
+            let insert = current[j] + 1;
+            let delete = previous[j + 1] + 1;
+            current.push(substitute.min(insert).min(delete));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The closest known name to an unknown one, if it is close enough to be a typo.
+pub(crate) fn closest_name<'a>(
+    name: &str,
+    known: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    const MAX_DISTANCE: usize = 2;
+    known
+        .into_iter()
+        .map(|candidate| (levenshtein_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= MAX_DISTANCE && *distance < name.chars().count())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_distance_of_equal_strings() {
+        assert_eq!(levenshtein_distance("LENGTH", "LENGTH"), 0);
+        assert_eq!(levenshtein_distance("", ""), 0);
+    }
+
+    #[test]
+    fn levenshtein_distance_of_different_strings() {
+        assert_eq!(levenshtein_distance("LENTGH", "LENGTH"), 2);
+        assert_eq!(levenshtein_distance("EXNTENSION", "EXTENSION"), 1);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+        assert_eq!(levenshtein_distance("abc", ""), 3);
+    }
+
+    #[test]
+    fn closest_name_picks_the_closest() {
+        let known = ["NAME", "SIZE", "SIZES"];
+
+        assert_eq!(closest_name("SIZ", known), Some("SIZE"));
+        assert_eq!(closest_name("NMAE", known), Some("NAME"));
+    }
+
+    #[test]
+    fn closest_name_ignores_names_that_are_too_far() {
+        let known = ["NAME", "SIZE"];
+
+        assert_eq!(closest_name("CONTENT", known), None);
+        assert_eq!(closest_name("X", known), None);
+    }
+}
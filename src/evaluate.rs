@@ -0,0 +1,144 @@
+use std::{path::Path, sync::Arc};
+
+use crate::{
+    content::ContentOptions, debugger::create_debugger, errors::FindItError,
+    evaluators::expr::read_expr, file_metadata::FileMetadata, file_wrapper::FileWrapper,
+    parser::parse_expression, value::Value,
+};
+
+/// Evaluate an expression for a single file that is described by `metadata` rather than read
+/// from the file system (so it can be used where there is no file system, like a browser).
+///
+/// The file properties that are not part of the metadata (like `files` or `total_size` of a
+/// directory) still use the file system, and are empty if it is not available.
+///
+/// # Errors
+///
+/// Will return `Err` if the expression is invalid.
+pub fn evaluate(expr: &str, path: &Path, metadata: FileMetadata) -> Result<Value, FindItError> {
+    let evaluator = read_expr(expr)?;
+    let debugger = Arc::new(create_debugger(None)?);
+    let file =
+        FileWrapper::new_with_debugger(path.to_path_buf(), 0, &debugger, ContentOptions::default())
+            .with_metadata(metadata);
+
+    Ok(evaluator.eval(&file))
+}
+
+/// The syntax tree of an expression as JSON, for tools that need to understand expressions.
+///
+/// The format follows the internal structure of the parser, so it might change between versions.
+///
+/// # Errors
+///
+/// Will return `Err` if the expression is invalid.
+pub fn expression_ast(expr: &str) -> Result<String, FindItError> {
+    let expression = parse_expression(expr)?;
+
+    serde_json::to_string(&expression).map_err(|err| FindItError::BadExpression(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn evaluate_uses_the_metadata() -> Result<(), FindItError> {
+        let metadata = FileMetadata {
+            size: Some(120),
+            modified: Some(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap().into()),
+            owner: Some("alice".into()),
+            permissions: Some(0o755),
+            content: Some("#!/bin/sh\necho hi\n".into()),
+            ..FileMetadata::default()
+        };
+        let path = PathBuf::from("/no/such/dir/run.sh");
+
+        assert_eq!(
+            evaluate("size", &path, metadata.clone())?,
+            Value::Number(120)
+        );
+        assert_eq!(
+            evaluate("owner", &path, metadata.clone())?,
+            Value::String("alice".into())
+        );
+        assert_eq!(
+            evaluate(
+                "IS FILE AND EXISTS AND owner_execute",
+                &path,
+                metadata.clone()
+            )?,
+            Value::Bool(true)
+        );
+        assert_eq!(
+            evaluate("content.lines().length()", &path, metadata.clone())?,
+            Value::Number(2)
+        );
+        assert_eq!(
+            evaluate("format(modified AS \"%Y\")", &path, metadata.clone())?,
+            Value::String("2025".into())
+        );
+        assert_eq!(
+            evaluate("extension", &path, metadata)?,
+            Value::String("sh".into())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_a_missing_directory() -> Result<(), FindItError> {
+        let metadata = FileMetadata {
+            exists: false,
+            is_dir: true,
+            size: Some(10),
+            ..FileMetadata::default()
+        };
+        let path = PathBuf::from("/no/such/dir");
+
+        assert_eq!(
+            evaluate("EXISTS OR IS DIR", &path, metadata.clone())?,
+            Value::Bool(false)
+        );
+        assert_eq!(evaluate("size", &path, metadata.clone())?, Value::Empty);
+        assert_eq!(evaluate("count", &path, metadata)?, Value::Number(0));
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_without_content() -> Result<(), FindItError> {
+        let path = PathBuf::from("/no/such/file.txt");
+
+        assert_eq!(
+            evaluate("content", &path, FileMetadata::default())?,
+            Value::Empty
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_a_bad_expression() {
+        let path = PathBuf::from("file.txt");
+
+        assert!(evaluate("size >", &path, FileMetadata::default()).is_err());
+    }
+
+    #[test]
+    fn expression_ast_of_a_comparison() -> Result<(), FindItError> {
+        let ast = expression_ast("size > 10")?;
+
+        assert_eq!(
+            ast,
+            r#"{"Binary":{"left":{"Access":"Size"},"operator":{"Comparison":"LargerThen"},"right":{"Literal":10}}}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expression_ast_of_a_bad_expression() {
+        assert!(expression_ast("size >").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::errors::FindItError;
+use crate::evaluators::cast::{is_numeric, promote_to_float};
 use crate::evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory};
 use crate::file_wrapper::FileWrapper;
 use crate::parser::ast::between::Between as BetweenExpression;
@@ -11,14 +12,20 @@ struct Between {
 }
 impl EvaluatorFactory for BetweenExpression {
     fn build(&self, bindings: &BindingsTypes) -> Result<Box<dyn Evaluator>, FindItError> {
-        let evaluator = self.reference.build(bindings)?;
-        let low = self.lower_limit.build(bindings)?;
+        let mut evaluator = self.reference.build(bindings)?;
+        let mut low = self.lower_limit.build(bindings)?;
+        let mut high = self.upper_limit.build(bindings)?;
+        let types = [&evaluator, &low, &high].map(|e| e.expected_type());
+        if types.iter().all(is_numeric) && types.contains(&ValueType::Float) {
+            evaluator = promote_to_float(evaluator);
+            low = promote_to_float(low);
+            high = promote_to_float(high);
+        }
         if evaluator.expected_type() != low.expected_type() {
             return Err(FindItError::BadExpression(
                 "Between low must have the same type as the expression".into(),
             ));
         }
-        let high = self.upper_limit.build(bindings)?;
         if evaluator.expected_type() != high.expected_type() {
             return Err(FindItError::BadExpression(
                 "Between high must have the same type as the expression".into(),
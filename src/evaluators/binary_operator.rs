@@ -1,10 +1,13 @@
+use std::sync::Arc;
+
 use chrono::TimeDelta;
 
 use crate::{
     errors::FindItError,
     evaluators::{
+        cast::{is_numeric, promote_to_float},
         expr::{BindingsTypes, Evaluator, EvaluatorFactory},
-        functions::string_functions::new_regex,
+        functions::string_functions::{new_glob, new_regex},
     },
     file_wrapper::FileWrapper,
     parser::ast::{
@@ -14,7 +17,7 @@ use crate::{
             LogicalOperator,
         },
     },
-    value::{Value, ValueType},
+    value::{List, Value, ValueType},
 };
 
 impl EvaluatorFactory for BinaryExpression {
@@ -27,6 +30,8 @@ impl EvaluatorFactory for BinaryExpression {
             BinaryOperator::Logical(operator) => new_logical_operator(left, &operator, right),
             BinaryOperator::Comparison(operator) => new_comparison_operator(left, &operator, right),
             BinaryOperator::Matches => new_regex(left, right),
+            BinaryOperator::Glob => new_glob(left, right),
+            BinaryOperator::In => new_in(left, right),
             BinaryOperator::Of => new_of(left, right),
             BinaryOperator::Dot => new_of(right, left),
             BinaryOperator::BitwiseOperator(operator) => {
@@ -36,57 +41,152 @@ impl EvaluatorFactory for BinaryExpression {
     }
 }
 
+fn new_float_operator(
+    left: Box<dyn Evaluator>,
+    right: Box<dyn Evaluator>,
+    operation: fn(f64, f64) -> Option<f64>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    Ok(Box::new(FloatOperation {
+        left: promote_to_float(left),
+        right: promote_to_float(right),
+        operation,
+    }))
+}
+
 fn new_arithmetic_operator(
     left: Box<dyn Evaluator>,
     operator: &ArithmeticOperator,
     right: Box<dyn Evaluator>,
 ) -> Result<Box<dyn Evaluator>, FindItError> {
+    let (left_type, right_type) = (left.expected_type(), right.expected_type());
+    let floats = is_numeric(&left_type)
+        && is_numeric(&right_type)
+        && (left_type == ValueType::Float || right_type == ValueType::Float);
     match operator {
-        ArithmeticOperator::Plus => match (left.expected_type(), right.expected_type()) {
+        ArithmeticOperator::Plus if floats => {
+            new_float_operator(left, right, |left, right| Some(left + right))
+        }
+        ArithmeticOperator::Minus if floats => {
+            new_float_operator(left, right, |left, right| Some(left - right))
+        }
+        ArithmeticOperator::Multiply if floats => {
+            new_float_operator(left, right, |left, right| Some(left * right))
+        }
+        ArithmeticOperator::Module if floats => {
+            new_float_operator(left, right, |left, right| {
+                (right != 0.0).then_some(left % right)
+            })
+        }
+        ArithmeticOperator::Plus => match (left_type, right_type) {
             (ValueType::Number, ValueType::Number) => Ok(Box::new(PlusNumbers { left, right })),
             (ValueType::String, _) => Ok(Box::new(PlusString { left, right })),
-            (ValueType::Date, ValueType::Number) => Ok(Box::new(PlusDate { left, right })),
+            (ValueType::Date, ValueType::Number | ValueType::Duration) => {
+                Ok(Box::new(PlusDate { left, right }))
+            }
+            (ValueType::Duration, ValueType::Duration) => {
+                Ok(Box::new(PlusDurations { left, right }))
+            }
+            (ValueType::List(left_items), ValueType::List(right_items)) => {
+                // An empty list literal (`[]`) can be added to any list.
+                let items_type = match (left_items.as_ref(), right_items.as_ref()) {
+                    (_, ValueType::Empty) => left_items,
+                    (ValueType::Empty, _) => right_items,
+                    _ if left_items == right_items => left_items,
+                    _ => {
+                        return Err(FindItError::BadExpression(
+                            "Operator + can only concat lists with the same items type".into(),
+                        ));
+                    }
+                };
+                Ok(Box::new(ConcatLists {
+                    left,
+                    right,
+                    items_type,
+                }))
+            }
             _ => Err(FindItError::BadExpression(
-                "Operator + only support two numbers, string and anything, or date and number"
+                "Operator + only support two numbers (or floats), string and anything, date and number (or duration), two durations, or two lists"
                     .into(),
             )),
         },
-        ArithmeticOperator::Minus => match (left.expected_type(), right.expected_type()) {
+        ArithmeticOperator::Minus => match (left_type, right_type) {
             (ValueType::Number, ValueType::Number) => Ok(Box::new(MinusNumbers { left, right })),
-            (ValueType::Date, ValueType::Number) => Ok(Box::new(MinusDate { left, right })),
+            (ValueType::Date, ValueType::Number | ValueType::Duration) => {
+                Ok(Box::new(MinusDate { left, right }))
+            }
+            (ValueType::Date, ValueType::Date) => Ok(Box::new(MinusDates { left, right })),
+            (ValueType::Duration, ValueType::Duration) => {
+                Ok(Box::new(MinusDurations { left, right }))
+            }
             _ => Err(FindItError::BadExpression(
-                "Operator - only support two numbers, or date and number".into(),
+                "Operator - only support two numbers (or floats), date and number (or duration), two dates, or two durations".into(),
             )),
         },
-        ArithmeticOperator::Multiply => match (left.expected_type(), right.expected_type()) {
+        ArithmeticOperator::Multiply => match (left_type, right_type) {
             (ValueType::Number, ValueType::Number) => Ok(Box::new(TimesNumbers { left, right })),
             (ValueType::String, ValueType::Number) => Ok(Box::new(RepeatString { left, right })),
             _ => Err(FindItError::BadExpression(
-                "Operator * only support two numbers".into(),
+                "Operator * only support two numbers (or floats)".into(),
             )),
         },
-        ArithmeticOperator::Divide => match (left.expected_type(), right.expected_type()) {
-            (ValueType::Number, ValueType::Number) => Ok(Box::new(DivideNumbers { left, right })),
+        ArithmeticOperator::Divide => match (left_type, right_type) {
+            // Dividing two numbers can have a fraction, so the result is a float.
+            (left_type, right_type) if is_numeric(&left_type) && is_numeric(&right_type) => {
+                new_float_operator(left, right, |left, right| {
+                    (right != 0.0).then_some(left / right)
+                })
+            }
             (ValueType::Path, ValueType::String) => Ok(Box::new(DividePath { left, right })),
             _ => Err(FindItError::BadExpression(
-                "Operator / only support two numbers or path and string".into(),
+                "Operator / only support two numbers (or floats) or path and string".into(),
+            )),
+        },
+        ArithmeticOperator::IntegerDivide => match (left_type, right_type) {
+            (ValueType::Number, ValueType::Number) => Ok(Box::new(DivideNumbers { left, right })),
+            _ => Err(FindItError::BadExpression(
+                "Operator DIV only support two numbers".into(),
             )),
         },
-        ArithmeticOperator::Module => match (left.expected_type(), right.expected_type()) {
+        ArithmeticOperator::Module => match (left_type, right_type) {
             (ValueType::Number, ValueType::Number) => Ok(Box::new(ModuloNumbers { left, right })),
             _ => Err(FindItError::BadExpression(
-                "Operator % only support two numbers".into(),
+                "Operator % only support two numbers (or floats)".into(),
             )),
         },
     }
 }
 
+fn new_in(
+    item: Box<dyn Evaluator>,
+    container: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match (item.expected_type(), container.expected_type()) {
+        (ValueType::String, ValueType::String) => Ok(Box::new(InString { item, container })),
+        (item_type, ValueType::List(items_type))
+            if *items_type == item_type || *items_type == ValueType::Empty =>
+        {
+            Ok(Box::new(InList { item, container }))
+        }
+        _ => Err(FindItError::BadExpression(
+            "IN only support a string in a string or an item in a list of the same type".into(),
+        )),
+    }
+}
+
 fn new_comparison_operator(
     left: Box<dyn Evaluator>,
     operator: &ComparisonOperator,
     right: Box<dyn Evaluator>,
 ) -> Result<Box<dyn Evaluator>, FindItError> {
-    if left.expected_type() != right.expected_type() {
+    let (left, right) = if left.expected_type() != right.expected_type()
+        && is_numeric(&left.expected_type())
+        && is_numeric(&right.expected_type())
+    {
+        (promote_to_float(left), promote_to_float(right))
+    } else {
+        (left, right)
+    };
+    if !comparable_types(&left.expected_type(), &right.expected_type()) {
         return Err(FindItError::BadExpression(format!(
             "Cannot compare two different value types, left type is: {} while right type is {}",
             left.expected_type(),
@@ -103,6 +203,26 @@ fn new_comparison_operator(
     }
 }
 
+fn comparable_types(left: &ValueType, right: &ValueType) -> bool {
+    match (left, right) {
+        (ValueType::Empty, _) | (_, ValueType::Empty) => true,
+        // Lists are compared item by item, so only the items type must match.
+        (ValueType::List(left_items), ValueType::List(right_items)) => {
+            comparable_types(left_items, right_items)
+        }
+        // Classes are compared field by field, so the fields order does not matter.
+        (ValueType::Class(left_class), ValueType::Class(right_class)) => {
+            left_class.fields().count() == right_class.fields().count()
+                && left_class.fields().all(|(name, left_type)| {
+                    right_class
+                        .get_index_and_type(name)
+                        .is_ok_and(|(_, right_type)| comparable_types(left_type, &right_type))
+                })
+        }
+        _ => left == right,
+    }
+}
+
 fn new_logical_operator(
     left: Box<dyn Evaluator>,
     operator: &LogicalOperator,
@@ -170,21 +290,72 @@ impl Evaluator for PlusString {
         ValueType::String
     }
 }
+struct ConcatLists {
+    left: Box<dyn Evaluator>,
+    right: Box<dyn Evaluator>,
+    items_type: Arc<ValueType>,
+}
+impl Evaluator for ConcatLists {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::List(left), Value::List(right)) = (self.left.eval(file), self.right.eval(file))
+        else {
+            return Value::Empty;
+        };
+        let items = left.items().into_iter().chain(right.items());
+        Value::List(List::new_lazy(self.items_type.clone(), items))
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.items_type.clone())
+    }
+}
+struct InString {
+    item: Box<dyn Evaluator>,
+    container: Box<dyn Evaluator>,
+}
+impl Evaluator for InString {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::String(item), Value::String(container)) =
+            (self.item.eval(file), self.container.eval(file))
+        else {
+            return Value::Empty;
+        };
+        container.contains(&item).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+struct InList {
+    item: Box<dyn Evaluator>,
+    container: Box<dyn Evaluator>,
+}
+impl Evaluator for InList {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let item = self.item.eval(file);
+        if item == Value::Empty {
+            return Value::Empty;
+        }
+        let Value::List(container) = self.container.eval(file) else {
+            return Value::Empty;
+        };
+        container.items().into_iter().any(|i| i == item).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
 struct PlusDate {
     left: Box<dyn Evaluator>,
     right: Box<dyn Evaluator>,
 }
 impl Evaluator for PlusDate {
     fn eval(&self, file: &FileWrapper) -> Value {
-        let (Value::Date(left), Value::Number(right)) =
-            (self.left.eval(file), self.right.eval(file))
-        else {
+        let (Value::Date(left), right) = (self.left.eval(file), self.right.eval(file)) else {
             return Value::Empty;
         };
-        let Ok(right) = i64::try_from(right) else {
+        let Some(time_delta) = as_time_delta(right) else {
             return Value::Empty;
         };
-        let time_delta = TimeDelta::seconds(right);
         left.checked_add_signed(time_delta).into()
     }
     fn expected_type(&self) -> ValueType {
@@ -215,15 +386,12 @@ struct MinusDate {
 }
 impl Evaluator for MinusDate {
     fn eval(&self, file: &FileWrapper) -> Value {
-        let (Value::Date(left), Value::Number(right)) =
-            (self.left.eval(file), self.right.eval(file))
-        else {
+        let (Value::Date(left), right) = (self.left.eval(file), self.right.eval(file)) else {
             return Value::Empty;
         };
-        let Ok(right) = i64::try_from(right) else {
+        let Some(time_delta) = as_time_delta(right) else {
             return Value::Empty;
         };
-        let time_delta = TimeDelta::seconds(right);
         left.checked_sub_signed(time_delta).into()
     }
     fn expected_type(&self) -> ValueType {
@@ -231,6 +399,68 @@ impl Evaluator for MinusDate {
     }
 }
 
+/// A number (of seconds) or a duration as a time delta.
+fn as_time_delta(value: Value) -> Option<TimeDelta> {
+    match value {
+        Value::Number(seconds) => TimeDelta::try_seconds(i64::try_from(seconds).ok()?),
+        Value::Duration(duration) => Some(duration),
+        _ => None,
+    }
+}
+
+struct MinusDates {
+    left: Box<dyn Evaluator>,
+    right: Box<dyn Evaluator>,
+}
+impl Evaluator for MinusDates {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::Date(left), Value::Date(right)) = (self.left.eval(file), self.right.eval(file))
+        else {
+            return Value::Empty;
+        };
+        left.signed_duration_since(right).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Duration
+    }
+}
+
+struct PlusDurations {
+    left: Box<dyn Evaluator>,
+    right: Box<dyn Evaluator>,
+}
+impl Evaluator for PlusDurations {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::Duration(left), Value::Duration(right)) =
+            (self.left.eval(file), self.right.eval(file))
+        else {
+            return Value::Empty;
+        };
+        left.checked_add(&right).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Duration
+    }
+}
+
+struct MinusDurations {
+    left: Box<dyn Evaluator>,
+    right: Box<dyn Evaluator>,
+}
+impl Evaluator for MinusDurations {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::Duration(left), Value::Duration(right)) =
+            (self.left.eval(file), self.right.eval(file))
+        else {
+            return Value::Empty;
+        };
+        left.checked_sub(&right).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Duration
+    }
+}
+
 struct TimesNumbers {
     left: Box<dyn Evaluator>,
     right: Box<dyn Evaluator>,
@@ -267,6 +497,24 @@ impl Evaluator for RepeatString {
     }
 }
 
+struct DivideNumbers {
+    left: Box<dyn Evaluator>,
+    right: Box<dyn Evaluator>,
+}
+impl Evaluator for DivideNumbers {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::Number(left), Value::Number(right)) =
+            (self.left.eval(file), self.right.eval(file))
+        else {
+            return Value::Empty;
+        };
+        left.checked_div(right).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+}
+
 struct ModuloNumbers {
     left: Box<dyn Evaluator>,
     right: Box<dyn Evaluator>,
@@ -285,21 +533,22 @@ impl Evaluator for ModuloNumbers {
     }
 }
 
-struct DivideNumbers {
+struct FloatOperation {
     left: Box<dyn Evaluator>,
     right: Box<dyn Evaluator>,
+    operation: fn(f64, f64) -> Option<f64>,
 }
-impl Evaluator for DivideNumbers {
+impl Evaluator for FloatOperation {
     fn eval(&self, file: &FileWrapper) -> Value {
-        let (Value::Number(left), Value::Number(right)) =
+        let (Value::Float(left), Value::Float(right)) =
             (self.left.eval(file), self.right.eval(file))
         else {
             return Value::Empty;
         };
-        left.checked_div(right).into()
+        (self.operation)(left.get(), right.get()).into()
     }
     fn expected_type(&self) -> ValueType {
-        ValueType::Number
+        ValueType::Float
     }
 }
 
@@ -648,6 +897,57 @@ mod tests {
         assert!(err.is_some())
     }
 
+    #[test]
+    fn unsupported_compare_lists_of_different_type() {
+        let err = read_expr("[\"a\"] = [1]").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn compare_lists() {
+        let wrapper = FileWrapper::new(Path::new("no/such/file").to_path_buf(), 2);
+        for (expr, expected) in [
+            ("[1, 2] = [1, 2]", true),
+            ("[1, 2] = [2, 1]", false),
+            ("[1, 2] <> [1, 3]", true),
+            ("[1, 2] < [1, 3]", true),
+            ("[1] < [1, 0]", true),
+            ("[2] > [1, 3]", true),
+            ("[] = [1].take(0)", true),
+            ("[[1], [2]] = [[1], [2]]", true),
+            ("[1, 2, 3].filter($n $n > 1) = [2, 3]", true),
+        ] {
+            let eval = read_expr(expr).unwrap();
+            assert_eq!(eval.eval(&wrapper), Value::Bool(expected), "{expr}");
+        }
+    }
+
+    #[test]
+    fn compare_classes() {
+        let wrapper = FileWrapper::new(Path::new("no/such/file").to_path_buf(), 2);
+        for (expr, expected) in [
+            ("{:a 1, :b \"x\"} = {:a 1, :b \"x\"}", true),
+            ("{:a 1, :b \"x\"} = {:b \"x\", :a 1}", true),
+            ("{:a 1, :b \"x\"} = {:a 2, :b \"x\"}", false),
+            ("{:a 1, :b \"x\"} <> {:a 1, :b \"y\"}", true),
+            ("{:a {:b [1]}} = {:a {:b [1]}}", true),
+        ] {
+            let eval = read_expr(expr).unwrap();
+            assert_eq!(eval.eval(&wrapper), Value::Bool(expected), "{expr}");
+        }
+    }
+
+    #[test]
+    fn unsupported_compare_classes_with_different_fields() {
+        for expr in [
+            "{:a 1} = {:a 1, :b 2}",
+            "{:a 1} = {:b 1}",
+            "{:a 1} = {:a \"1\"}",
+        ] {
+            assert!(read_expr(expr).is_err(), "{expr}");
+        }
+    }
+
     #[test]
     fn unsupported_logical_numbers() {
         let err = read_expr("4 AND TRUE").err();
@@ -1100,4 +1400,268 @@ mod tests {
 
         Ok(())
     }
+
+    fn eval_no_file(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(expr.eval(&file))
+    }
+
+    #[test]
+    fn divide_numbers_returns_fraction() -> Result<(), FindItError> {
+        assert_eq!(eval_no_file("5 / 2")?, Value::from(2.5));
+        assert_eq!(eval_no_file("4.0 / 2")?, Value::from(2.0));
+        assert_eq!(eval_no_file("4.0 / 2")?.to_string(), "2");
+        assert_eq!(read_expr("5 / 2")?.expected_type(), ValueType::Float);
+        assert_eq!(read_expr("4 / 2")?.expected_type(), ValueType::Float);
+        assert_eq!(read_expr("size / 2")?.expected_type(), ValueType::Float);
+
+        Ok(())
+    }
+
+    #[test]
+    fn integer_divide_numbers_returns_number() -> Result<(), FindItError> {
+        assert_eq!(eval_no_file("5 DIV 2")?, Value::Number(2));
+        assert_eq!(eval_no_file("4 div 2")?, Value::Number(2));
+        assert_eq!(eval_no_file("4 DIV 0")?, Value::Empty);
+        assert_eq!(read_expr("4 DIV 2")?.expected_type(), ValueType::Number);
+        assert_eq!(read_expr("size DIV 2")?.expected_type(), ValueType::Number);
+        assert!(read_expr("4.0 DIV 2").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_non_literal_numbers() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        std::fs::write(&path, "12345")?;
+        let file = FileWrapper::new(path, 1);
+
+        assert_eq!(read_expr("size / 2")?.eval(&file), Value::from(2.5));
+        assert_eq!(read_expr("size DIV 2")?.eval(&file), Value::Number(2));
+        assert_eq!(
+            read_expr("[1, 2, 3, 4].take(size DIV 2).length()")?.eval(&file),
+            Value::Number(2)
+        );
+        assert_eq!(
+            read_expr("[1, 2, 3, 4].skip((size / 2) AS NUMBER).length()")?.eval(&file),
+            Value::Number(2)
+        );
+        assert!(read_expr("[1, 2, 3, 4].take(size / 2)").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_by_zero_is_empty() -> Result<(), FindItError> {
+        assert_eq!(eval_no_file("5 / 0")?, Value::Empty);
+        assert_eq!(eval_no_file("5.5 / 0.0")?, Value::Empty);
+        assert_eq!(eval_no_file("5.5 % 0")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_arithmetic_promotes_to_float() -> Result<(), FindItError> {
+        assert_eq!(eval_no_file("1.5 + 1")?, Value::from(2.5));
+        assert_eq!(eval_no_file("1 - 1.5")?, Value::from(-0.5));
+        assert_eq!(eval_no_file("2 * 1.25")?, Value::from(2.5));
+        assert_eq!(eval_no_file("5.5 % 2")?, Value::from(1.5));
+        assert_eq!(eval_no_file("1.5 / 0.5")?, Value::from(3.0));
+        assert_eq!(read_expr("1.5 + 1")?.expected_type(), ValueType::Float);
+        assert_eq!(read_expr("1 + 1")?.expected_type(), ValueType::Number);
+
+        Ok(())
+    }
+
+    #[test]
+    fn float_arithmetic_with_empty_is_empty() -> Result<(), FindItError> {
+        assert_eq!(eval_no_file("1.5 + content.length()")?, Value::Empty);
+        assert_eq!(eval_no_file("content.length() / 2")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compare_numbers_and_floats() -> Result<(), FindItError> {
+        assert_eq!(eval_no_file("2.5 > 2")?, Value::Bool(true));
+        assert_eq!(eval_no_file("3 = 3.0")?, Value::Bool(true));
+        assert_eq!(eval_no_file("7 / 2 < 4")?, Value::Bool(true));
+        assert_eq!(eval_no_file("3 BETWEEN 2.5 AND 3.5")?, Value::Bool(true));
+        assert_eq!(eval_no_file("3.5 BETWEEN 1 AND 3")?, Value::Bool(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_float_operators() {
+        assert!(read_expr("1.5 & 1").is_err());
+        assert!(read_expr("1.5 + true").is_err());
+        assert!(read_expr("\"a\" = 1.5").is_err());
+    }
+
+    #[test]
+    fn date_and_duration() -> Result<(), FindItError> {
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+
+        assert_eq!(
+            read_expr("@(2025-04-19 08:42:00) + 2h = @(2025-04-19 10:42:00)")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            read_expr("@(2025-04-19 08:42:00) - 1d = @(2025-04-18 08:42:00)")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            read_expr("now() - 7d < now()")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn date_minus_date_is_a_duration() -> Result<(), FindItError> {
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let expr = read_expr("@(2025-04-19 08:42:00) - @(2025-04-18 08:12:00)")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Duration);
+        assert_eq!(expr.eval(&wrapper).to_string(), "1d 30m");
+        assert_eq!(
+            read_expr("@(2025-04-18) - @(2025-04-19) = 0s - 1d")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_arithmetic_and_comparison() -> Result<(), FindItError> {
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+
+        assert_eq!(
+            read_expr("1h + 30m = 90m")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        assert_eq!(read_expr("1w - 1d = 6d")?.eval(&wrapper), Value::Bool(true));
+        assert_eq!(read_expr("45s < 1m")?.eval(&wrapper), Value::Bool(true));
+        assert_eq!(
+            read_expr("duration(2, \"h\") = 2h")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_with_wrong_types() {
+        assert!(read_expr("1h + 1").is_err());
+        assert!(read_expr("1h - @(2025-04-19)").is_err());
+        assert!(read_expr("1h * 2").is_err());
+        assert!(read_expr("1h = 3600").is_err());
+    }
+
+    #[test]
+    fn concat_lists() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        for (expr, expected) in [
+            ("([1, 2] + [3, 1]) AS TEXT", "[1, 2, 3, 1]"),
+            ("([\"a\"] + []) AS TEXT", "[a]"),
+            ("([] + [\"a\"]) AS TEXT", "[a]"),
+            ("([1] + [2] + [3]).sum()", "6"),
+        ] {
+            assert_eq!(
+                read_expr(expr)?.eval(file).to_string(),
+                expected.to_string(),
+                "{expr}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn concat_lists_keeps_the_items_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("[1, 2] + [3]")?.expected_type(),
+            ValueType::List(std::sync::Arc::new(ValueType::Number))
+        );
+        assert_eq!(
+            read_expr("[] + [\"a\"]")?.expected_type(),
+            ValueType::List(std::sync::Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn concat_files_and_dirs() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("src").to_path_buf(), 1);
+        let expr = read_expr(
+            "(files.filter($f $f.IS FILE) + files.filter($f $f.IS DIR)).length() = files.length()",
+        )?;
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn concat_lists_of_different_types() {
+        assert!(read_expr("[1, 2] + [\"a\"]").is_err());
+        assert!(read_expr("[1, 2] + 3").is_err());
+    }
+
+    #[test]
+    fn in_string() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        assert_eq!(
+            read_expr("\"hello\" IN \"hello world\"")?.eval(file),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            read_expr("\"bye\" in \"hello world\"")?.eval(file),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            read_expr("\"\" IN \"hello\"")?.eval(file),
+            Value::Bool(true)
+        );
+        assert_eq!(read_expr("\"TODO\" IN content")?.eval(file), Value::Empty);
+        assert_eq!(
+            read_expr("\"TODO\" IN content")?.expected_type(),
+            ValueType::Bool
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn in_list() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        assert_eq!(read_expr("2 IN [1, 2, 3]")?.eval(file), Value::Bool(true));
+        assert_eq!(read_expr("4 IN [1, 2, 3]")?.eval(file), Value::Bool(false));
+        assert_eq!(read_expr("4 IN []")?.eval(file), Value::Bool(false));
+        assert_eq!(
+            read_expr("extension IN [\"rs\", \"toml\"]")?.eval(file),
+            Value::Empty
+        );
+        assert_eq!(
+            read_expr("1 + 1 IN [2] AND TRUE")?.eval(file),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn in_with_bad_types() {
+        assert!(read_expr("1 IN \"123\"").is_err());
+        assert!(read_expr("\"1\" IN [1, 2]").is_err());
+        assert!(read_expr("1 IN 1").is_err());
+    }
 }
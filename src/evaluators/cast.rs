@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use chrono::DateTime;
 
@@ -13,7 +13,7 @@ use crate::{
         },
         parse_expression,
     },
-    value::{Value, ValueType},
+    value::{List, Value, ValueType, duration_of, parse_duration},
 };
 
 struct CastToBool {
@@ -32,9 +32,13 @@ impl Evaluator for CastToBool {
             Value::Date(_) => true.into(),
             Value::Empty => Value::Empty,
             Value::Number(n) => (n != 0).into(),
+            Value::Float(n) => (n.get() != 0.0).into(),
+            Value::Duration(d) => (!d.is_zero()).into(),
             Value::Path(p) => p.exists().into(),
             Value::List(l) => l.has_items().into(),
             Value::Class(c) => (!c.is_empty()).into(),
+            Value::Bytes(b) => (!b.is_empty()).into(),
+            Value::Regex(_) => true.into(),
         }
     }
 }
@@ -47,7 +51,10 @@ impl Evaluator for CastToString {
         ValueType::String
     }
     fn eval(&self, file: &FileWrapper) -> Value {
-        self.expr.eval(file).to_string().into()
+        match self.expr.eval(file) {
+            Value::Bytes(b) => String::from_utf8_lossy(&b).into_owned().into(),
+            value => value.to_string().into(),
+        }
     }
 }
 
@@ -72,13 +79,48 @@ impl Evaluator for CastToNumber {
                 Err(_) => Value::Empty,
             },
             Value::Number(n) => Value::Number(n),
+            // Truncate towards zero, there are no negative numbers.
+            Value::Float(n) if n.get() >= 0.0 => Value::Number(n.get() as u64),
+            Value::Float(_) => Value::Empty,
+            Value::Duration(d) => u64::try_from(d.num_seconds()).ok().into(),
             Value::List(l) => l.count().into(),
             Value::Class(c) => c.len().into(),
-            Value::Path(_) => Value::Empty,
+            Value::Bytes(b) => b.len().into(),
+            Value::Path(_) | Value::Regex(_) => Value::Empty,
         }
     }
 }
 
+/// Used to mix numbers and floats, by promoting the numbers to floats.
+struct CastToFloat {
+    expr: Box<dyn Evaluator>,
+}
+impl Evaluator for CastToFloat {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Float
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        match self.expr.eval(file) {
+            Value::Number(n) => (n as f64).into(),
+            Value::Float(n) => Value::Float(n),
+            _ => Value::Empty,
+        }
+    }
+}
+
+pub(crate) fn is_numeric(value_type: &ValueType) -> bool {
+    matches!(value_type, ValueType::Number | ValueType::Float)
+}
+
+/// Promote a number expression to a float expression (a float expression is kept as is).
+pub(crate) fn promote_to_float(expr: Box<dyn Evaluator>) -> Box<dyn Evaluator> {
+    if expr.expected_type() == ValueType::Float {
+        expr
+    } else {
+        Box::new(CastToFloat { expr })
+    }
+}
+
 struct CastToDate {
     expr: Box<dyn Evaluator>,
 }
@@ -105,7 +147,12 @@ impl Evaluator for CastToDate {
                 .and_then(|m| m.accessed().ok())
                 .map(|tm| Value::Date(tm.into()))
                 .unwrap_or(Value::Empty),
-            Value::List(_) | Value::Class(_) => Value::Empty,
+            Value::Float(_)
+            | Value::Duration(_)
+            | Value::List(_)
+            | Value::Class(_)
+            | Value::Bytes(_)
+            | Value::Regex(_) => Value::Empty,
         }
     }
 }
@@ -123,14 +170,64 @@ impl Evaluator for CastToPath {
             | Value::Empty
             | Value::Date(_)
             | Value::Number(_)
+            | Value::Float(_)
+            | Value::Duration(_)
             | Value::List(_)
-            | Value::Class(_) => Value::Empty,
+            | Value::Class(_)
+            | Value::Bytes(_)
+            | Value::Regex(_) => Value::Empty,
             Value::Path(p) => Value::Path(p),
             Value::String(s) => Value::Path(Path::new(&s).to_path_buf()),
         }
     }
 }
 
+struct CastToDuration {
+    expr: Box<dyn Evaluator>,
+}
+impl Evaluator for CastToDuration {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Duration
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        match self.expr.eval(file) {
+            Value::Duration(d) => Value::Duration(d),
+            Value::String(str) => parse_duration(&str).into(),
+            // A number is the number of seconds.
+            Value::Number(n) => duration_of(n, "s").into(),
+            _ => Value::Empty,
+        }
+    }
+}
+
+struct CastToList {
+    expr: Box<dyn Evaluator>,
+    item_type: Arc<ValueType>,
+}
+impl Evaluator for CastToList {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.item_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let items = match self.expr.eval(file) {
+            Value::List(list) => return Value::List(list),
+            Value::String(str) => str.lines().map(|line| line.to_string().into()).collect(),
+            Value::Empty => vec![],
+            value => vec![value],
+        };
+        Value::List(List::new_from_vec(self.item_type.clone(), items))
+    }
+}
+
+fn new_cast_to_list(expr: Box<dyn Evaluator>) -> Box<dyn Evaluator> {
+    let item_type = match expr.expected_type() {
+        ValueType::List(item_type) => item_type,
+        // A string is split into its lines, anything else is wrapped in a single item list.
+        item_type => Arc::new(item_type),
+    };
+    Box::new(CastToList { expr, item_type })
+}
+
 impl EvaluatorFactory for As {
     fn build(&self, bindings: &BindingsTypes) -> Result<Box<dyn Evaluator>, FindItError> {
         let expr = self.expression.build(bindings)?;
@@ -140,13 +237,15 @@ impl EvaluatorFactory for As {
             CastType::Number => Ok(Box::new(CastToNumber { expr })),
             CastType::Date => Ok(Box::new(CastToDate { expr })),
             CastType::Path => Ok(Box::new(CastToPath { expr })),
+            CastType::List => Ok(new_cast_to_list(expr)),
+            CastType::Duration => Ok(Box::new(CastToDuration { expr })),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{Local, TimeZone};
+    use chrono::{Local, TimeDelta, TimeZone};
 
     use crate::evaluators::expr::read_expr;
 
@@ -707,4 +806,147 @@ mod tests {
         assert_eq!(value, Value::Empty);
         Ok(())
     }
+    #[test]
+    fn test_bytes_casts() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.bin");
+        std::fs::write(&path, b"ab\xffc")?;
+        let wrapper = FileWrapper::new(path, 1);
+
+        assert_eq!(
+            read_expr("bytes as string")?.eval(&wrapper),
+            Value::String("ab\u{FFFD}c".into())
+        );
+        assert_eq!(
+            read_expr("bytes as number")?.eval(&wrapper),
+            Value::Number(4)
+        );
+        assert_eq!(
+            read_expr("bytes as bool")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        assert_eq!(read_expr("bytes as path")?.eval(&wrapper), Value::Empty);
+        assert_eq!(read_expr("bytes as date")?.eval(&wrapper), Value::Empty);
+        assert_eq!(
+            read_expr("bytes = bytes")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        Ok(())
+    }
+
+    fn as_list(items_type: ValueType, items: Vec<Value>) -> Value {
+        Value::List(List::new_from_vec(Arc::new(items_type), items))
+    }
+
+    #[test]
+    fn test_cast_string_to_list() -> Result<(), FindItError> {
+        let eval = read_expr("\"one\\ntwo\\n\\nthree\" as list")?;
+        let wrapper = FileWrapper::new(Path::new("/").to_path_buf(), 1);
+
+        assert_eq!(
+            eval.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+        assert_eq!(
+            eval.eval(&wrapper),
+            as_list(
+                ValueType::String,
+                vec!["one".into(), "two".into(), "".into(), "three".into()]
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_scalar_to_list() -> Result<(), FindItError> {
+        let wrapper = FileWrapper::new(Path::new("/").to_path_buf(), 1);
+
+        let eval = read_expr("12 as list")?;
+        assert_eq!(
+            eval.expected_type(),
+            ValueType::List(Arc::new(ValueType::Number))
+        );
+        assert_eq!(
+            eval.eval(&wrapper),
+            as_list(ValueType::Number, vec![Value::Number(12)])
+        );
+
+        let eval = read_expr("true as list")?;
+        assert_eq!(
+            eval.eval(&wrapper),
+            as_list(ValueType::Bool, vec![Value::Bool(true)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_list_to_list() -> Result<(), FindItError> {
+        let eval = read_expr("[1, 2] as list")?;
+        let wrapper = FileWrapper::new(Path::new("/").to_path_buf(), 1);
+
+        assert_eq!(
+            eval.expected_type(),
+            ValueType::List(Arc::new(ValueType::Number))
+        );
+        assert_eq!(
+            eval.eval(&wrapper),
+            as_list(ValueType::Number, vec![Value::Number(1), Value::Number(2)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_empty_to_list() -> Result<(), FindItError> {
+        let wrapper = FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+
+        let eval = read_expr("content as list")?;
+        assert_eq!(eval.eval(&wrapper), as_list(ValueType::String, vec![]));
+
+        let eval = read_expr("size as list")?;
+        assert_eq!(eval.eval(&wrapper), as_list(ValueType::Number, vec![]));
+        assert_eq!(
+            read_expr("(size as list).length()")?.eval(&wrapper),
+            Value::Number(0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_to_duration() -> Result<(), FindItError> {
+        let wrapper = FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+
+        for (sql, expected) in [
+            ("\"2h\" as duration", Value::Duration(TimeDelta::hours(2))),
+            (
+                "\"1d 2h 30m 45s\" as duration",
+                Value::Duration(
+                    TimeDelta::days(1)
+                        + TimeDelta::hours(2)
+                        + TimeDelta::minutes(30)
+                        + TimeDelta::seconds(45),
+                ),
+            ),
+            ("90 as duration", Value::Duration(TimeDelta::seconds(90))),
+            ("3d as duration", Value::Duration(TimeDelta::days(3))),
+            ("\"2 fortnights\" as duration", Value::Empty),
+            ("true as duration", Value::Empty),
+        ] {
+            let eval = read_expr(sql)?;
+            assert_eq!(eval.expected_type(), ValueType::Duration, "{sql}");
+            assert_eq!(eval.eval(&wrapper), expected, "{sql}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_duration_cast_to_date() -> Result<(), FindItError> {
+        let wrapper = FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        let eval = read_expr("@(2025-01-01) + (\"1d 2h\" AS DURATION)")?;
+
+        assert_eq!(
+            eval.eval(&wrapper),
+            Value::Date(Local.with_ymd_and_hms(2025, 1, 2, 2, 0, 0).unwrap())
+        );
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     class_type::{Class, ClassType},
@@ -10,7 +10,7 @@ use crate::{
 };
 
 struct ClassBuilder {
-    cls: Rc<ClassType>,
+    cls: Arc<ClassType>,
     fields: Vec<Box<dyn Evaluator>>,
 }
 impl Evaluator for ClassBuilder {
@@ -34,7 +34,7 @@ impl EvaluatorFactory for ClassDefinition {
             fields.push(access);
         }
 
-        let cls = Rc::new(ClassType::new(&defs));
+        let cls = Arc::new(ClassType::new(&defs));
         Ok(Box::new(ClassBuilder { cls, fields }))
     }
 }
@@ -42,7 +42,7 @@ impl EvaluatorFactory for ClassDefinition {
 #[cfg(test)]
 mod tests {
 
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         class_type::{Class, ClassType},
@@ -65,7 +65,7 @@ mod tests {
             ("two".into(), ValueType::String),
         ]);
         let cls = Class::new(
-            &Rc::new(tp),
+            &Arc::new(tp),
             vec![Value::Number(1), Value::String("2".into())],
         );
         assert_eq!(value, Value::Class(cls));
@@ -1,17 +1,15 @@
 use std::collections::HashMap;
 
 use crate::{
+    cache::expression_cache,
     errors::FindItError,
     file_wrapper::FileWrapper,
     order::{OrderDirection, OrderItem},
-    parser::{
-        ast::expression::Expression, ast::order_by::OrderByDirection, parse_expression,
-        parse_order_by,
-    },
+    parser::{ast::expression::Expression, ast::order_by::OrderByDirection, parse_order_by},
     value::{Value, ValueType},
 };
 
-pub(crate) trait Evaluator {
+pub(crate) trait Evaluator: Send + Sync {
     fn eval(&self, file: &FileWrapper) -> Value;
     fn expected_type(&self) -> ValueType;
 }
@@ -68,12 +66,13 @@ impl EvaluatorFactory for Expression {
             Expression::MethodInvocation(l) => l.build(bindings),
             Expression::ClassDefinition(d) => d.build(bindings),
             Expression::ClassAccess(a) => a.build(bindings),
+            Expression::Include(i) => i.expression.build(bindings),
         }
     }
 }
 
 pub(crate) fn read_expr(expr: &str) -> Result<Box<dyn Evaluator>, FindItError> {
-    let expression = parse_expression(expr)?;
+    let expression = expression_cache().get_or_parse(expr)?;
 
     expression.build(&BindingsTypes::default())
 }
@@ -1,12 +1,18 @@
-use std::{fs, os::unix::fs::MetadataExt, rc::Rc};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
-use std::os::unix::fs::PermissionsExt;
-use uzers::{get_group_by_gid, get_user_by_uid};
+use chrono::{DateTime, Local};
 
 use crate::{
+    clock,
     evaluators::expr::Evaluator,
     file_wrapper::FileWrapper,
     parser::ast::access::Access,
+    platform::{group_name, mode, owner_name},
     value::{List, Value, ValueType},
 };
 
@@ -23,11 +29,15 @@ impl From<&Access> for Box<dyn Evaluator> {
             Access::Stem => Box::new(StemExtractor {}),
 
             Access::Content => Box::new(ContentExtractor {}),
+            Access::ContentBytes => Box::new(ContentBytesExtractor {}),
             Access::Depth => Box::new(DepthExtractor {}),
 
             Access::Size => Box::new(SizeExtractor {}),
             Access::Count => Box::new(CountExtractor {}),
+            Access::ChildCount => Box::new(ChildCountExtractor {}),
+            Access::TotalSize => Box::new(TotalSizeExtractor::default()),
             Access::Created => Box::new(CreatedExtractor {}),
+            Access::Age => Box::new(AgeExtractor {}),
             Access::Modified => Box::new(ModifiedExtractor {}),
             Access::Exists => Box::new(ExistsExtractor {}),
             Access::IsDir => Box::new(IsDirExtractor { negate: false }),
@@ -36,11 +46,35 @@ impl From<&Access> for Box<dyn Evaluator> {
             Access::IsNotDir => Box::new(IsDirExtractor { negate: true }),
             Access::IsNotFile => Box::new(IsFileExtractor { negate: true }),
             Access::IsNotLink => Box::new(IsLinkExtractor { negate: true }),
+            Access::IsAbsolute => Box::new(IsAbsoluteExtractor { negate: false }),
+            Access::IsRelative => Box::new(IsAbsoluteExtractor { negate: true }),
 
             Access::Owner => Box::new(OwnerExtractor {}),
             Access::Group => Box::new(GroupExtractor {}),
             Access::Permissions => Box::new(PermissionsExtractor {}),
-            Access::Files => Box::new(FilesExtractor {}),
+            Access::OwnerRead => Box::new(PermissionBitExtractor { mask: 0o400 }),
+            Access::OwnerWrite => Box::new(PermissionBitExtractor { mask: 0o200 }),
+            Access::OwnerExecute => Box::new(PermissionBitExtractor { mask: 0o100 }),
+            Access::GroupRead => Box::new(PermissionBitExtractor { mask: 0o040 }),
+            Access::GroupWrite => Box::new(PermissionBitExtractor { mask: 0o020 }),
+            Access::GroupExecute => Box::new(PermissionBitExtractor { mask: 0o010 }),
+            Access::OthersRead => Box::new(PermissionBitExtractor { mask: 0o004 }),
+            Access::OthersWrite => Box::new(PermissionBitExtractor { mask: 0o002 }),
+            Access::OthersExecute => Box::new(PermissionBitExtractor { mask: 0o001 }),
+            Access::Setuid => Box::new(PermissionBitExtractor { mask: 0o4000 }),
+            Access::Setgid => Box::new(PermissionBitExtractor { mask: 0o2000 }),
+            Access::Sticky => Box::new(PermissionBitExtractor { mask: 0o1000 }),
+            Access::Files => Box::new(FilesExtractor {
+                entries: Entries::All,
+            }),
+            Access::FileList => Box::new(FilesExtractor {
+                entries: Entries::Files,
+            }),
+            Access::Dirs => Box::new(FilesExtractor {
+                entries: Entries::Dirs,
+            }),
+            Access::IsEmptyDir => Box::new(IsEmptyDirExtractor {}),
+            Access::Hidden => Box::new(HiddenExtractor {}),
         }
     }
 }
@@ -115,13 +149,38 @@ impl Evaluator for MeExtractor {
 struct ContentExtractor {}
 impl Evaluator for ContentExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        file.read().into()
+        match file.read() {
+            Ok(content) => content.into(),
+            Err(err) => {
+                file.report(&|| format!("Can not read the content of [{file}]: {err}"));
+                Value::Empty
+            }
+        }
     }
     fn expected_type(&self) -> ValueType {
         ValueType::String
     }
 }
 
+struct ContentBytesExtractor {}
+impl Evaluator for ContentBytesExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        if file.metadata().is_some() {
+            return file.read().map(String::into_bytes).into();
+        }
+        match fs::read(file.path()) {
+            Ok(content) => content.into(),
+            Err(err) => {
+                file.report(&|| format!("Can not read the bytes of [{file}]: {err}"));
+                Value::Empty
+            }
+        }
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bytes
+    }
+}
+
 struct DepthExtractor {}
 impl Evaluator for DepthExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
@@ -132,9 +191,64 @@ impl Evaluator for DepthExtractor {
     }
 }
 
+/// The size of everything under a directory. Symbolic links under the directory are not
+/// followed (so there are no loops), and the size of each directory is calculated only once.
+#[derive(Default)]
+struct TotalSizeExtractor {
+    cache: Mutex<HashMap<PathBuf, u64>>,
+}
+impl TotalSizeExtractor {
+    fn dir_size(&self, dir: &Path, file: &FileWrapper) -> u64 {
+        if let Some(size) = self.cache.lock().ok().and_then(|c| c.get(dir).copied()) {
+            return size;
+        }
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                file.debugger()
+                    .log(&|| format!("Can not list the files of {}: {}", dir.display(), err));
+                return 0;
+            }
+        };
+        let size = entries
+            .filter_map(Result::ok)
+            .map(|entry| match entry.file_type() {
+                Ok(tp) if tp.is_dir() => self.dir_size(&entry.path(), file),
+                Ok(tp) if tp.is_file() => entry.metadata().map(|m| m.len()).unwrap_or_default(),
+                _ => 0,
+            })
+            .sum();
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(dir.to_path_buf(), size);
+        }
+        size
+    }
+}
+impl Evaluator for TotalSizeExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        if let Some(metadata) = file.metadata() {
+            return metadata.size.filter(|_| metadata.exists).into();
+        }
+        let Ok(metadata) = file.path().metadata() else {
+            return Value::Empty;
+        };
+        if metadata.is_dir() {
+            self.dir_size(file.path(), file).into()
+        } else {
+            metadata.len().into()
+        }
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+}
+
 struct SizeExtractor {}
 impl Evaluator for SizeExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
+        if let Some(metadata) = file.metadata() {
+            return metadata.size.filter(|_| metadata.is_file()).into();
+        }
         let Ok(metadata) = file.path().metadata() else {
             return Value::Empty;
         };
@@ -158,18 +272,65 @@ impl Evaluator for CountExtractor {
         ValueType::Number
     }
 }
+struct ChildCountExtractor {}
+impl Evaluator for ChildCountExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let path = file.path();
+        if !path.is_dir() {
+            return Value::Empty;
+        }
+        match fs::read_dir(path) {
+            Ok(entries) => entries.count().into(),
+            Err(err) => {
+                file.debugger()
+                    .log(&|| format!("Can not list the files of {}: {}", path.display(), err));
+                Value::Empty
+            }
+        }
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+}
 struct CreatedExtractor {}
 impl Evaluator for CreatedExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
+        if let Some(metadata) = file.metadata() {
+            return metadata.created.into();
+        }
         file.path().metadata().and_then(|m| m.created()).into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Date
     }
 }
+struct AgeExtractor {}
+impl Evaluator for AgeExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let created = match file.metadata() {
+            Some(metadata) => metadata.created,
+            None => file
+                .path()
+                .metadata()
+                .and_then(|m| m.created())
+                .ok()
+                .map(DateTime::<Local>::from),
+        };
+        let Some(created) = created else {
+            return Value::Empty;
+        };
+        clock::now().signed_duration_since(created).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Duration
+    }
+}
 struct ModifiedExtractor {}
 impl Evaluator for ModifiedExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
+        if let Some(metadata) = file.metadata() {
+            return metadata.modified.into();
+        }
         file.path().metadata().and_then(|m| m.modified()).into()
     }
     fn expected_type(&self) -> ValueType {
@@ -179,7 +340,10 @@ impl Evaluator for ModifiedExtractor {
 struct ExistsExtractor {}
 impl Evaluator for ExistsExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        file.path().exists().into()
+        match file.metadata() {
+            Some(metadata) => metadata.exists.into(),
+            None => file.path().exists().into(),
+        }
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Bool
@@ -190,7 +354,11 @@ struct IsDirExtractor {
 }
 impl Evaluator for IsDirExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        (self.negate ^ file.path().is_dir()).into()
+        let is_dir = match file.metadata() {
+            Some(metadata) => metadata.exists && metadata.is_dir,
+            None => file.path().is_dir(),
+        };
+        (self.negate ^ is_dir).into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Bool
@@ -201,7 +369,11 @@ struct IsFileExtractor {
 }
 impl Evaluator for IsFileExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        (self.negate ^ file.path().is_file()).into()
+        let is_file = match file.metadata() {
+            Some(metadata) => metadata.is_file(),
+            None => file.path().is_file(),
+        };
+        (self.negate ^ is_file).into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Bool
@@ -212,7 +384,34 @@ struct IsLinkExtractor {
 }
 impl Evaluator for IsLinkExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        (self.negate ^ file.path().is_symlink()).into()
+        let is_link = match file.metadata() {
+            Some(metadata) => metadata.exists && metadata.is_link,
+            None => file.path().is_symlink(),
+        };
+        (self.negate ^ is_link).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+struct IsAbsoluteExtractor {
+    negate: bool,
+}
+impl Evaluator for IsAbsoluteExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        (self.negate ^ file.path().is_absolute()).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+struct HiddenExtractor {}
+impl Evaluator for HiddenExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        file.path()
+            .file_name()
+            .is_some_and(|name| name.as_encoded_bytes().starts_with(b"."))
+            .into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Bool
@@ -222,12 +421,13 @@ impl Evaluator for IsLinkExtractor {
 struct OwnerExtractor {}
 impl Evaluator for OwnerExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        let Ok(m) = fs::metadata(file.path()) else {
-            return Value::Empty;
-        };
-        get_user_by_uid(m.uid())
-            .map(|u| u.name().into())
-            .unwrap_or(Value::Empty)
+        if let Some(metadata) = file.metadata() {
+            return metadata.owner.clone().into();
+        }
+        fs::metadata(file.path())
+            .ok()
+            .and_then(|m| owner_name(&m))
+            .into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::String
@@ -236,48 +436,138 @@ impl Evaluator for OwnerExtractor {
 struct GroupExtractor {}
 impl Evaluator for GroupExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        let Ok(m) = fs::metadata(file.path()) else {
-            return Value::Empty;
-        };
-        get_group_by_gid(m.gid())
-            .map(|g| g.name().into())
-            .unwrap_or(Value::Empty)
+        if let Some(metadata) = file.metadata() {
+            return metadata.group.clone().into();
+        }
+        fs::metadata(file.path())
+            .ok()
+            .and_then(|m| group_name(&m))
+            .into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::String
     }
 }
 
+fn permissions(file: &FileWrapper) -> Option<u32> {
+    match file.metadata() {
+        Some(metadata) => metadata.permissions,
+        None => file.path().metadata().ok().and_then(|m| mode(&m)),
+    }
+}
+
 struct PermissionsExtractor {}
 impl Evaluator for PermissionsExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        file.path()
-            .metadata()
-            .map(|m| m.permissions().mode())
-            .into()
+        permissions(file).into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Number
     }
 }
 
-struct FilesExtractor {}
+/// Checks a single bit of the permissions, like `0o100` for the owner execute permission.
+struct PermissionBitExtractor {
+    mask: u32,
+}
+impl Evaluator for PermissionBitExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        permissions(file)
+            .map(|permissions| permissions & self.mask != 0)
+            .into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+
+/// The entries of a directory that a `FilesExtractor` lists.
+#[derive(Clone, Copy, PartialEq)]
+enum Entries {
+    /// All the entries, listed lazily.
+    All,
+    /// Only the regular files, listed eagerly.
+    Files,
+    /// Only the sub directories, listed eagerly.
+    Dirs,
+}
+
+struct FilesExtractor {
+    entries: Entries,
+}
 impl Evaluator for FilesExtractor {
     fn eval(&self, file: &FileWrapper) -> Value {
-        let Ok(paths) = fs::read_dir(file.path()) else {
+        let path = file.path();
+        if !path.exists() {
             return Value::Empty;
+        }
+        let no_files = || Value::List(List::new_from_vec(Arc::new(ValueType::Path), vec![]));
+        if !path.is_dir() {
+            return no_files();
+        }
+        let paths = match fs::read_dir(path) {
+            Ok(paths) => paths,
+            Err(err) => {
+                file.debugger()
+                    .log(&|| format!("Can not list the files of {}: {}", path.display(), err));
+                return no_files();
+            }
+        };
+        let debugger = file.debugger().clone();
+        let dir = path.clone();
+        let paths = paths.filter_map(move |entry| match entry {
+            Ok(entry) => Some(entry.path()),
+            Err(err) => {
+                debugger
+                    .log(&|| format!("Can not read a file entry of {}: {}", dir.display(), err));
+                None
+            }
+        });
+        let list = match self.entries {
+            Entries::All => List::new_lazy(Arc::new(ValueType::Path), paths.map(Value::from)),
+            Entries::Files => List::new_from_vec(
+                Arc::new(ValueType::Path),
+                paths
+                    .filter(|path| path.is_file())
+                    .map(Value::from)
+                    .collect(),
+            ),
+            Entries::Dirs => List::new_from_vec(
+                Arc::new(ValueType::Path),
+                paths
+                    .filter(|path| path.is_dir())
+                    .map(Value::from)
+                    .collect(),
+            ),
         };
-        let list = List::new_lazy(
-            Rc::new(ValueType::Path),
-            paths
-                .filter_map(Result::ok)
-                .map(|f| f.path())
-                .map(|f| f.into()),
-        );
         Value::List(list)
     }
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::Path))
+        ValueType::List(Arc::new(ValueType::Path))
+    }
+}
+
+struct IsEmptyDirExtractor {}
+impl Evaluator for IsEmptyDirExtractor {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let path = file.path();
+        if !path.exists() {
+            return Value::Empty;
+        }
+        if !path.is_dir() {
+            return false.into();
+        }
+        match fs::read_dir(path) {
+            Ok(mut entries) => entries.next().is_none().into(),
+            Err(err) => {
+                file.debugger()
+                    .log(&|| format!("Can not list the files of {}: {}", path.display(), err));
+                Value::Empty
+            }
+        }
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
     }
 }
 
@@ -285,9 +575,12 @@ impl Evaluator for FilesExtractor {
 mod tests {
     use std::{
         env,
+        os::unix::fs::PermissionsExt,
         path::{Path, PathBuf},
     };
 
+    use chrono::TimeDelta;
+
     use crate::{errors::FindItError, evaluators::expr::read_expr};
 
     use super::*;
@@ -306,6 +599,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_file_age() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "test")?;
+        let expr = read_expr("age")?;
+        let wrapper = FileWrapper::new(path, 1);
+
+        let Value::Duration(age) = expr.eval(&wrapper) else {
+            panic!("age of a file should be a duration");
+        };
+        assert!(age >= TimeDelta::zero() && age < TimeDelta::hours(1));
+        assert_eq!(read_expr("age < 1d")?.eval(&wrapper), Value::Bool(true));
+        assert_eq!(expr.expected_type(), ValueType::Duration);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_age_of_missing_file_is_empty() -> Result<(), FindItError> {
+        let expr = read_expr("age")?;
+        let wrapper = FileWrapper::new(PathBuf::from("/no/such/file"), 1);
+
+        assert_eq!(expr.eval(&wrapper), Value::Empty);
+
+        Ok(())
+    }
+
     fn test_expected_type(name: &str, expected: ValueType) -> Result<(), FindItError> {
         let expr = read_expr(name)?;
         let tp = expr.expected_type();
@@ -314,6 +635,196 @@ mod tests {
 
         Ok(())
     }
+    #[test]
+    fn test_files_of_directory() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("one.txt"), "1")?;
+        fs::write(dir.path().join("two.txt"), "2")?;
+        let expr = read_expr("files.map($f $f.name).sort()")?;
+        let wrapper = FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        let expected = read_expr("[\"one.txt\", \"two.txt\"]")?.eval(&wrapper);
+        assert_eq!(expr.eval(&wrapper), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_list_of_directory_skips_directories() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("one.txt"), "1")?;
+        fs::create_dir(dir.path().join("sub"))?;
+        fs::write(dir.path().join("sub").join("two.txt"), "2")?;
+        let expr = read_expr("file_list.map($f $f.name)")?;
+        let wrapper = FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        let expected = read_expr("[\"one.txt\"]")?.eval(&wrapper);
+        assert_eq!(expr.eval(&wrapper), expected);
+        assert_eq!(
+            read_expr("files.length()")?.eval(&wrapper),
+            Value::Number(2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dirs_of_directory() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("one.txt"), "1")?;
+        fs::create_dir(dir.path().join("sub"))?;
+        fs::create_dir(dir.path().join("other"))?;
+        let expr = read_expr("dirs.map($f $f.name).sort()")?;
+        let wrapper = FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        let expected = read_expr("[\"other\", \"sub\"]")?.eval(&wrapper);
+        assert_eq!(expr.eval(&wrapper), expected);
+        assert_eq!(
+            read_expr("files.length()")?.eval(&wrapper),
+            Value::Number(3)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dirs_of_file_and_missing_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "test")?;
+        let expr = read_expr("directories.length()")?;
+
+        assert_eq!(expr.eval(&FileWrapper::new(path, 1)), Value::Number(0));
+        assert_eq!(
+            expr.eval(&FileWrapper::new(PathBuf::from("/no/such/file"), 1)),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_empty_dir() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let empty = dir.path().join("empty");
+        fs::create_dir(&empty)?;
+        let full = dir.path().join("full");
+        fs::create_dir(&full)?;
+        fs::write(full.join("file.txt"), "test")?;
+        let expr = read_expr("is_empty_dir")?;
+
+        assert_eq!(expr.eval(&FileWrapper::new(empty, 1)), Value::Bool(true));
+        assert_eq!(
+            expr.eval(&FileWrapper::new(full.clone(), 1)),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(full.join("file.txt"), 1)),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(PathBuf::from("/no/such/file"), 1)),
+            Value::Empty
+        );
+        assert_eq!(
+            read_expr("IS DIR AND isEmptyDir")?.eval(&FileWrapper::new(full, 1)),
+            Value::Bool(false)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_child_count() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("a.txt"), "a")?;
+        fs::write(dir.path().join("b.txt"), "b")?;
+        let sub = dir.path().join("sub");
+        fs::create_dir(&sub)?;
+        fs::write(sub.join("c.txt"), "c")?;
+        fs::write(sub.join("d.txt"), "d")?;
+        fs::create_dir(sub.join("nested"))?;
+        let expr = read_expr("child_count")?;
+
+        assert_eq!(
+            expr.eval(&FileWrapper::new(dir.path().to_path_buf(), 1)),
+            Value::Number(3)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(sub.clone(), 2)),
+            Value::Number(3)
+        );
+        assert_eq!(
+            read_expr("childCount")?.eval(&FileWrapper::new(sub.join("nested"), 3)),
+            Value::Number(0)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(sub.join("c.txt"), 3)),
+            Value::Empty
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(PathBuf::from("/no/such/file"), 1)),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_child_count_expected_type() -> Result<(), FindItError> {
+        test_expected_type("child_count", ValueType::Number)
+    }
+
+    #[test]
+    fn test_is_empty_dir_expected_type() -> Result<(), FindItError> {
+        test_expected_type("is_empty_dir", ValueType::Bool)
+    }
+
+    #[test]
+    fn test_files_of_empty_directory() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let exe: Box<dyn Evaluator> = (&Access::Files).into();
+        let wrapper = FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        let Value::List(files) = exe.eval(&wrapper) else {
+            panic!("files of a directory should be a list");
+        };
+        assert!(!files.has_items());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_files_of_file_is_an_empty_list() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "test")?;
+        let exe: Box<dyn Evaluator> = (&Access::Files).into();
+        let wrapper = FileWrapper::new(path, 1);
+
+        let Value::List(files) = exe.eval(&wrapper) else {
+            panic!("files of a file should be a list");
+        };
+        assert!(!files.has_items());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_files_of_missing_path_is_empty() -> Result<(), FindItError> {
+        let exe: Box<dyn Evaluator> = (&Access::Files).into();
+        let wrapper = FileWrapper::new(PathBuf::from("no/such/file"), 1);
+
+        assert_eq!(exe.eval(&wrapper), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_files_expected_type() -> Result<(), FindItError> {
+        test_expected_type("files", ValueType::List(Arc::new(ValueType::Path)))
+    }
+
     #[test]
     fn test_self_expected_type() -> Result<(), FindItError> {
         test_expected_type("self", ValueType::Path)
@@ -394,6 +905,127 @@ mod tests {
         test_expected_type("is link", ValueType::Bool)
     }
 
+    #[test]
+    fn test_is_absolute_expected_type() -> Result<(), FindItError> {
+        test_expected_type("is_absolute", ValueType::Bool)
+    }
+
+    #[test]
+    fn test_is_relative_expected_type() -> Result<(), FindItError> {
+        test_expected_type("is_relative", ValueType::Bool)
+    }
+
+    #[test]
+    fn test_is_absolute_and_is_relative() -> Result<(), FindItError> {
+        let relative = FileWrapper::new(PathBuf::from("src/main.rs"), 1);
+        let absolute = FileWrapper::new(PathBuf::from("/tmp/main.rs"), 1);
+
+        assert_eq!(
+            read_expr("is_absolute")?.eval(&relative),
+            Value::Bool(false)
+        );
+        assert_eq!(read_expr("isRelative")?.eval(&relative), Value::Bool(true));
+        assert_eq!(read_expr("is_absolute")?.eval(&absolute), Value::Bool(true));
+        assert_eq!(
+            read_expr("is_relative")?.eval(&absolute),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            read_expr("absolute.is_absolute")?.eval(&relative),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            read_expr("(\"a/b\" AS PATH).is_relative")?.eval(&absolute),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_size() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        fs::create_dir_all(root.join("a").join("b"))?;
+        fs::create_dir(root.join("empty"))?;
+        fs::write(root.join("one.txt"), "1")?;
+        fs::write(root.join("a").join("two.txt"), "22")?;
+        fs::write(root.join("a").join("b").join("three.txt"), "333")?;
+        std::os::unix::fs::symlink(root, root.join("a").join("loop"))?;
+        let expr = read_expr("total_size")?;
+
+        assert_eq!(
+            expr.eval(&FileWrapper::new(root.to_path_buf(), 1)),
+            Value::Number(6)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(root.join("a"), 1)),
+            Value::Number(5)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(root.join("empty"), 1)),
+            Value::Number(0)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(root.join("a").join("two.txt"), 1)),
+            Value::Number(2)
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(root.join("no_such_file"), 1)),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_size_is_calculated_once() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("one.txt"), "1")?;
+        let expr = read_expr("totalSize")?;
+        let wrapper = FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        assert_eq!(expr.eval(&wrapper), Value::Number(1));
+        fs::write(dir.path().join("two.txt"), "22")?;
+        assert_eq!(expr.eval(&wrapper), Value::Number(1));
+        assert_eq!(read_expr("totalSize")?.eval(&wrapper), Value::Number(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_size_expected_type() -> Result<(), FindItError> {
+        test_expected_type("total_size", ValueType::Number)
+    }
+
+    #[test]
+    fn test_hidden() -> Result<(), FindItError> {
+        let expr = read_expr("hidden")?;
+
+        for (path, hidden) in [
+            (".env", true),
+            ("src/.config/app.toml", false),
+            ("src/.config", true),
+            ("src/main.rs", false),
+            (".", false),
+            ("..", false),
+        ] {
+            let wrapper = FileWrapper::new(PathBuf::from(path), 1);
+            assert_eq!(expr.eval(&wrapper), Value::Bool(hidden), "{path}");
+        }
+        assert_eq!(
+            read_expr("parent.is_hidden")?.eval(&FileWrapper::new(PathBuf::from(".a/b"), 1)),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hidden_expected_type() -> Result<(), FindItError> {
+        test_expected_type("hidden", ValueType::Bool)
+    }
+
     #[test]
     fn test_owner_expected_type() -> Result<(), FindItError> {
         test_expected_type("owner", ValueType::String)
@@ -404,6 +1036,71 @@ mod tests {
         test_expected_type("group", ValueType::String)
     }
 
+    #[test]
+    fn test_permission_bits() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("script.sh");
+        fs::write(&path, "echo")?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o754))?;
+        let file = &FileWrapper::new(path, 1);
+
+        for (name, expected) in [
+            ("owner_read", true),
+            ("owner_write", true),
+            ("owner_execute", true),
+            ("group_read", true),
+            ("group_write", false),
+            ("group_execute", true),
+            ("others_read", true),
+            ("others_write", false),
+            ("others_execute", false),
+            ("ownerExecute", true),
+            ("othersWrite", false),
+        ] {
+            assert_eq!(read_expr(name)?.eval(file), Value::Bool(expected), "{name}");
+        }
+        assert_eq!(
+            read_expr("group_read")?.eval(&FileWrapper::new(PathBuf::from("/no/such/file"), 1)),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_special_permission_bits() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("shared");
+        fs::create_dir(&path)?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o1777))?;
+        let file = &FileWrapper::new(path, 1);
+
+        for (name, expected) in [
+            ("sticky", true),
+            ("setuid", false),
+            ("suid", false),
+            ("setgid", false),
+            ("sgid", false),
+        ] {
+            assert_eq!(read_expr(name)?.eval(file), Value::Bool(expected), "{name}");
+        }
+        assert_eq!(
+            read_expr("setuid")?.eval(&FileWrapper::new(PathBuf::from("/no/such/file"), 1)),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_permission_bits_expected_type() -> Result<(), FindItError> {
+        test_expected_type("owner_execute", ValueType::Bool)?;
+        test_expected_type("group_read", ValueType::Bool)?;
+        test_expected_type("others_write", ValueType::Bool)?;
+        test_expected_type("setuid", ValueType::Bool)?;
+        test_expected_type("sticky", ValueType::Bool)
+    }
+
     #[test]
     fn test_permissions_expected_type() -> Result<(), FindItError> {
         test_expected_type("permissions", ValueType::Number)
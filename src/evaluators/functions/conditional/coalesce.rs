@@ -30,13 +30,18 @@ impl Evaluator for Coalesce {
 pub(crate) fn build_coalesce(
     args: VecDeque<Box<dyn Evaluator>>,
 ) -> Result<Box<dyn Evaluator>, FindItError> {
-    let Some(value_type) = args.iter().next().map(|e| e.expected_type()) else {
+    if args.is_empty() {
         return Err(FindItError::BadExpression(
             "coalesce must have arguments.".into(),
         ));
-    };
+    }
+    let value_type = args
+        .iter()
+        .map(|e| e.expected_type())
+        .find(|t| *t != ValueType::Empty)
+        .unwrap_or(ValueType::Empty);
     for a in &args {
-        if a.expected_type() != value_type {
+        if a.expected_type() != value_type && a.expected_type() != ValueType::Empty {
             return Err(FindItError::BadExpression(
                 "All the coalesce arguments must have the same type.".into(),
             ));
@@ -45,6 +50,45 @@ pub(crate) fn build_coalesce(
     Ok(Box::new(Coalesce { args, value_type }))
 }
 
+struct CoalesceList {
+    list: Box<dyn Evaluator>,
+    value_type: ValueType,
+}
+
+impl Evaluator for CoalesceList {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(list) = self.list.eval(file) else {
+            return Value::Empty;
+        };
+        list.items()
+            .into_iter()
+            .find(|value| value != &Value::Empty)
+            .unwrap_or(Value::Empty)
+    }
+    fn expected_type(&self) -> ValueType {
+        self.value_type.clone()
+    }
+}
+
+pub(crate) fn build_coalesce_list(
+    mut args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let Some(list) = args.pop_front().filter(|_| args.is_empty()) else {
+        return Err(FindItError::BadExpression(
+            "coalesce_list must have exactly one argument.".into(),
+        ));
+    };
+    let ValueType::List(item_type) = list.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "coalesce_list argument must be a list.".into(),
+        ));
+    };
+    Ok(Box::new(CoalesceList {
+        list,
+        value_type: item_type.as_ref().clone(),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -107,4 +151,74 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn coalesce_list_with_no_args() {
+        let sql = "coalesce_list()";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn coalesce_list_with_two_args() {
+        let sql = "coalesce_list([1], [2])";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn coalesce_list_with_no_list() {
+        let sql = "coalesce_list(1)";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn coalesce_list_return_value() -> Result<(), FindItError> {
+        let sql = "coalesce_list([\"a\"])";
+        let eval = read_expr(sql)?;
+
+        assert_eq!(eval.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_list_return_first_non_empty_item() -> Result<(), FindItError> {
+        let sql = "coalesce_list([parent.content, content, \"text\", \"other\"])";
+        let eval = read_expr(sql)?;
+        let file = Path::new("no/such/file.txt");
+        let wrapper = FileWrapper::new(file.to_path_buf(), 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::String("text".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_list_return_empty_when_all_empty() -> Result<(), FindItError> {
+        let sql = "coalesce_list([parent.content, content])";
+        let eval = read_expr(sql)?;
+        let file = Path::new("no/such/file.txt");
+        let wrapper = FileWrapper::new(file.to_path_buf(), 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn coalesce_list_return_empty_for_empty_list() -> Result<(), FindItError> {
+        let sql = "coalesce_list(files)";
+        let eval = read_expr(sql)?;
+        let file = Path::new("no/such/file.txt");
+        let wrapper = FileWrapper::new(file.to_path_buf(), 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::Empty);
+
+        Ok(())
+    }
 }
@@ -37,7 +37,10 @@ impl Evaluator for IfWithElse {
         }
     }
     fn expected_type(&self) -> ValueType {
-        self.then.expected_type()
+        match self.then.expected_type() {
+            ValueType::Empty => self.else_branch.expected_type(),
+            value_type => value_type,
+        }
     }
 }
 
@@ -52,7 +55,10 @@ impl EvaluatorFactory for IfExpression {
         let then = self.then_branch.build(bindings)?;
         if let Some(else_branch) = &self.else_branch {
             let else_branch = else_branch.build(bindings)?;
-            if else_branch.expected_type() != then.expected_type() {
+            if else_branch.expected_type() != then.expected_type()
+                && else_branch.expected_type() != ValueType::Empty
+                && then.expected_type() != ValueType::Empty
+            {
                 return Err(FindItError::BadExpression(
                     "IF results must be the same.".into(),
                 ));
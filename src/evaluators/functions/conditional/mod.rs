@@ -1,4 +1,6 @@
 pub(crate) mod case;
 pub(crate) mod coalesce;
 pub(crate) mod if_func;
+pub(crate) mod new_uuid;
+pub(crate) mod null_if;
 pub(crate) mod random;
@@ -0,0 +1,91 @@
+use std::collections::VecDeque;
+
+use uuid::Uuid;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+struct NewUuid {}
+impl Evaluator for NewUuid {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, _: &FileWrapper) -> Value {
+        Value::String(Uuid::new_v4().to_string())
+    }
+}
+
+pub(crate) fn build_uuid(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if !args.is_empty() {
+        return Err(FindItError::BadExpression("UUID with arguments.".into()));
+    }
+
+    Ok(Box::new(NewUuid {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use uuid::{Uuid, Version};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn uuid_with_args() {
+        let sql = "UUID(1)";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn uuid_expected_value() {
+        let sql = "uuid()";
+        let eval = read_expr(sql).unwrap();
+
+        assert_eq!(eval.expected_type(), ValueType::String);
+    }
+
+    #[test]
+    fn uuid_execution() -> Result<(), FindItError> {
+        let sql = "NEW_UUID()";
+        let eval = read_expr(sql).unwrap();
+
+        let file = env::current_dir()?;
+        let wrapper = FileWrapper::new(file, 1);
+
+        let Value::String(value) = eval.eval(&wrapper) else {
+            panic!("Not a string!")
+        };
+        assert_eq!(value.len(), 36);
+        let uuid = Uuid::parse_str(&value).unwrap();
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_is_new_on_every_invocation() -> Result<(), FindItError> {
+        let sql = "uuid() = uuid()";
+        let eval = read_expr(sql).unwrap();
+
+        let file = env::current_dir()?;
+        let wrapper = FileWrapper::new(file, 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::Bool(false));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+struct NullIf {
+    value: Box<dyn Evaluator>,
+    comparand: Box<dyn Evaluator>,
+}
+
+impl Evaluator for NullIf {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let value = self.value.eval(file);
+        if value == self.comparand.eval(file) {
+            Value::Empty
+        } else {
+            value
+        }
+    }
+    fn expected_type(&self) -> ValueType {
+        self.value.expected_type()
+    }
+}
+
+pub(crate) fn build_null_if(
+    mut args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let (Some(value), Some(comparand), None) =
+        (args.pop_front(), args.pop_front(), args.pop_front())
+    else {
+        return Err(FindItError::BadExpression(
+            "nullif must have exactly two arguments.".into(),
+        ));
+    };
+    if value.expected_type() != comparand.expected_type() {
+        return Err(FindItError::BadExpression(
+            "nullif arguments must have the same type.".into(),
+        ));
+    }
+    Ok(Box::new(NullIf { value, comparand }))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn nullif_with_no_args() {
+        let sql = "nullif()";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn nullif_with_one_arg() {
+        let sql = "nullif(1)";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn nullif_with_three_args() {
+        let sql = "nullif(1, 2, 3)";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn nullif_with_args_with_different_type() {
+        let sql = "nullif(1, \"1\")";
+        let err = read_expr(sql).err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn nullif_return_value() -> Result<(), FindItError> {
+        let sql = "nullif(1, 2)";
+        let eval = read_expr(sql)?;
+
+        assert_eq!(eval.expected_type(), ValueType::Number);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_return_empty_when_equal() -> Result<(), FindItError> {
+        let sql = "nullif(\"a\", \"a\")";
+        let eval = read_expr(sql)?;
+        let wrapper = FileWrapper::new(Path::new("no/such/file.txt").to_path_buf(), 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_return_first_when_not_equal() -> Result<(), FindItError> {
+        let sql = "nullif(10, 0)";
+        let eval = read_expr(sql)?;
+        let wrapper = FileWrapper::new(Path::new("no/such/file.txt").to_path_buf(), 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::Number(10));
+
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_return_first_when_comparand_is_empty() -> Result<(), FindItError> {
+        let sql = "nullif(\"a\", content)";
+        let eval = read_expr(sql)?;
+        let wrapper = FileWrapper::new(Path::new("no/such/file.txt").to_path_buf(), 1);
+
+        assert_eq!(eval.eval(&wrapper), Value::String("a".into()));
+
+        Ok(())
+    }
+}
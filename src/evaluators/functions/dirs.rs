@@ -0,0 +1,145 @@
+use std::{collections::VecDeque, env, path::PathBuf};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    platform::current_user_home,
+    value::{Value, ValueType},
+};
+
+fn home_dir() -> Option<PathBuf> {
+    match env::var_os("HOME") {
+        Some(home) if !home.is_empty() => Some(PathBuf::from(home)),
+        _ => current_user_home(),
+    }
+}
+
+struct HomeDir {}
+impl Evaluator for HomeDir {
+    fn eval(&self, _: &FileWrapper) -> Value {
+        home_dir().into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Path
+    }
+}
+
+struct TempDir {}
+impl Evaluator for TempDir {
+    fn eval(&self, _: &FileWrapper) -> Value {
+        Value::Path(env::temp_dir())
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Path
+    }
+}
+
+pub(crate) fn build_home_dir(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if !args.is_empty() {
+        return Err(FindItError::BadExpression(
+            "HOME_DIR with arguments.".into(),
+        ));
+    }
+
+    Ok(Box::new(HomeDir {}))
+}
+
+pub(crate) fn build_temp_dir(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if !args.is_empty() {
+        return Err(FindItError::BadExpression(
+            "TEMP_DIR with arguments.".into(),
+        ));
+    }
+
+    Ok(Box::new(TempDir {}))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::Path};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::{expr::read_expr, functions::dirs::home_dir},
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn home_dir_return_the_home_directory() -> Result<(), FindItError> {
+        let expr = read_expr("home_dir()")?;
+        let file = FileWrapper::new(Path::new("/no").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(&file), home_dir().into());
+        assert_ne!(expr.eval(&file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn home_return_the_home_directory() -> Result<(), FindItError> {
+        let expr = read_expr("home()")?;
+        let file = FileWrapper::new(Path::new("/no").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(&file), home_dir().into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn home_dir_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("home_dir()")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn home_dir_with_args() {
+        let err = read_expr("home_dir(1)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn temp_dir_return_the_temp_directory() -> Result<(), FindItError> {
+        let expr = read_expr("tmpdir()")?;
+        let file = FileWrapper::new(Path::new("/no").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(&file), Value::Path(env::temp_dir()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn temp_dir_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("temp_dir()")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn temp_dir_with_args() {
+        let err = read_expr("temp_dir(\"a\")").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn depth_in_home_dir() -> Result<(), FindItError> {
+        let expr = read_expr("me.depth_in(home_dir())")?;
+        let file = FileWrapper::new(home_dir().unwrap().join("a.txt"), 1);
+
+        assert_eq!(expr.eval(&file), Value::Number(0));
+
+        Ok(())
+    }
+}
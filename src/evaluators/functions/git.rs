@@ -0,0 +1,257 @@
+use std::{
+    collections::VecDeque,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+const GIT_DIR: &str = ".git";
+const BRANCH_REF_PREFIX: &str = "ref: refs/heads/";
+const GIT_DIR_PREFIX: &str = "gitdir: ";
+
+fn find_git_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(GIT_DIR).exists())
+        .map(Path::to_path_buf)
+}
+
+fn find_git_dir(root: &Path) -> Option<PathBuf> {
+    let git = root.join(GIT_DIR);
+    if git.is_dir() {
+        return Some(git);
+    }
+    // Work trees and sub modules have a .git file that points to the real git directory.
+    let content = fs::read_to_string(&git).ok()?;
+    let dir = content.trim().strip_prefix(GIT_DIR_PREFIX)?;
+    Some(root.join(dir))
+}
+
+fn read_branch(root: &Path) -> Option<String> {
+    let head = fs::read_to_string(find_git_dir(root)?.join("HEAD")).ok()?;
+    head.trim()
+        .strip_prefix(BRANCH_REF_PREFIX)
+        .map(|branch| branch.to_string())
+}
+
+fn current_git_root() -> Option<PathBuf> {
+    find_git_root(&env::current_dir().ok()?)
+}
+
+struct GitRoot {
+    root: OnceLock<Value>,
+}
+impl Evaluator for GitRoot {
+    fn eval(&self, _: &FileWrapper) -> Value {
+        self.root.get_or_init(|| current_git_root().into()).clone()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Path
+    }
+}
+
+struct GitBranch {
+    branch: OnceLock<Value>,
+}
+impl Evaluator for GitBranch {
+    fn eval(&self, _: &FileWrapper) -> Value {
+        self.branch
+            .get_or_init(|| {
+                current_git_root()
+                    .and_then(|root| read_branch(&root))
+                    .into()
+            })
+            .clone()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+}
+
+pub(crate) fn build_git_root(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if !args.is_empty() {
+        return Err(FindItError::BadExpression(
+            "GIT_ROOT with arguments.".into(),
+        ));
+    }
+
+    Ok(Box::new(GitRoot {
+        root: OnceLock::new(),
+    }))
+}
+
+pub(crate) fn build_git_branch(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if !args.is_empty() {
+        return Err(FindItError::BadExpression(
+            "GIT_BRANCH with arguments.".into(),
+        ));
+    }
+
+    Ok(Box::new(GitBranch {
+        branch: OnceLock::new(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::{
+            expr::read_expr,
+            functions::git::{current_git_root, find_git_root, read_branch},
+        },
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn find_git_root_from_sub_directory() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join(".git"))?;
+        fs::create_dir_all(dir.path().join("a/b"))?;
+
+        assert_eq!(
+            find_git_root(&dir.path().join("a/b")),
+            Some(dir.path().to_path_buf())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_git_root_with_no_repository() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("a"))?;
+
+        let root = find_git_root(&dir.path().join("a"));
+
+        assert!(root.is_none_or(|root| !root.starts_with(dir.path())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_branch_from_head() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join(".git"))?;
+        fs::write(dir.path().join(".git/HEAD"), "ref: refs/heads/feature/x\n")?;
+
+        assert_eq!(read_branch(dir.path()), Some("feature/x".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_branch_of_detached_head() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join(".git"))?;
+        fs::write(
+            dir.path().join(".git/HEAD"),
+            "4dbafc9b7f4bcd1c3d0ea3e1c1b8f5b7e5a7a1c2\n",
+        )?;
+
+        assert_eq!(read_branch(dir.path()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_branch_of_work_tree() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("repo/.git/worktrees/wt"))?;
+        fs::write(
+            dir.path().join("repo/.git/worktrees/wt/HEAD"),
+            "ref: refs/heads/wt-branch\n",
+        )?;
+        fs::create_dir_all(dir.path().join("wt"))?;
+        fs::write(
+            dir.path().join("wt/.git"),
+            "gitdir: ../repo/.git/worktrees/wt\n",
+        )?;
+
+        assert_eq!(
+            read_branch(&dir.path().join("wt")),
+            Some("wt-branch".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_branch_with_no_repository() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+
+        assert_eq!(read_branch(dir.path()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_root_return_the_current_root() -> Result<(), FindItError> {
+        let expr = read_expr("git_root()")?;
+        let file = FileWrapper::new(Path::new("/no").to_path_buf(), 1);
+        let expected: Value = find_git_root(&env::current_dir()?).into();
+
+        assert_eq!(expr.eval(&file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_branch_return_the_current_branch() -> Result<(), FindItError> {
+        let expr = read_expr("gitBranch()")?;
+        let file = FileWrapper::new(Path::new("/no").to_path_buf(), 1);
+        let expected: Value = current_git_root()
+            .and_then(|root| read_branch(&root))
+            .into();
+
+        assert_eq!(expr.eval(&file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_root_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("git_root()")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_branch_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("git_branch()")?;
+
+        assert_eq!(expr.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn git_root_with_args() {
+        let err = read_expr("git_root(1)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn git_branch_with_args() {
+        let err = read_expr("git_branch(\"main\")").err();
+
+        assert!(err.is_some());
+    }
+}
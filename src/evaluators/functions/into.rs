@@ -5,15 +5,30 @@ use crate::{
     evaluators::{
         expr::{BindingsTypes, Evaluator, EvaluatorFactory},
         functions::{
-            conditional::{coalesce::build_coalesce, random::build_rand},
+            conditional::{
+                coalesce::{build_coalesce, build_coalesce_list},
+                new_uuid::build_uuid,
+                null_if::build_null_if,
+                random::build_rand,
+            },
+            dirs::{build_home_dir, build_temp_dir},
             env::build_env,
-            spawn::exec::build_capture_output_exec,
-            time::now::build_now,
+            git::{build_git_branch, build_git_root},
+            number::{
+                format_number::build_format_number,
+                math::{build_fibonacci, build_is_prime},
+            },
+            spawn::exec::{build_capture_output_exec, build_exec_capture},
+            time::{
+                calendar::{build_day_of_year, build_is_leap_year},
+                duration::build_duration,
+                now::build_now,
+            },
         },
     },
     parser::ast::{
         function::Function,
-        function_name::{EnvFunctionName, FunctionName, TimeFunctionName},
+        function_name::{EnvFunctionName, FunctionName, NumberFunctionName, TimeFunctionName},
     },
 };
 
@@ -28,6 +43,7 @@ impl EvaluatorFactory for Function {
         match &self.name {
             FunctionName::Env(env) => new_env_function(env, args),
             FunctionName::Time(time) => new_time_function(time, args),
+            FunctionName::Number(number) => new_number_function(number, args),
         }
     }
 }
@@ -38,9 +54,17 @@ fn new_env_function(
 ) -> Result<Box<dyn Evaluator>, FindItError> {
     match name {
         EnvFunctionName::Rand => build_rand(args),
+        EnvFunctionName::Uuid => build_uuid(args),
         EnvFunctionName::Coalesce => build_coalesce(args),
+        EnvFunctionName::CoalesceList => build_coalesce_list(args),
+        EnvFunctionName::NullIf => build_null_if(args),
         EnvFunctionName::Env => build_env(args),
         EnvFunctionName::ExecOut => build_capture_output_exec(args),
+        EnvFunctionName::ExecCapture => build_exec_capture(args),
+        EnvFunctionName::GitRoot => build_git_root(args),
+        EnvFunctionName::GitBranch => build_git_branch(args),
+        EnvFunctionName::HomeDir => build_home_dir(args),
+        EnvFunctionName::TempDir => build_temp_dir(args),
     }
 }
 
@@ -50,6 +74,20 @@ fn new_time_function(
 ) -> Result<Box<dyn Evaluator>, FindItError> {
     match name {
         TimeFunctionName::Now => build_now(args),
+        TimeFunctionName::DayOfYear => build_day_of_year(args),
+        TimeFunctionName::IsLeapYear => build_is_leap_year(args),
+        TimeFunctionName::Duration => build_duration(args),
+    }
+}
+
+fn new_number_function(
+    name: &NumberFunctionName,
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match name {
+        NumberFunctionName::FormatNumber => build_format_number(args),
+        NumberFunctionName::IsPrime => build_is_prime(args),
+        NumberFunctionName::Fibonacci => build_fibonacci(args),
     }
 }
 
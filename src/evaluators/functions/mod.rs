@@ -1,6 +1,9 @@
 pub(crate) mod conditional;
+pub(crate) mod dirs;
 pub(crate) mod env;
+pub(crate) mod git;
 pub(crate) mod into;
+pub(crate) mod number;
 pub(crate) mod spawn;
 pub(crate) mod string_functions;
 pub(crate) mod time;
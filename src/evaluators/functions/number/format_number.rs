@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+const DEFAULT_SEPARATOR: &str = ",";
+
+struct FormatNumber {
+    number: Box<dyn Evaluator>,
+    decimals: Option<Box<dyn Evaluator>>,
+    separator: Option<Box<dyn Evaluator>>,
+}
+
+fn group_digits(number: u64, separator: &str) -> String {
+    let digits = number.to_string();
+    let mut result = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            result.push_str(separator);
+        }
+        result.push(digit);
+    }
+    result
+}
+
+impl Evaluator for FormatNumber {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(number) = self.number.eval(file) else {
+            return Value::Empty;
+        };
+        let decimals = match &self.decimals {
+            None => 0,
+            Some(decimals) => {
+                let Value::Number(decimals) = decimals.eval(file) else {
+                    return Value::Empty;
+                };
+                decimals as usize
+            }
+        };
+        let separator = match &self.separator {
+            None => DEFAULT_SEPARATOR.to_string(),
+            Some(separator) => {
+                let Value::String(separator) = separator.eval(file) else {
+                    return Value::Empty;
+                };
+                separator
+            }
+        };
+        let mut formatted = group_digits(number, &separator);
+        if decimals > 0 {
+            formatted.push('.');
+            formatted.push_str(&"0".repeat(decimals));
+        }
+        Value::String(formatted)
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+}
+
+pub(crate) fn build_format_number(
+    mut args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if args.len() > 3 {
+        return Err(FindItError::BadExpression(
+            "FORMAT_NUMBER can not handle more than three arguments.".into(),
+        ));
+    }
+    let Some(number) = args.pop_front() else {
+        return Err(FindItError::BadExpression(
+            "FORMAT_NUMBER must have at least one argument.".into(),
+        ));
+    };
+    if number.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "FORMAT_NUMBER first argument must be a number.".into(),
+        ));
+    }
+    let decimals = args.pop_front();
+    if decimals
+        .as_ref()
+        .is_some_and(|d| d.expected_type() != ValueType::Number)
+    {
+        return Err(FindItError::BadExpression(
+            "FORMAT_NUMBER decimal places must be a number.".into(),
+        ));
+    }
+    let separator = args.pop_front();
+    if separator
+        .as_ref()
+        .is_some_and(|s| s.expected_type() != ValueType::String)
+    {
+        return Err(FindItError::BadExpression(
+            "FORMAT_NUMBER separator must be a string.".into(),
+        ));
+    }
+
+    Ok(Box::new(FormatNumber {
+        number,
+        decimals,
+        separator,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        Ok(expr.eval(&file))
+    }
+
+    #[test]
+    fn format_number_with_defaults() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("format_number(1234567890)")?,
+            Value::String("1,234,567,890".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_number_with_separator() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("format_number(1073741824, 0, \"_\")")?,
+            Value::String("1_073_741_824".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_number_with_decimals() -> Result<(), FindItError> {
+        assert_eq!(eval("format_number(3, 2)")?, Value::String("3.00".into()));
+        assert_eq!(
+            eval("format_number(12345, 1, \"\")")?,
+            Value::String("12345.0".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_number_small_numbers() -> Result<(), FindItError> {
+        assert_eq!(eval("format_number(0)")?, Value::String("0".into()));
+        assert_eq!(eval("format_number(999)")?, Value::String("999".into()));
+        assert_eq!(eval("format_number(1000)")?, Value::String("1,000".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_number_empty_values() -> Result<(), FindItError> {
+        assert_eq!(eval("format_number(size)")?, Value::Empty);
+        assert_eq!(eval("format_number(1, size)")?, Value::Empty);
+        assert_eq!(eval("format_number(1, 0, content)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_number_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("format_number(1)")?;
+
+        assert_eq!(expr.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_number_with_bad_arguments() {
+        assert!(read_expr("format_number()").is_err());
+        assert!(read_expr("format_number(\"1\")").is_err());
+        assert!(read_expr("format_number(1, \"2\")").is_err());
+        assert!(read_expr("format_number(1, 2, 3)").is_err());
+        assert!(read_expr("format_number(1, 2, \"3\", 4)").is_err());
+    }
+}
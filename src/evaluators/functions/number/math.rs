@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+
+use crate::{
+    errors::FindItError,
+    evaluators::{expr::Evaluator, functions::time::calendar::single_argument},
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+fn is_prime(number: u64) -> bool {
+    if number < 4 {
+        return number > 1;
+    }
+    if number.is_multiple_of(2) || number.is_multiple_of(3) {
+        return false;
+    }
+    // All the primes above 3 are of the form 6k ± 1.
+    (5..)
+        .step_by(6)
+        .take_while(|divider: &u64| divider.saturating_mul(*divider) <= number)
+        .all(|divider| !number.is_multiple_of(divider) && !number.is_multiple_of(divider + 2))
+}
+
+fn fibonacci(index: u64) -> Option<u64> {
+    let mut current: u64 = 0;
+    let mut next = Some(1);
+    for _ in 0..index {
+        let value = next?;
+        next = current.checked_add(value);
+        current = value;
+    }
+    Some(current)
+}
+
+struct IsPrime {
+    number: Box<dyn Evaluator>,
+}
+
+impl Evaluator for IsPrime {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(number) = self.number.eval(file) else {
+            return Value::Empty;
+        };
+        is_prime(number).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+
+struct Fibonacci {
+    index: Box<dyn Evaluator>,
+}
+
+impl Evaluator for Fibonacci {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(index) = self.index.eval(file) else {
+            return Value::Empty;
+        };
+        fibonacci(index).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+}
+
+pub(crate) fn build_is_prime(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let number = single_argument("IS_PRIME", ValueType::Number, args)?;
+
+    Ok(Box::new(IsPrime { number }))
+}
+
+pub(crate) fn build_fibonacci(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let index = single_argument("FIBONACCI", ValueType::Number, args)?;
+
+    Ok(Box::new(Fibonacci { index }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        Ok(expr.eval(&file))
+    }
+
+    #[test]
+    fn is_prime_for_small_numbers() -> Result<(), FindItError> {
+        let primes = (0..30)
+            .filter(|n| matches!(eval(&format!("is_prime({n})")), Ok(Value::Bool(true))))
+            .collect::<Vec<_>>();
+
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_prime_for_large_numbers() -> Result<(), FindItError> {
+        assert_eq!(eval("is_prime(1000000007)")?, Value::Bool(true));
+        assert_eq!(eval("is_prime(1000000007 * 3)")?, Value::Bool(false));
+        assert_eq!(eval("is_prime(25)")?, Value::Bool(false));
+        assert_eq!(eval("is_prime(49)")?, Value::Bool(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_prime_for_empty() -> Result<(), FindItError> {
+        assert_eq!(eval("is_prime(size)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_prime_expected_type() -> Result<(), FindItError> {
+        assert_eq!(read_expr("is_prime(3)")?.expected_type(), ValueType::Bool);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_prime_bad_arguments() {
+        assert!(read_expr("is_prime()").is_err());
+        assert!(read_expr("is_prime(\"3\")").is_err());
+        assert!(read_expr("is_prime(3, 5)").is_err());
+    }
+
+    #[test]
+    fn fibonacci_values() -> Result<(), FindItError> {
+        let values = (0..10)
+            .map(|n| eval(&format!("fibonacci({n})")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(
+            values,
+            [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]
+                .into_iter()
+                .map(Value::Number)
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fibonacci_largest_value() -> Result<(), FindItError> {
+        assert_eq!(eval("fibonacci(93)")?, Value::Number(12200160415121876738));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fibonacci_overflow() -> Result<(), FindItError> {
+        assert_eq!(eval("fibonacci(94)")?, Value::Empty);
+        assert_eq!(eval("fibonacci(1000000)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fibonacci_for_empty() -> Result<(), FindItError> {
+        assert_eq!(eval("fibonacci(size)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fibonacci_expected_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("fibonacci(3)")?.expected_type(),
+            ValueType::Number
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fibonacci_bad_arguments() {
+        assert!(read_expr("fibonacci()").is_err());
+        assert!(read_expr("fibonacci(true)").is_err());
+        assert!(read_expr("fibonacci(1, 2)").is_err());
+    }
+}
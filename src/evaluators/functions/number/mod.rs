@@ -0,0 +1,2 @@
+pub(crate) mod format_number;
+pub(crate) mod math;
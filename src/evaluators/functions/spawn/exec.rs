@@ -13,6 +13,8 @@ pub(super) enum ExecType {
     Status,
     IntoStatus,
     CaptureOutput,
+    /// Like `CaptureOutput`, but empty if the command failed, and without the trailing new line.
+    Capture,
 }
 struct Execute {
     executor: Executor,
@@ -21,7 +23,7 @@ struct Execute {
 
 impl Evaluator for Execute {
     fn expected_type(&self) -> ValueType {
-        if self.exec_type == ExecType::CaptureOutput {
+        if matches!(self.exec_type, ExecType::CaptureOutput | ExecType::Capture) {
             ValueType::String
         } else {
             ValueType::Bool
@@ -36,6 +38,23 @@ impl Evaluator for Execute {
                 return Value::Empty;
             };
             String::from_utf8(output.stdout).into()
+        } else if self.exec_type == ExecType::Capture {
+            let Some(output) = command.output().ok() else {
+                return Value::Empty;
+            };
+            if !output.status.success() {
+                return Value::Empty;
+            }
+            let Ok(mut text) = String::from_utf8(output.stdout) else {
+                return Value::Empty;
+            };
+            if text.ends_with('\n') {
+                text.pop();
+                if text.ends_with('\r') {
+                    text.pop();
+                }
+            }
+            text.into()
         } else {
             let Some(status) = command.status().ok() else {
                 return Value::Empty;
@@ -46,7 +65,20 @@ impl Evaluator for Execute {
 }
 
 pub(crate) fn build_capture_output_exec(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    build_output_exec(args, ExecType::CaptureOutput)
+}
+
+pub(crate) fn build_exec_capture(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    build_output_exec(args, ExecType::Capture)
+}
+
+fn build_output_exec(
     mut args: VecDeque<Box<dyn Evaluator>>,
+    exec_type: ExecType,
 ) -> Result<Box<dyn Evaluator>, FindItError> {
     let Some(exec) = args.pop_front() else {
         return Err(FindItError::BadExpression(
@@ -60,7 +92,7 @@ pub(crate) fn build_capture_output_exec(
     }
     let into = None;
     let executor = Executor::new(exec, args.into(), into);
-    Ok(build_exec(executor, ExecType::CaptureOutput))
+    Ok(build_exec(executor, exec_type))
 }
 
 pub(super) fn build_exec(executor: Executor, exec_type: ExecType) -> Box<dyn Evaluator> {
@@ -165,6 +197,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_exec_capture_trims_the_new_line() -> Result<(), FindItError> {
+        let expr = read_expr("exec_capture(\"echo\", \"one\", name)")?;
+        let wrapper = FileWrapper::new(Path::new("dir/two.txt").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(&wrapper), Value::String("one two.txt".into()));
+        assert_eq!(
+            read_expr("capture(@printf, \"a\\nb\\n\\n\")")?.eval(&wrapper),
+            Value::String("a\nb\n".into())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exec_capture_is_empty_on_failure() -> Result<(), FindItError> {
+        let wrapper = FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(
+            read_expr("execCapture(@ls, path)")?.eval(&wrapper),
+            Value::Empty
+        );
+        assert_eq!(
+            read_expr("execCapture(\"nothing_to_run\")")?.eval(&wrapper),
+            Value::Empty
+        );
+        assert_eq!(
+            read_expr("execCapture(@printf, \"\\\\377\")")?.eval(&wrapper),
+            Value::Empty
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exec_capture_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("execCapture(@echo)")?.expected_type(),
+            ValueType::String
+        );
+        assert!(read_expr("execCapture()").is_err());
+        assert!(read_expr("execCapture(12)").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_exec_into_return_true() -> Result<(), FindItError> {
         let dir = tempdir()?;
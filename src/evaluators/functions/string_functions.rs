@@ -8,6 +8,17 @@ use crate::{
     value::{Value, ValueType},
 };
 
+/// Compile the pattern, reporting a failure if it is not a valid regular expression.
+pub(crate) fn compile_regex(pattern: &str, file: &FileWrapper) -> Option<Regex> {
+    match Regex::new(pattern) {
+        Ok(regex) => Some(regex),
+        Err(err) => {
+            file.report(&|| format!("Bad regular expression [{pattern}]: {err}"));
+            None
+        }
+    }
+}
+
 pub(crate) fn new_regex(
     expr: Box<dyn Evaluator>,
     pattern: Box<dyn Evaluator>,
@@ -17,19 +28,79 @@ pub(crate) fn new_regex(
             "REGULAR expressions can only work with strings".into(),
         ));
     }
+    match pattern.expected_type() {
+        ValueType::String => Ok(Box::new(Regexp { expr, pattern })),
+        ValueType::Regex => Ok(Box::new(CompiledRegexp { expr, pattern })),
+        _ => Err(FindItError::BadExpression(
+            "REGULAR expressions pattern can only be strings or regular expressions".into(),
+        )),
+    }
+}
+
+/// A regular expression literal is compiled once, when the expression is parsed.
+struct CompiledRegexp {
+    expr: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for CompiledRegexp {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(expr) = self.expr.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Regex(regexp) = self.pattern.eval(file) else {
+            return Value::Empty;
+        };
+        regexp.get().is_match(&expr).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+
+struct Regexp {
+    expr: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for Regexp {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(expr) = self.expr.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(pattern) = self.pattern.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(regexp) = compile_regex(&pattern, file) else {
+            return Value::Empty;
+        };
+        regexp.is_match(&expr).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+
+pub(crate) fn new_glob(
+    expr: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if expr.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "GLOB can only work with strings".into(),
+        ));
+    }
     if pattern.expected_type() != ValueType::String {
         return Err(FindItError::BadExpression(
-            "REGULAR expressions pattern can only be strings".into(),
+            "GLOB pattern can only be strings".into(),
         ));
     }
-    Ok(Box::new(Regexp { expr, pattern }))
+    Ok(Box::new(Glob { expr, pattern }))
 }
 
-struct Regexp {
+struct Glob {
     expr: Box<dyn Evaluator>,
     pattern: Box<dyn Evaluator>,
 }
-impl Evaluator for Regexp {
+impl Evaluator for Glob {
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::String(expr) = self.expr.eval(file) else {
             return Value::Empty;
@@ -37,7 +108,7 @@ impl Evaluator for Regexp {
         let Value::String(pattern) = self.pattern.eval(file) else {
             return Value::Empty;
         };
-        let Ok(regexp) = Regex::new(&pattern) else {
+        let Some(regexp) = compile_regex(&glob_to_regex(&pattern), file) else {
             return Value::Empty;
         };
         regexp.is_match(&expr).into()
@@ -47,6 +118,54 @@ impl Evaluator for Regexp {
     }
 }
 
+// `*` and `?` never match the path separator, `**` does.
+fn glob_to_regex(glob: &str) -> String {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut regex = String::from("^");
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '*' if chars.get(index + 1) == Some(&'*') => {
+                regex.push_str(".*");
+                index += 1;
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' if let Some((negate, class)) = glob_class(&chars[index + 1..]) => {
+                regex.push('[');
+                if negate {
+                    regex.push('^');
+                }
+                for chr in class {
+                    match chr {
+                        '\\' | '[' | ']' | '^' | '&' | '~' => {
+                            regex.push('\\');
+                            regex.push(*chr);
+                        }
+                        _ => regex.push(*chr),
+                    }
+                }
+                regex.push(']');
+                index += usize::from(negate) + class.len() + 1;
+            }
+            chr => regex.push_str(&regex::escape(&chr.to_string())),
+        }
+        index += 1;
+    }
+    regex.push('$');
+    regex
+}
+
+/// Reads a glob class after its `[`, returning whether it is negated (by `!` or `^`) and its
+/// characters. A `]` right at the start of the class is a literal, so `[]]` matches `]` and
+/// `[!]` (without another `]`) is no class at all.
+fn glob_class(chars: &[char]) -> Option<(bool, &[char])> {
+    let negate = matches!(chars.first(), Some('!' | '^'));
+    let class = &chars[usize::from(negate)..];
+    let end = class.iter().skip(1).position(|c| *c == ']')? + 1;
+    Some((negate, &class[..end]))
+}
+
 struct ReplaceString {
     source: Box<dyn Evaluator>,
     from: Box<dyn Evaluator>,
@@ -83,7 +202,7 @@ impl Evaluator for ReplaceRegex {
         let Value::String(pattern) = self.pattern.eval(file) else {
             return Value::Empty;
         };
-        let Ok(regexp) = Regex::new(&pattern) else {
+        let Some(regexp) = compile_regex(&pattern, file) else {
             return Value::Empty;
         };
         let Value::String(to) = self.to.eval(file) else {
@@ -181,6 +300,129 @@ mod tests {
         assert_eq!(value, Value::Empty)
     }
 
+    #[test]
+    fn regex_literal_pattern() {
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        for (expr, expected) in [
+            ("\"abc\" MATCHES re/^a.c$/", true),
+            ("\"ABC\" MATCHES re/^a.c$/", false),
+            ("\"ABC\" MATCHES re/^a.c$/i", true),
+            ("\"a/b\" MATCHES re/a\\/b/", true),
+        ] {
+            let eval = read_expr(expr).unwrap();
+            assert_eq!(eval.eval(&wrapper), Value::Bool(expected), "{expr}");
+        }
+    }
+
+    #[test]
+    fn regex_literal_expected_type() {
+        let eval = read_expr("re/abc/").unwrap();
+        assert_eq!(eval.expected_type(), ValueType::Regex)
+    }
+
+    #[test]
+    fn glob_no_string_expr() {
+        let err = read_expr("1 GLOB \"a\"").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn glob_no_string_pattern() {
+        let err = read_expr("\"a\" glob 1").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn glob_null_expr_return_empty() {
+        let eval = read_expr("content GLOB \"*.rs\"").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        assert_eq!(value, Value::Empty)
+    }
+
+    #[test]
+    fn glob_expect_bool() {
+        let expr = read_expr("name GLOB \"*.rs\"").unwrap();
+        assert_eq!(expr.expected_type(), ValueType::Bool);
+    }
+
+    #[test]
+    fn glob_matching_file_name() {
+        let eval = read_expr("name GLOB \"*.rs\"").unwrap();
+        let path = Path::new("src/main.rs");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        assert_eq!(value, Value::Bool(true))
+    }
+
+    #[test]
+    fn glob_non_matching_file_name() {
+        let eval = read_expr("name GLOB \"*.rs\"").unwrap();
+        let path = Path::new("src/main.rs.bak");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        assert_eq!(value, Value::Bool(false))
+    }
+
+    #[test]
+    fn glob_question_mark_and_classes() {
+        let eval =
+            read_expr("[\"a1.txt\", \"b2.txt\", \"ab.txt\", \"c3.txt\", \"a12.txt\"].filter($n $n GLOB \"[ab]?.txt\").filter($n $n GLOB \"?[!b]*\")").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        let expected = read_expr("[\"a1.txt\", \"b2.txt\"]")
+            .unwrap()
+            .eval(&wrapper);
+        assert_eq!(value, expected)
+    }
+
+    #[test]
+    fn glob_star_does_not_cross_separator() {
+        let eval = read_expr("[\"src/a.rs\" GLOB \"*.rs\", \"src/a.rs\" GLOB \"**.rs\", \"src/a.rs\" GLOB \"src/*.rs\"]").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        let expected = read_expr("[false, true, true]").unwrap().eval(&wrapper);
+        assert_eq!(value, expected)
+    }
+
+    #[test]
+    fn glob_escapes_regex_characters() {
+        let eval = read_expr("[\"a+b(1).txt\" GLOB \"a+b(?).txt\", \"aab(1).txt\" GLOB \"a+b(?).txt\", \"[x\" GLOB \"[x\"]").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        let expected = read_expr("[true, false, true]").unwrap().eval(&wrapper);
+        assert_eq!(value, expected)
+    }
+
+    #[test]
+    fn glob_leading_close_bracket_is_literal() {
+        let eval = read_expr("[\"]\" GLOB \"[]]\", \"a\" GLOB \"[]]\", \"a\" GLOB \"[!]]\", \"]\" GLOB \"[!]]\", \"]\" GLOB \"[]a]\", \"a\" GLOB \"[]a]\"]").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        let expected = read_expr("[true, false, true, false, true, true]")
+            .unwrap()
+            .eval(&wrapper);
+        assert_eq!(value, expected)
+    }
+
+    #[test]
+    fn glob_unclosed_class_is_literal() {
+        let eval = read_expr("[\"[!]\" GLOB \"[!]\", \"a\" GLOB \"[!]\", \"[]\" GLOB \"[]\", \"x[!]y\" GLOB \"x[!]y\"]").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        let expected = read_expr("[true, false, true, true]")
+            .unwrap()
+            .eval(&wrapper);
+        assert_eq!(value, expected)
+    }
+
     #[test]
     fn replace_return_the_correct_value() {
         let eval = read_expr("replace(\"abc123def123\" from \"12\" to \" 12 \")").unwrap();
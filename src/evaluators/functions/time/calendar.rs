@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+
+use chrono::Datelike;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+struct DayOfYear {
+    date: Box<dyn Evaluator>,
+}
+
+impl Evaluator for DayOfYear {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Date(date) = self.date.eval(file) else {
+            return Value::Empty;
+        };
+        date.ordinal().into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+}
+
+struct IsLeapYear {
+    year: Box<dyn Evaluator>,
+}
+
+impl Evaluator for IsLeapYear {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(year) = self.year.eval(file) else {
+            return Value::Empty;
+        };
+        (year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+}
+
+pub(crate) fn single_argument(
+    name: &str,
+    expected_type: ValueType,
+    mut args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let Some(arg) = args.pop_front().filter(|_| args.is_empty()) else {
+        return Err(FindItError::BadExpression(format!(
+            "{} must have one argument.",
+            name
+        )));
+    };
+    if arg.expected_type() != expected_type {
+        return Err(FindItError::BadExpression(format!(
+            "{} argument must be a {}.",
+            name, expected_type
+        )));
+    }
+    Ok(arg)
+}
+
+pub(crate) fn build_day_of_year(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let date = single_argument("DAY_OF_YEAR", ValueType::Date, args)?;
+
+    Ok(Box::new(DayOfYear { date }))
+}
+
+pub(crate) fn build_is_leap_year(
+    args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let year = single_argument("IS_LEAP_YEAR", ValueType::Number, args)?;
+
+    Ok(Box::new(IsLeapYear { year }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(expr.eval(&file))
+    }
+
+    #[test]
+    fn day_of_year_first_day() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("day_of_year(parse(\"2024-01-01 10:00\" FROM \"%Y-%m-%d %H:%M\"))")?,
+            Value::Number(1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn day_of_year_last_day_of_leap_year() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("yearDay(parse(\"2024-12-31 10:00\" FROM \"%Y-%m-%d %H:%M\"))")?,
+            Value::Number(366)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn day_of_year_of_empty_date() -> Result<(), FindItError> {
+        assert_eq!(eval("day_of_year(created)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn day_of_year_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("day_of_year(now())")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Number);
+
+        Ok(())
+    }
+
+    #[test]
+    fn day_of_year_with_no_date() {
+        assert!(read_expr("day_of_year(12)").err().is_some());
+    }
+
+    #[test]
+    fn day_of_year_with_no_args() {
+        assert!(read_expr("day_of_year()").err().is_some());
+    }
+
+    #[test]
+    fn day_of_year_with_two_args() {
+        assert!(read_expr("day_of_year(now(), now())").err().is_some());
+    }
+
+    #[test]
+    fn is_leap_year() -> Result<(), FindItError> {
+        assert_eq!(eval("is_leap_year(2024)")?, Value::Bool(true));
+        assert_eq!(eval("is_leap_year(2023)")?, Value::Bool(false));
+        assert_eq!(eval("isLeapYear(1900)")?, Value::Bool(false));
+        assert_eq!(eval("isLeapYear(2000)")?, Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_leap_year_of_empty_year() -> Result<(), FindItError> {
+        assert_eq!(eval("is_leap_year(size)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_leap_year_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("is_leap_year(2000)")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Bool);
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_leap_year_with_no_number() {
+        assert!(read_expr("is_leap_year(\"2000\")").err().is_some());
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType, duration_of},
+};
+
+struct Duration {
+    amount: Box<dyn Evaluator>,
+    unit: Box<dyn Evaluator>,
+}
+
+impl Evaluator for Duration {
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::Number(amount), Value::String(unit)) =
+            (self.amount.eval(file), self.unit.eval(file))
+        else {
+            return Value::Empty;
+        };
+        duration_of(amount, &unit).into()
+    }
+    fn expected_type(&self) -> ValueType {
+        ValueType::Duration
+    }
+}
+
+pub(crate) fn build_duration(
+    mut args: VecDeque<Box<dyn Evaluator>>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let (Some(amount), Some(unit), None) = (args.pop_front(), args.pop_front(), args.pop_front())
+    else {
+        return Err(FindItError::BadExpression(
+            "duration must have exactly two arguments.".into(),
+        ));
+    };
+    if amount.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "duration amount must be a number.".into(),
+        ));
+    }
+    if unit.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "duration unit must be a string.".into(),
+        ));
+    }
+    Ok(Box::new(Duration { amount, unit }))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::path::Path;
+
+    use chrono::TimeDelta;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn duration_with_wrong_number_of_args() {
+        assert!(read_expr("duration(2)").is_err());
+        assert!(read_expr("duration(2, \"h\", 3)").is_err());
+    }
+
+    #[test]
+    fn duration_with_wrong_types() {
+        assert!(read_expr("duration(\"2\", \"h\")").is_err());
+        assert!(read_expr("duration(2, 3)").is_err());
+    }
+
+    #[test]
+    fn duration_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("duration(2, \"h\")")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Duration);
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_of_units() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+
+        assert_eq!(
+            read_expr("duration(2, \"hours\")")?.eval(file),
+            Value::Duration(TimeDelta::hours(2))
+        );
+        assert_eq!(
+            read_expr("duration(3, \"W\") = 21d")?.eval(file),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_with_unknown_unit_is_empty() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+
+        assert_eq!(
+            read_expr("duration(2, \"fortnight\")")?.eval(file),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+}
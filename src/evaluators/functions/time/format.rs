@@ -7,18 +7,26 @@ use crate::value::{Value, ValueType};
 impl EvaluatorFactory for FormatExpression {
     fn build(&self, bindings: &BindingsTypes) -> Result<Box<dyn Evaluator>, FindItError> {
         let timestamp = self.timestamp.build(bindings)?;
-        if timestamp.expected_type() != ValueType::Date {
-            return Err(FindItError::BadExpression("Can only format dates".into()));
-        }
         let format = self.format.build(bindings)?;
-        if format.expected_type() != ValueType::String {
-            return Err(FindItError::BadExpression(
-                "Format must be a string value".into(),
-            ));
-        }
+        new_format(timestamp, format)
+    }
+}
 
-        Ok(Box::new(Format { timestamp, format }))
+/// Used by both the `FORMAT` expression and the `format_date` method.
+pub(crate) fn new_format(
+    timestamp: Box<dyn Evaluator>,
+    format: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if timestamp.expected_type() != ValueType::Date {
+        return Err(FindItError::BadExpression("Can only format dates".into()));
+    }
+    if format.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Format must be a string value".into(),
+        ));
     }
+
+    Ok(Box::new(Format { timestamp, format }))
 }
 
 struct Format {
@@ -118,4 +126,30 @@ mod tests {
 
         assert_eq!(expr.eval(&wrapper), Value::Empty);
     }
+
+    #[test]
+    fn format_date_method() {
+        let wrapper = FileWrapper::new(PathBuf::new(), 1);
+
+        for (sql, expected) in [
+            (
+                "@(2021-12-21).format_date(\"%Y/%m/%d\")",
+                Value::String("2021/12/21".into()),
+            ),
+            ("@(2021-12-21).strftime(\"%d\")", Value::String("21".into())),
+            ("@(2021-12-21).format_date(\"%\")", Value::Empty),
+            ("modified.format_date(\"%Y\")", Value::Empty),
+        ] {
+            let expr = read_expr(sql).unwrap();
+            assert_eq!(expr.expected_type(), ValueType::String, "{sql}");
+            assert_eq!(expr.eval(&wrapper), expected, "{sql}");
+        }
+    }
+
+    #[test]
+    fn format_date_method_bad_types() {
+        assert!(read_expr("10.format_date(\"%Y\")").is_err());
+        assert!(read_expr("@(2021-12-21).format_date(10)").is_err());
+        assert!(read_expr("@(2021-12-21).format_date()").is_err());
+    }
 }
@@ -1,3 +1,5 @@
+pub(crate) mod calendar;
+pub(crate) mod duration;
 pub(crate) mod format;
 pub(crate) mod now;
 pub(crate) mod parse;
@@ -1,8 +1,7 @@
 use std::collections::VecDeque;
 
-use chrono::Local;
-
 use crate::{
+    clock,
     errors::FindItError,
     evaluators::expr::Evaluator,
     file_wrapper::FileWrapper,
@@ -13,7 +12,7 @@ struct Now {}
 
 impl Evaluator for Now {
     fn eval(&self, _: &FileWrapper) -> Value {
-        Local::now().into()
+        clock::now().into()
     }
     fn expected_type(&self) -> ValueType {
         ValueType::Date
@@ -33,9 +32,10 @@ pub(crate) fn build_now(
 mod tests {
     use std::env;
 
-    use chrono::Local;
+    use chrono::{Local, TimeZone};
 
     use crate::{
+        clock,
         errors::FindItError,
         evaluators::expr::read_expr,
         file_wrapper::FileWrapper,
@@ -75,4 +75,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn now_with_fixed_clock() -> Result<(), FindItError> {
+        let fixed = Local.with_ymd_and_hms(2025, 6, 1, 8, 0, 0).unwrap();
+        let wrapper = FileWrapper::new(env::current_dir()?, 1);
+
+        clock::set_test_now(Some(fixed));
+        let result = read_expr("now()")?.eval(&wrapper);
+        clock::set_test_now(None);
+
+        assert_eq!(result, Value::Date(fixed));
+
+        Ok(())
+    }
 }
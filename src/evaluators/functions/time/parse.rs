@@ -10,18 +10,26 @@ use crate::value::{Value, ValueType};
 impl EvaluatorFactory for ParseExpression {
     fn build(&self, bindings: &BindingsTypes) -> Result<Box<dyn Evaluator>, FindItError> {
         let str = self.str.build(bindings)?;
-        if str.expected_type() != ValueType::String {
-            return Err(FindItError::BadExpression("Can only parse strings".into()));
-        }
         let format = self.format.build(bindings)?;
-        if format.expected_type() != ValueType::String {
-            return Err(FindItError::BadExpression(
-                "Parse format must be a string value".into(),
-            ));
-        }
+        new_parse(str, format)
+    }
+}
 
-        Ok(Box::new(Parse { str, format }))
+/// Used by both the `PARSE` expression and the `parse_date` method.
+pub(crate) fn new_parse(
+    str: Box<dyn Evaluator>,
+    format: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if str.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression("Can only parse strings".into()));
+    }
+    if format.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Parse format must be a string value".into(),
+        ));
     }
+
+    Ok(Box::new(Parse { str, format }))
 }
 
 struct Parse {
@@ -62,7 +70,7 @@ mod tests {
 
     use std::path::PathBuf;
 
-    use chrono::{FixedOffset, NaiveDate, NaiveTime};
+    use chrono::{FixedOffset, Local, NaiveDate, NaiveTime};
 
     use crate::{
         evaluators::expr::read_expr,
@@ -159,4 +167,36 @@ mod tests {
 
         assert_eq!(expr.eval(&wrapper), Value::Empty);
     }
+
+    #[test]
+    fn parse_date_method() {
+        let wrapper = FileWrapper::new(PathBuf::new(), 1);
+        let expected = Value::Date(
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+                .unwrap()
+                .and_time(NaiveTime::MIN)
+                .and_local_timezone(Local)
+                .unwrap(),
+        );
+
+        for (sql, expected) in [
+            ("\"20250101\".parse_date(\"%Y%m%d\")", expected.clone()),
+            (
+                "\"01/01/2025 00:00\".strptime(\"%d/%m/%Y %H:%M\")",
+                expected,
+            ),
+            ("\"2025-13-01\".parse_date(\"%Y-%m-%d\")", Value::Empty),
+            ("content.parse_date(\"%Y\")", Value::Empty),
+        ] {
+            let expr = read_expr(sql).unwrap();
+            assert_eq!(expr.expected_type(), ValueType::Date, "{sql}");
+            assert_eq!(expr.eval(&wrapper), expected, "{sql}");
+        }
+    }
+
+    #[test]
+    fn parse_date_method_bad_types() {
+        assert!(read_expr("10.parse_date(\"%Y\")").is_err());
+        assert!(read_expr("\"2025\".parse_date(10)").is_err());
+    }
 }
@@ -201,4 +201,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_null_literal() -> Result<(), FindItError> {
+        let file = Path::new("/no/such/file");
+        let wrapper = FileWrapper::new(file.to_path_buf(), 1);
+
+        assert_eq!(read_expr("NULL")?.eval(&wrapper), Value::Empty);
+        assert_eq!(
+            read_expr("empty is none")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            read_expr("null is some")?.eval(&wrapper),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            read_expr("content IS NULL")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            read_expr("1 IS NOT NULL")?.eval(&wrapper),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_with_other_types() -> Result<(), FindItError> {
+        let file = Path::new("/no/such/file");
+        let wrapper = FileWrapper::new(file.to_path_buf(), 1);
+
+        assert_eq!(read_expr("size = EMPTY")?.eval(&wrapper), Value::Empty);
+        assert_eq!(read_expr("NULL <> \"a\"")?.eval(&wrapper), Value::Empty);
+        let expr = read_expr("coalesce(NULL, size, NULL, 7)")?;
+        assert_eq!(expr.expected_type(), ValueType::Number);
+        assert_eq!(expr.eval(&wrapper), Value::Number(7));
+        let expr = read_expr("IF size > 10 THEN NULL ELSE \"small\" END")?;
+        assert_eq!(expr.expected_type(), ValueType::String);
+        assert_eq!(expr.eval(&wrapper), Value::Empty);
+        assert!(read_expr("coalesce(NULL, size, \"a\")").is_err());
+
+        Ok(())
+    }
 }
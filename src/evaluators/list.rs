@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -10,7 +10,7 @@ use crate::{
 
 struct ListEval {
     items: Vec<Box<dyn Evaluator>>,
-    items_type: Rc<ValueType>,
+    items_type: Arc<ValueType>,
 }
 impl Evaluator for ListEval {
     fn expected_type(&self) -> ValueType {
@@ -41,14 +41,14 @@ impl EvaluatorFactory for ListExpression {
             items.push(item);
         }
         let items_type = items_type.unwrap_or(ValueType::Empty);
-        let items_type = Rc::new(items_type);
+        let items_type = Arc::new(items_type);
         Ok(Box::new(ListEval { items, items_type }))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{path::PathBuf, rc::Rc};
+    use std::{path::PathBuf, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -65,7 +65,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_lazy(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 [10, 20, 30].iter().map(|i| Value::Number(*i))
             ))
         );
@@ -81,7 +81,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_lazy(
-                Rc::new(ValueType::Empty),
+                Arc::new(ValueType::Empty),
                 [].iter().map(|i| Value::Number(*i))
             ))
         );
@@ -102,7 +102,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
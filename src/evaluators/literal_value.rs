@@ -18,9 +18,12 @@ impl Evaluator for Value {
         match self {
             Value::Bool(_) => ValueType::Bool,
             Value::Date(_) => ValueType::Date,
+            Value::Duration(_) => ValueType::Duration,
             Value::Number(_) => ValueType::Number,
+            Value::Float(_) => ValueType::Float,
             Value::String(_) => ValueType::String,
             Value::Path(_) => ValueType::Path,
+            Value::Regex(_) => ValueType::Regex,
             _ => ValueType::Empty,
         }
     }
@@ -29,7 +32,7 @@ impl Evaluator for Value {
 #[cfg(test)]
 mod tests {
 
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use chrono::{FixedOffset, Local, MappedLocalTime, NaiveDate, NaiveTime, TimeZone, Utc};
 
@@ -258,7 +261,7 @@ mod tests {
             Value::Bool(true),
             Value::Number(1),
         ];
-        let inst = Class::new(&Rc::new(cls), details);
+        let inst = Class::new(&Arc::new(cls), details);
         let value = Value::Class(inst);
         let eval: Box<dyn Evaluator> = (&value).into();
 
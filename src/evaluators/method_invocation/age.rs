@@ -0,0 +1,93 @@
+use crate::{
+    clock,
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+struct Age {
+    target: Box<dyn Evaluator>,
+}
+
+impl Evaluator for Age {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Date(date) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        // A date in the future has no age.
+        u64::try_from(clock::now().signed_duration_since(date).num_seconds())
+            .ok()
+            .into()
+    }
+}
+
+pub(super) fn new_age(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Date {
+        return Err(FindItError::BadExpression(
+            "Age method can only be applied to Date type".to_string(),
+        ));
+    }
+    Ok(Box::new(Age { target }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use chrono::{Local, TimeZone};
+
+    use crate::{
+        clock,
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_age_of_past_date() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        clock::set_test_now(Some(Local.with_ymd_and_hms(2020, 1, 2, 1, 0, 0).unwrap()));
+
+        assert_eq!(
+            read_expr("@(2020-01-01).age()")?.eval(file),
+            Value::Number(25 * 60 * 60)
+        );
+        clock::set_test_now(None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_age_of_future_date_is_empty() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(read_expr("(now() + 1d).age()")?.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_age_of_no_date_is_empty() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(read_expr("modified.age()")?.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_age_expected_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("modified.age()")?.expected_type(),
+            ValueType::Number
+        );
+        assert!(read_expr("12.age()").is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -10,7 +10,7 @@ use crate::{
 
 struct All {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
+    lambda: Arc<Box<dyn Evaluator>>,
 }
 
 impl Evaluator for All {
@@ -52,7 +52,7 @@ pub(super) fn new_all(
     }
     Ok(Box::new(All {
         target,
-        lambda: Rc::new(lambda_evaluator),
+        lambda: Arc::new(lambda_evaluator),
     }))
 }
 
@@ -60,11 +60,23 @@ pub(super) fn new_all(
 mod tests {
     use crate::{
         errors::FindItError,
-        evaluators::expr::read_expr,
+        evaluators::{
+            expr::{BindingsTypes, read_expr},
+            method_invocation::counting_list::CountingList,
+        },
         file_wrapper::FileWrapper,
+        parser::{ast::methods::LambdaFunction, parse_expression},
         value::{Value, ValueType},
     };
-    use std::path::{Path, PathBuf};
+    use std::{
+        path::{Path, PathBuf},
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    use super::new_all;
 
     #[test]
     fn test_simple_all_true() -> Result<(), FindItError> {
@@ -116,4 +128,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_all_empty_list() -> Result<(), FindItError> {
+        let expr = read_expr("[].all($n $n IS SOME)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_stops_at_first_decisive_item() -> Result<(), FindItError> {
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let target = Box::new(CountingList {
+            pulled: pulled.clone(),
+        });
+        let lambda = LambdaFunction {
+            parameter: "n".into(),
+            body: Box::new(parse_expression("$n < 3")?),
+        };
+        let expr = new_all(target, &lambda, &BindingsTypes::default())?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(false));
+        assert_eq!(pulled.load(Ordering::Relaxed), 3);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,155 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct Ancestor {
+    target: Box<dyn Evaluator>,
+    levels: Box<dyn Evaluator>,
+}
+impl Evaluator for Ancestor {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Path
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Number(levels) = self.levels.eval(file) else {
+            return Value::Empty;
+        };
+        path.ancestors()
+            .nth(levels as usize)
+            .filter(|ancestor| !ancestor.as_os_str().is_empty())
+            .into()
+    }
+}
+
+pub(super) fn new_ancestor(
+    target: Box<dyn Evaluator>,
+    levels: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(
+            "Ancestor method can only be applied to Path type".to_string(),
+        ));
+    }
+    let levels = levels.build(bindings)?;
+    if levels.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "Ancestor method argument must be a Number".to_string(),
+        ));
+    }
+    Ok(Box::new(Ancestor { target, levels }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_ancestor_self() -> Result<(), FindItError> {
+        let expr = read_expr("ancestor(0)")?;
+        let file = &FileWrapper::new(PathBuf::from("a/src/b/file.rs"), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::Path(PathBuf::from("a/src/b/file.rs"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_parent() -> Result<(), FindItError> {
+        let expr = read_expr("me.ancestor(1) = parent")?;
+        let file = &FileWrapper::new(PathBuf::from("a/src/b/file.rs"), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_grandparent_name() -> Result<(), FindItError> {
+        let expr = read_expr("parentAt(2).name")?;
+        let file = &FileWrapper::new(PathBuf::from("a/src/b/file.rs"), 1);
+
+        assert_eq!(expr.eval(file), Value::String("src".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_root() -> Result<(), FindItError> {
+        let expr = read_expr("ancestor(3)")?;
+        let file = &FileWrapper::new(PathBuf::from("/a/b/file.rs"), 1);
+
+        assert_eq!(expr.eval(file), Value::Path(PathBuf::from("/")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_beyond_root() -> Result<(), FindItError> {
+        let expr = read_expr("ancestor(4)")?;
+        let file = &FileWrapper::new(PathBuf::from("/a/b/file.rs"), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_beyond_relative_root() -> Result<(), FindItError> {
+        let expr = read_expr("ancestor(2)")?;
+        let file = &FileWrapper::new(PathBuf::from("b/file.rs"), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_empty_levels() -> Result<(), FindItError> {
+        let expr = read_expr("ancestor(size)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ancestor_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("ancestor(2)")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_path_return_error() {
+        let err = read_expr("\"a\".ancestor(1)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn no_number_return_error() {
+        let err = read_expr("ancestor(\"a\")").err();
+        assert!(err.is_some())
+    }
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -10,7 +10,7 @@ use crate::{
 
 struct Any {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
+    lambda: Arc<Box<dyn Evaluator>>,
 }
 
 impl Evaluator for Any {
@@ -52,7 +52,7 @@ pub(super) fn new_any(
     }
     Ok(Box::new(Any {
         target,
-        lambda: Rc::new(lambda_evaluator),
+        lambda: Arc::new(lambda_evaluator),
     }))
 }
 
@@ -60,11 +60,23 @@ pub(super) fn new_any(
 mod tests {
     use crate::{
         errors::FindItError,
-        evaluators::expr::read_expr,
+        evaluators::{
+            expr::{BindingsTypes, read_expr},
+            method_invocation::counting_list::CountingList,
+        },
         file_wrapper::FileWrapper,
+        parser::{ast::methods::LambdaFunction, parse_expression},
         value::{Value, ValueType},
     };
-    use std::path::{Path, PathBuf};
+    use std::{
+        path::{Path, PathBuf},
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    use super::new_any;
 
     #[test]
     fn test_simple_any_true() -> Result<(), FindItError> {
@@ -116,4 +128,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_any_empty_list() -> Result<(), FindItError> {
+        let expr = read_expr("[].any($n $n IS SOME)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_any_stops_at_first_decisive_item() -> Result<(), FindItError> {
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let target = Box::new(CountingList {
+            pulled: pulled.clone(),
+        });
+        let lambda = LambdaFunction {
+            parameter: "n".into(),
+            body: Box::new(parse_expression("$n > 2")?),
+        };
+        let expr = new_any(target, &lambda, &BindingsTypes::default())?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+        assert_eq!(pulled.load(Ordering::Relaxed), 3);
+
+        Ok(())
+    }
 }
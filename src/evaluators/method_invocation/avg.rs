@@ -1,6 +1,6 @@
 use crate::{
     errors::FindItError,
-    evaluators::expr::Evaluator,
+    evaluators::{cast::is_numeric, expr::Evaluator},
     file_wrapper::FileWrapper,
     value::{Value, ValueType},
 };
@@ -8,12 +8,12 @@ use std::ops::{Add, Deref};
 
 #[derive(Default)]
 struct AvgCalc {
-    total: u64,
+    total: f64,
     count: u64,
 }
-impl Add<u64> for AvgCalc {
+impl Add<f64> for AvgCalc {
     type Output = Self;
-    fn add(self, rhs: u64) -> Self {
+    fn add(self, rhs: f64) -> Self {
         Self {
             total: self.total + rhs,
             count: self.count + 1,
@@ -23,10 +23,9 @@ impl Add<u64> for AvgCalc {
 impl From<AvgCalc> for Value {
     fn from(value: AvgCalc) -> Self {
         if value.count == 0 {
-            Value::Empty
-        } else {
-            Value::Number(value.total / value.count)
+            return Value::Empty;
         }
+        (value.total / value.count as f64).into()
     }
 }
 
@@ -35,7 +34,7 @@ struct Avg {
 }
 impl Evaluator for Avg {
     fn expected_type(&self) -> ValueType {
-        ValueType::Number
+        ValueType::Float
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::List(value) = self.target.eval(file) else {
@@ -44,12 +43,10 @@ impl Evaluator for Avg {
         value
             .items()
             .into_iter()
-            .fold(AvgCalc::default(), |acc, item| {
-                if let Value::Number(n) = item {
-                    acc + n
-                } else {
-                    acc
-                }
+            .fold(AvgCalc::default(), |acc, item| match item {
+                Value::Number(n) => acc + n as f64,
+                Value::Float(n) => acc + n.get(),
+                _ => acc,
             })
             .into()
     }
@@ -61,9 +58,9 @@ pub(super) fn new_avg(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>,
             "Avg method can only be applied to a List of numbers".to_string(),
         ));
     };
-    if item_type.deref() != &ValueType::Number {
+    if !is_numeric(item_type.deref()) {
         return Err(FindItError::BadExpression(
-            "Avg method can only be applied to List of Number type".to_string(),
+            "Avg method can only be applied to List of Number or Float type".to_string(),
         ));
     }
     Ok(Box::new(Avg { target }))
@@ -85,7 +82,27 @@ mod tests {
         let expr = read_expr("[10, 20, 50, 30, 40].avg()")?;
         let file = &FileWrapper::new(PathBuf::new(), 1);
 
-        assert_eq!(expr.eval(file), Value::Number(30));
+        assert_eq!(expr.eval(file), Value::from(30.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fraction_avg() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2].avg()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::from(1.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_float_avg() -> Result<(), FindItError> {
+        let expr = read_expr("[0.5, 1.5, 2.5].avg()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::from(1.5));
 
         Ok(())
     }
@@ -104,7 +121,7 @@ mod tests {
     fn test_avg_expected_type() -> Result<(), FindItError> {
         let expr = read_expr("[1, 2, 3, 4, 5, 6].avg()")?;
 
-        assert_eq!(expr.expected_type(), ValueType::Number);
+        assert_eq!(expr.expected_type(), ValueType::Float);
 
         Ok(())
     }
@@ -126,7 +143,7 @@ mod tests {
         let path = Path::new("tests/test_cases/filter/test_files");
         let file = &FileWrapper::new(path.to_path_buf(), 1);
 
-        assert_eq!(expr.eval(file), Value::Number(66));
+        assert_eq!(expr.eval(file), Value::from(66.0));
 
         Ok(())
     }
@@ -4,9 +4,12 @@ use itertools::Itertools;
 
 use crate::{
     errors::FindItError,
-    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    evaluators::{
+        expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+        method_invocation::any::new_any,
+    },
     file_wrapper::FileWrapper,
-    parser::ast::expression::Expression,
+    parser::ast::{expression::Expression, methods::LambdaFunction},
     value::{Value, ValueType},
 };
 
@@ -82,6 +85,19 @@ pub(super) fn new_contains(
     }
 }
 
+pub(super) fn new_contains_matching(
+    target: Box<dyn Evaluator>,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(_) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Contains method with a condition can only be applied to List type".to_string(),
+        ));
+    };
+    new_any(target, lambda, bindings)
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -199,4 +215,80 @@ mod tests {
         let err = read_expr("\"hello world\".contains(true)").err();
         assert!(err.is_some())
     }
+
+    #[test]
+    fn contains_with_lambda_returns_true_when_needed() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 10, 4, 2, 5, 12].contains($n $n > 10)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn contains_with_lambda_returns_false_when_needed() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 10, 4, 2, 5, 12].contains($n $n > 20)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn contains_with_lambda_returns_empty_when_needed() -> Result<(), FindItError> {
+        let expr = read_expr("files.contains($f $f.size > 1000000)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn contains_with_lambda_return_type() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 4, 5].contains($n $n == 3)")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Bool);
+
+        Ok(())
+    }
+
+    #[test]
+    fn contains_with_lambda_no_list() {
+        let err = read_expr("\"abc\".contains($n $n == \"a\")").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn contains_with_lambda_no_bool() {
+        let err = read_expr("[1, 2, 3].contains($n $n + 1)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn contains_binding_value_is_not_a_lambda() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].any($n [3, 4].contains($n))")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn contains_binding_expression_is_not_a_lambda() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].all($n [2, 3, 4].contains($n + 1))")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,150 @@
+use crate::{
+    errors::FindItError,
+    evaluators::{
+        expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+        method_invocation::grep::read_pattern,
+    },
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct CountMatchesString {
+    target: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for CountMatchesString {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(regex) = read_pattern(self.pattern.as_ref(), file) else {
+            return Value::Empty;
+        };
+        regex.find_iter(&str).count().into()
+    }
+}
+
+struct CountMatchesFile {
+    target: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for CountMatchesFile {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(regex) = read_pattern(self.pattern.as_ref(), file) else {
+            return Value::Empty;
+        };
+        let Some(lines) = file.read_lines(&path) else {
+            return Value::Empty;
+        };
+        lines
+            .map(|line| regex.find_iter(&line).count())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+pub(super) fn new_count_matches(
+    target: Box<dyn Evaluator>,
+    pattern: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let pattern = pattern.build(bindings)?;
+    if pattern.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "CountMatches method pattern must be a String".to_string(),
+        ));
+    }
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(CountMatchesString { target, pattern })),
+        ValueType::Path => Ok(Box::new(CountMatchesFile { target, pattern })),
+        _ => Err(FindItError::BadExpression(
+            "CountMatches method can only be applied to String or Path types".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        errors::FindItError, evaluators::expr::read_expr, file_wrapper::FileWrapper, value::Value,
+    };
+
+    #[test]
+    fn test_count_matches_string() -> Result<(), FindItError> {
+        let expr =
+            read_expr("\"fn a() {}\\nfn b() {}\\nlet fnx = 1;\".countMatches(\"\\\\bfn\\\\b\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_matches_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("app.log");
+        fs::write(
+            &path,
+            "INFO starting\nERROR no disk ERROR\nINFO retry\nERROR still no disk\n",
+        )?;
+        let expr = read_expr("countMatches(\"ERROR\")")?;
+        let file = &FileWrapper::new(path, 1);
+
+        assert_eq!(expr.eval(file), Value::Number(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_matches_no_matches() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".countMatches(\"x\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_matches_bad_pattern() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".countMatches(\"(\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_matches_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("content.countMatches(\"a\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        let expr = read_expr("countMatches(\"a\")")?;
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_matches_bad_types() {
+        assert!(read_expr("12.countMatches(\"a\")").is_err());
+        assert!(read_expr("\"abc\".countMatches(1)").is_err());
+    }
+}
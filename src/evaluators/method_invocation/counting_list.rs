@@ -0,0 +1,31 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{List, Value, ValueType},
+};
+
+/// A lazy list of the numbers 1 to 100 that counts how many of its items were pulled, to test
+/// that a method stops reading the list early.
+pub(super) struct CountingList {
+    pub(super) pulled: Arc<AtomicUsize>,
+}
+impl Evaluator for CountingList {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::Number))
+    }
+    fn eval(&self, _: &FileWrapper) -> Value {
+        let pulled = self.pulled.clone();
+        Value::List(List::new_lazy(
+            Arc::new(ValueType::Number),
+            (1..=100_u64).map(move |n| {
+                pulled.fetch_add(1, Ordering::Relaxed);
+                Value::Number(n)
+            }),
+        ))
+    }
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -10,7 +10,14 @@ use crate::{
 
 struct Debug {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    parameter: String,
+}
+impl Debug {
+    fn message(&self, file: &FileWrapper, value: &Value) -> String {
+        let new_file = file.with_binding(value.clone());
+        self.lambda.eval(&new_file).to_string()
+    }
 }
 
 impl Evaluator for Debug {
@@ -19,12 +26,13 @@ impl Evaluator for Debug {
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let value = self.target.eval(file);
-        file.debugger().log(&|| {
-            let lambda = self.lambda.clone();
-            let value = value.clone();
-            let new_file = file.with_binding(value);
-            lambda.eval(&new_file).to_string()
-        });
+        if file.debug_methods() {
+            let message = self.message(file, &value);
+            eprintln!("${}: {}", self.parameter, message);
+            file.debugger().log(&|| message.clone());
+        } else {
+            file.debugger().log(&|| self.message(file, &value));
+        }
         value
     }
 }
@@ -34,17 +42,27 @@ pub(super) fn new_debug(
     lambda: &LambdaFunction,
     bindings: &BindingsTypes,
 ) -> Result<Box<dyn Evaluator>, FindItError> {
+    let parameter = lambda.parameter.clone();
     let lambda = lambda.build(bindings, &target.expected_type())?;
-    let lambda = Rc::new(lambda);
+    let lambda = Arc::new(lambda);
 
-    Ok(Box::new(Debug { target, lambda }))
+    Ok(Box::new(Debug {
+        target,
+        lambda,
+        parameter,
+    }))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{fmt::Debug, path::PathBuf, rc::Rc};
+    use std::{
+        fmt::Debug,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
 
     use crate::{
+        content::ContentOptions,
         debugger::Debugger,
         errors::FindItError,
         evaluators::expr::read_expr,
@@ -53,11 +71,11 @@ mod tests {
     };
 
     struct MyDebugger {
-        logs: Rc<std::cell::RefCell<Vec<String>>>,
+        logs: Arc<Mutex<Vec<String>>>,
     }
     impl Debugger for MyDebugger {
         fn log(&self, f: &dyn Fn() -> String) {
-            self.logs.borrow_mut().push(f());
+            self.logs.lock().unwrap().push(f());
         }
     }
     impl Debug for MyDebugger {
@@ -68,19 +86,37 @@ mod tests {
 
     #[test]
     fn test_simple_debug() -> Result<(), FindItError> {
-        let logs = Rc::new(std::cell::RefCell::new(Vec::new()));
-        let debugger: Rc<Box<dyn Debugger>> = Rc::new(Box::new(MyDebugger { logs: logs.clone() }));
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let debugger: Arc<Box<dyn Debugger>> =
+            Arc::new(Box::new(MyDebugger { logs: logs.clone() }));
         let expr = read_expr("100.debug($x \"ten is: \" + $x)")?;
-        let file = FileWrapper::new_with_debugger(PathBuf::new(), 1, &debugger);
+        let file =
+            FileWrapper::new_with_debugger(PathBuf::new(), 1, &debugger, ContentOptions::default());
 
         assert_eq!(expr.eval(&file), Value::Number(100));
-        let logs = logs.borrow();
+        let logs = logs.lock().unwrap();
         assert_eq!(logs.len(), 1);
         assert_eq!(logs[0], "ten is: 100");
 
         Ok(())
     }
 
+    #[test]
+    fn debug_methods_still_logs_and_returns_the_target() -> Result<(), FindItError> {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let debugger: Arc<Box<dyn Debugger>> =
+            Arc::new(Box::new(MyDebugger { logs: logs.clone() }));
+        let expr = read_expr("\"abc\".debug($str $str.length()).toUpper()")?;
+        let file =
+            FileWrapper::new_with_debugger(PathBuf::new(), 1, &debugger, ContentOptions::default())
+                .with_debug_methods(true);
+
+        assert_eq!(expr.eval(&file), Value::String("ABC".into()));
+        assert_eq!(*logs.lock().unwrap(), vec!["3".to_string()]);
+
+        Ok(())
+    }
+
     #[test]
     fn debug_return_value() -> Result<(), FindItError> {
         let expr = read_expr("100.debug($x \"ten is: \" + $x)")?;
@@ -0,0 +1,152 @@
+use std::path::{Component, Path};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct DepthIn {
+    target: Box<dyn Evaluator>,
+    root: Box<dyn Evaluator>,
+}
+
+fn components(path: &Path) -> impl Iterator<Item = Component<'_>> {
+    path.components().filter(|c| *c != Component::CurDir)
+}
+
+impl Evaluator for DepthIn {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Path(root) = self.root.eval(file) else {
+            return Value::Empty;
+        };
+        let mut path_components = components(&path);
+        for root_component in components(&root) {
+            if path_components.next() != Some(root_component) {
+                return Value::Empty;
+            }
+        }
+        match path_components.count() {
+            0 => Value::Empty,
+            count => (count - 1).into(),
+        }
+    }
+}
+
+pub(super) fn new_depth_in(
+    target: Box<dyn Evaluator>,
+    root: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(
+            "DepthIn method can only be applied to Path type".to_string(),
+        ));
+    }
+    let root = root.build(bindings)?;
+    if root.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(
+            "DepthIn method argument must be a Path".to_string(),
+        ));
+    }
+    Ok(Box::new(DepthIn { target, root }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn depth_in(path: &str, expr: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(expr)?;
+        let file = &FileWrapper::new(PathBuf::from(path), 1);
+        Ok(expr.eval(file))
+    }
+
+    #[test]
+    fn test_depth_in_direct_child() -> Result<(), FindItError> {
+        assert_eq!(depth_in("src/main.rs", "depth_in(@src)")?, Value::Number(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_in_nested() -> Result<(), FindItError> {
+        assert_eq!(
+            depth_in("a/src/b/c/main.rs", "me.depthIn(@\"a/src\")")?,
+            Value::Number(2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_in_ignore_current_dir() -> Result<(), FindItError> {
+        assert_eq!(
+            depth_in("./src/b/main.rs", "depth_in(@src)")?,
+            Value::Number(1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_in_not_under_root() -> Result<(), FindItError> {
+        assert_eq!(depth_in("source/main.rs", "depth_in(@src)")?, Value::Empty);
+        assert_eq!(depth_in("lib/src/main.rs", "depth_in(@src)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_in_root_itself() -> Result<(), FindItError> {
+        assert_eq!(depth_in("src", "depth_in(@src)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_in_empty_root() -> Result<(), FindItError> {
+        assert_eq!(
+            depth_in("src/main.rs", "depth_in(parent.parent.parent)")?,
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_in_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("depth_in(@src)")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Number);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_path_return_error() {
+        let err = read_expr("\"a\".depth_in(@src)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn no_path_argument_return_error() {
+        let err = read_expr("depth_in(\"src\")").err();
+        assert!(err.is_some())
+    }
+}
@@ -1,4 +1,7 @@
-use std::rc::Rc;
+use std::{
+    collections::{BTreeSet, HashSet},
+    sync::Arc,
+};
 
 use itertools::Itertools;
 
@@ -12,7 +15,7 @@ use crate::{
 
 struct Distinct {
     target: Box<dyn Evaluator>,
-    item_type: Rc<ValueType>,
+    item_type: Arc<ValueType>,
 }
 impl Evaluator for Distinct {
     fn expected_type(&self) -> ValueType {
@@ -29,8 +32,8 @@ impl Evaluator for Distinct {
 
 struct DistinctBy {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
-    items_type: Rc<ValueType>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
 }
 impl Evaluator for DistinctBy {
     fn expected_type(&self) -> ValueType {
@@ -49,6 +52,47 @@ impl Evaluator for DistinctBy {
         Value::List(List::new_lazy(self.items_type.clone(), items))
     }
 }
+struct SortedDistinct {
+    target: Box<dyn Evaluator>,
+    item_type: Arc<ValueType>,
+}
+impl Evaluator for SortedDistinct {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.item_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        #[allow(clippy::mutable_key_type)]
+        let items = value.items().into_iter().collect::<BTreeSet<_>>();
+        Value::List(List::new_eager(self.item_type.clone(), items.into_iter()))
+    }
+}
+struct DistinctCount {
+    target: Box<dyn Evaluator>,
+    lambda: Option<Box<dyn Evaluator>>,
+}
+impl Evaluator for DistinctCount {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Number
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        #[allow(clippy::mutable_key_type)]
+        let items = match &self.lambda {
+            None => value.items().into_iter().collect::<HashSet<_>>(),
+            Some(lambda) => value
+                .items()
+                .into_iter()
+                .map(|item| lambda.eval(&file.with_binding(item)))
+                .collect::<HashSet<_>>(),
+        };
+        items.len().into()
+    }
+}
 pub(super) fn new_distinct(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
     let ValueType::List(item_type) = target.expected_type() else {
         return Err(FindItError::BadExpression(
@@ -61,6 +105,48 @@ pub(super) fn new_distinct(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluat
     }))
 }
 
+pub(super) fn new_sorted_distinct(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Sorted distinct method can only be applied to a List type".to_string(),
+        ));
+    };
+    Ok(Box::new(SortedDistinct { target, item_type }))
+}
+
+pub(super) fn new_distinct_count(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(_) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Distinct count method can only be applied to a List type".to_string(),
+        ));
+    };
+    Ok(Box::new(DistinctCount {
+        target,
+        lambda: None,
+    }))
+}
+
+pub(super) fn new_distinct_count_by(
+    target: Box<dyn Evaluator>,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(items_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Distinct count by method can only be applied to a List type".to_string(),
+        ));
+    };
+    let lambda = lambda.build(bindings, &items_type)?;
+    Ok(Box::new(DistinctCount {
+        target,
+        lambda: Some(lambda),
+    }))
+}
+
 pub(super) fn new_distinct_by(
     target: Box<dyn Evaluator>,
     lambda: &LambdaFunction,
@@ -75,7 +161,7 @@ pub(super) fn new_distinct_by(
     let lambda = lambda.build(bindings, &items_type)?;
     Ok(Box::new(DistinctBy {
         target,
-        lambda: Rc::new(lambda),
+        lambda: Arc::new(lambda),
         items_type: items_type.clone(),
     }))
 }
@@ -84,7 +170,7 @@ pub(super) fn new_distinct_by(
 mod tests {
     use std::{
         path::{Path, PathBuf},
-        rc::Rc,
+        sync::Arc,
     };
 
     use crate::{
@@ -101,7 +187,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(1), Value::Number(10), Value::Number(2)].into_iter(),
             ))
         );
@@ -117,7 +203,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![Value::String("abcd".into()), Value::String("z".into()),].into_iter(),
             ))
         );
@@ -131,7 +217,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
@@ -154,7 +240,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
@@ -171,6 +257,137 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sorted_distinct() -> Result<(), FindItError> {
+        let expr = read_expr("[3, 1, 3, 2, 1].sortedDistinct()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::List(List::new_eager(
+                Arc::new(ValueType::Number),
+                vec![Value::Number(1), Value::Number(2), Value::Number(3)].into_iter(),
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_distinct_strings() -> Result<(), FindItError> {
+        let expr = read_expr("[\"b\", \"a\", \"c\", \"a\"].sorted_distinct()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            read_expr("[\"a\", \"b\", \"c\"]")?.eval(file)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_distinct_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\"].sortedDistinct()")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_distinct_nop_return_empty() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f $f.length()).sortedDistinct()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_count() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(
+            read_expr("[1, 10, 10, 1, 2].distinctCount()")?.eval(file),
+            Value::Number(3)
+        );
+        assert_eq!(
+            read_expr("[\"a\", \"a\"].distinct_count()")?.eval(file),
+            Value::Number(1)
+        );
+        assert_eq!(
+            read_expr("[1, 2].take(0).distinctCount()")?.eval(file),
+            Value::Number(0)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_count_by() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(
+            read_expr(
+                "[\"abcd\", \"1234\", \"z\", \"-\", \"xy\"].distinctCountBy($str $str.len())"
+            )?
+            .eval(file),
+            Value::Number(3)
+        );
+        assert_eq!(
+            read_expr("[1, 2].take(0).distinct_count_by($n $n * 2)")?.eval(file),
+            Value::Number(0)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_count_expected_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("[1, 2].distinctCount()")?.expected_type(),
+            ValueType::Number
+        );
+        assert_eq!(
+            read_expr("[1, 2].distinctCountBy($n $n)")?.expected_type(),
+            ValueType::Number
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_count_nop_return_empty() -> Result<(), FindItError> {
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(read_expr("files.distinctCount()")?.eval(file), Value::Empty);
+        assert_eq!(
+            read_expr("files.distinctCountBy($f $f.extension)")?.eval(file),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_distinct_count() {
+        assert!(read_expr("12.distinctCount()").is_err());
+        assert!(read_expr("12.distinctCountBy($f $f)").is_err());
+    }
+
+    #[test]
+    fn no_list_sorted_distinct() {
+        let err = read_expr("12.sortedDistinct()").err();
+        assert!(err.is_some())
+    }
+
     #[test]
     fn no_list_distinct() {
         let err = read_expr("12.distinct()").err();
@@ -0,0 +1,294 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Encoding {
+    Base64,
+    Hex,
+}
+impl Encoding {
+    fn name(&self) -> &str {
+        match self {
+            Encoding::Base64 => "Base64",
+            Encoding::Hex => "Hex",
+        }
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Base64 => STANDARD.encode(bytes),
+            Encoding::Hex => Value::Bytes(bytes.to_vec()).to_string(),
+        }
+    }
+
+    fn decode(&self, text: &str) -> Option<Vec<u8>> {
+        match self {
+            Encoding::Base64 => STANDARD.decode(text).ok(),
+            Encoding::Hex => {
+                if !text.len().is_multiple_of(2) {
+                    return None;
+                }
+                (0..text.len())
+                    .step_by(2)
+                    .map(|i| {
+                        text.get(i..i + 2)
+                            .filter(|b| b.chars().all(|c| c.is_ascii_hexdigit()))
+                            .and_then(|b| u8::from_str_radix(b, 16).ok())
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+struct Encode {
+    target: Box<dyn Evaluator>,
+    encoding: Encoding,
+}
+impl Evaluator for Encode {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        match self.target.eval(file) {
+            Value::String(str) => self.encoding.encode(str.as_bytes()).into(),
+            Value::Bytes(bytes) => self.encoding.encode(&bytes).into(),
+            _ => Value::Empty,
+        }
+    }
+}
+
+struct Decode {
+    target: Box<dyn Evaluator>,
+    encoding: Encoding,
+}
+impl Evaluator for Decode {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bytes
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        self.encoding.decode(&str).into()
+    }
+}
+
+pub(super) fn new_encode(
+    target: Box<dyn Evaluator>,
+    encoding: Encoding,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String | ValueType::Bytes => Ok(Box::new(Encode { target, encoding })),
+        _ => Err(FindItError::BadExpression(format!(
+            "Encode{} method can only be applied to String or Bytes types",
+            encoding.name()
+        ))),
+    }
+}
+
+pub(super) fn new_decode(
+    target: Box<dyn Evaluator>,
+    encoding: Encoding,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(Decode { target, encoding })),
+        _ => Err(FindItError::BadExpression(format!(
+            "Decode{} method can only be applied to String type",
+            encoding.name()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn binary_file(dir: &Path) -> Result<FileWrapper, FindItError> {
+        let path = dir.join("image.png");
+        fs::write(&path, b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR")?;
+        Ok(FileWrapper::new(path, 1))
+    }
+
+    fn eval(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(expr.eval(file))
+    }
+
+    #[test]
+    fn test_hex_of_binary_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let file = &binary_file(dir.path())?;
+        let expr = read_expr("content_bytes.hex()")?;
+
+        assert_eq!(
+            expr.eval(file),
+            Value::String("89504e470d0a1a0a0000000d49484452".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex_prefix_of_binary_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let file = &binary_file(dir.path())?;
+        let expr = read_expr("content_bytes.hex().hasPrefix(\"89504e47\")")?;
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_length_of_binary_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let file = &binary_file(dir.path())?;
+        let expr = read_expr("content_bytes.length()")?;
+
+        assert_eq!(expr.eval(file), Value::Number(16));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_of_binary_file_is_empty() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let file = &binary_file(dir.path())?;
+        let expr = read_expr("content")?;
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex_of_no_file() -> Result<(), FindItError> {
+        assert_eq!(eval("content_bytes.hex()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_hex_of_string() -> Result<(), FindItError> {
+        assert_eq!(eval("\"Hi!\".encodeHex()")?, Value::String("486921".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_base64() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"hello world\".encodeBase64()")?,
+            Value::String("aGVsbG8gd29ybGQ=".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_base64() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"aGVsbG8gd29ybGQ=\".decodeBase64()")?,
+            Value::Bytes(b"hello world".to_vec())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_hex() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"00fF10\".decodeHex()")?,
+            Value::Bytes(vec![0, 255, 16])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_base64_round_trip() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"find ü it\".encodeBase64().decodeBase64() AS STRING")?,
+            Value::String("find ü it".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex_round_trip() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"find ü it\".encodeHex().decodeHex() AS STRING")?,
+            Value::String("find ü it".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_round_trip() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let file = &binary_file(dir.path())?;
+        let expr = read_expr("content_bytes.encodeBase64().decodeBase64() = content_bytes")?;
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid_input() -> Result<(), FindItError> {
+        assert_eq!(eval("\"not base64!\".decodeBase64()")?, Value::Empty);
+        assert_eq!(eval("\"abc\".decodeHex()")?, Value::Empty);
+        assert_eq!(eval("\"zz\".decodeHex()")?, Value::Empty);
+        assert_eq!(eval("\"üü\".decodeHex()")?, Value::Empty);
+        assert_eq!(eval("\"+1+1\".decodeHex()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_empty() -> Result<(), FindItError> {
+        assert_eq!(eval("content.encodeBase64()")?, Value::Empty);
+        assert_eq!(eval("content.decodeHex()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_return_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("\"a\".encodeBase64()")?.expected_type(),
+            ValueType::String
+        );
+        assert_eq!(
+            read_expr("\"a\".decodeBase64()")?.expected_type(),
+            ValueType::Bytes
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_types() {
+        assert!(read_expr("12.encodeBase64()").is_err());
+        assert!(read_expr("12.encodeHex()").is_err());
+        assert!(read_expr("content_bytes.decodeHex()").is_err());
+        assert!(read_expr("12.decodeBase64()").is_err());
+    }
+}
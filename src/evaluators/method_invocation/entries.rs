@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use crate::{
+    class_type::{Class, ClassType},
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{List, Value, ValueType},
+};
+
+use super::values::fields_type;
+
+const KEY_FIELD_NAME: &str = "key";
+const VALUE_FIELD_NAME: &str = "value";
+
+struct Entries {
+    target: Box<dyn Evaluator>,
+    keys: Arc<Vec<Value>>,
+    class_type: Arc<ValueType>,
+    class_internal_type: Arc<ClassType>,
+}
+
+impl Evaluator for Entries {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.class_type.clone())
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(class) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        Value::List(List::new_eager(
+            self.class_type.clone(),
+            self.keys.iter().zip(class.values()).map(|(key, value)| {
+                Value::Class(Class::new(
+                    &self.class_internal_type,
+                    vec![key.clone(), value.clone()],
+                ))
+            }),
+        ))
+    }
+}
+
+pub(super) fn new_entries(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(class_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Entries method can only be applied to Class type".to_string(),
+        ));
+    };
+    let value_type = fields_type(&class_type, "Entries")?;
+    let keys = class_type
+        .fields()
+        .map(|(name, _)| Value::String(name.clone()))
+        .collect();
+    let class_internal_type = Arc::new(ClassType::new(&[
+        (KEY_FIELD_NAME.to_string(), ValueType::String),
+        (VALUE_FIELD_NAME.to_string(), value_type),
+    ]));
+    let class_type = Arc::new(ValueType::Class(class_internal_type.clone()));
+
+    Ok(Box::new(Entries {
+        target,
+        keys: Arc::new(keys),
+        class_type,
+        class_internal_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError, evaluators::expr::read_expr, file_wrapper::FileWrapper, value::Value,
+    };
+
+    #[test]
+    fn test_entries() -> Result<(), FindItError> {
+        let expr = read_expr("{:width 10, :height 20}.entries()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected =
+            read_expr("[{:key \"width\", :value 10}, {:key \"height\", :value 20}]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_entries_can_be_mapped() -> Result<(), FindItError> {
+        let expr = read_expr(
+            "{:width 10, :height 20}.entries().map($e $e::key + \"=\" + ($e::value AS STRING)).join(\",\")",
+        )?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::String("width=10,height=20".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_entries_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f {:name $f.name}).first().entries()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_types_return_error() {
+        let err = read_expr("{:name \"a\", :size 12}.entries()").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn no_class_return_error() {
+        let err = read_expr("[1].entries()").err();
+
+        assert!(err.is_some());
+    }
+}
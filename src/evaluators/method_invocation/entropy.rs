@@ -0,0 +1,171 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+/// Only that many bytes of the start of the file are used to calculate the entropy.
+const ENTROPY_SAMPLE_SIZE: u64 = 1024 * 1024;
+
+fn read_sample(path: &Path) -> io::Result<Vec<u8>> {
+    let mut sample = vec![];
+    File::open(path)?
+        .take(ENTROPY_SAMPLE_SIZE)
+        .read_to_end(&mut sample)?;
+    Ok(sample)
+}
+
+/// The Shannon entropy of the bytes, in bits per byte (between 0 and 8).
+fn shannon_entropy(bytes: &[u8]) -> f64 {
+    let mut counts = [0_usize; 256];
+    for byte in bytes {
+        counts[*byte as usize] += 1;
+    }
+    let total = bytes.len() as f64;
+    let entropy = counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let probability = *count as f64 / total;
+            -probability * probability.log2()
+        })
+        .sum::<f64>();
+    // Avoid a negative zero.
+    entropy.max(0.0)
+}
+
+struct Entropy {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for Entropy {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Float
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        if !path.is_file() {
+            return Value::Empty;
+        }
+        match read_sample(&path) {
+            Ok(sample) => shannon_entropy(&sample).into(),
+            Err(err) => {
+                file.report(&|| format!("Can not read [{}]: {err}", path.display()));
+                Value::Empty
+            }
+        }
+    }
+}
+
+pub(super) fn new_entropy(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::Path => Ok(Box::new(Entropy { target })),
+        _ => Err(FindItError::BadExpression(
+            "Entropy method can only be applied to Path type".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use rand::RngCore;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn text_has_lower_entropy_than_random_data() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let text = dir.path().join("text.txt");
+        fs::write(
+            &text,
+            "the quick brown fox jumps over the lazy dog\n".repeat(100),
+        )?;
+        let mut random = vec![0_u8; 64 * 1024];
+        rand::rng().fill_bytes(&mut random);
+        let data = dir.path().join("data.bin");
+        fs::write(&data, random)?;
+
+        let expr = read_expr("me.entropy()")?;
+        let Value::Float(text_entropy) = expr.eval(&FileWrapper::new(text, 1)) else {
+            panic!("text entropy is not a float");
+        };
+        let Value::Float(data_entropy) = expr.eval(&FileWrapper::new(data.clone(), 1)) else {
+            panic!("data entropy is not a float");
+        };
+        assert!(text_entropy.get() < 5.0, "{text_entropy}");
+        assert!(data_entropy.get() > 7.5, "{data_entropy}");
+        assert_eq!(
+            read_expr("me.entropy() > 7.5")?.eval(&FileWrapper::new(data, 1)),
+            Value::Bool(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn entropy_of_single_byte_file_is_zero() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("a.txt");
+        fs::write(&path, "aaaaaaaa")?;
+        let expr = read_expr("me.entropy() = 0.0")?;
+
+        assert_eq!(expr.eval(&FileWrapper::new(path, 1)), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn entropy_of_two_equal_bytes_is_one() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("ab.txt");
+        fs::write(&path, "abababab")?;
+        let expr = read_expr("me.entropy() = 1.0")?;
+
+        assert_eq!(expr.eval(&FileWrapper::new(path, 1)), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn entropy_of_dir_or_missing_file_is_empty() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let expr = read_expr("me.entropy()")?;
+
+        assert_eq!(
+            expr.eval(&FileWrapper::new(dir.path().to_path_buf(), 1)),
+            Value::Empty
+        );
+        assert_eq!(
+            expr.eval(&FileWrapper::new(
+                Path::new("/no/such/file").to_path_buf(),
+                1
+            )),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<(), FindItError> {
+        assert_eq!(read_expr("me.entropy()")?.expected_type(), ValueType::Float);
+        assert!(read_expr("name.entropy()").is_err());
+
+        Ok(())
+    }
+}
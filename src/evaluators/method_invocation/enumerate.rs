@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     class_type::{Class, ClassType},
@@ -13,8 +13,8 @@ const ITEM_FIELD_NAME: &str = "item";
 
 struct Enumerate {
     target: Box<dyn Evaluator>,
-    class_type: Rc<ValueType>,
-    class_internal_type: Rc<ClassType>,
+    class_type: Arc<ValueType>,
+    class_internal_type: Arc<ClassType>,
 }
 
 impl Evaluator for Enumerate {
@@ -45,11 +45,11 @@ pub(super) fn new_enumerate(target: Box<dyn Evaluator>) -> Result<Box<dyn Evalua
             "Enumerate method can only be applied to List type".to_string(),
         ));
     };
-    let class_internal_type = Rc::new(ClassType::new(&[
+    let class_internal_type = Arc::new(ClassType::new(&[
         (INDEX_FIELD_NAME.to_string(), ValueType::Number),
         (ITEM_FIELD_NAME.to_string(), (*item_type).clone()),
     ]));
-    let class_type = Rc::new(ValueType::Class(class_internal_type.clone()));
+    let class_type = Arc::new(ValueType::Class(class_internal_type.clone()));
 
     Ok(Box::new(Enumerate {
         target,
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -10,8 +10,8 @@ use crate::{
 
 struct Filter {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
-    items_type: Rc<ValueType>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
 }
 
 impl Evaluator for Filter {
@@ -51,7 +51,7 @@ pub(super) fn new_filter(
     }
     Ok(Box::new(Filter {
         target,
-        lambda: Rc::new(lambda_evaluator),
+        lambda: Arc::new(lambda_evaluator),
         items_type,
     }))
 }
@@ -60,7 +60,7 @@ pub(super) fn new_filter(
 mod tests {
     use std::{
         path::{Path, PathBuf},
-        rc::Rc,
+        sync::Arc,
     };
 
     use crate::{
@@ -78,7 +78,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(2), Value::Number(4), Value::Number(6)].into_iter(),
             ))
         );
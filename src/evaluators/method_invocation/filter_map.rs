@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator},
+    file_wrapper::FileWrapper,
+    parser::ast::methods::LambdaFunction,
+    value::{List, Value, ValueType},
+};
+
+struct FilterMap {
+    target: Box<dyn Evaluator>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
+}
+
+impl Evaluator for FilterMap {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.items_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let lambda = self.lambda.clone();
+        let file = file.clone();
+        let iter = value.items().into_iter().filter_map(move |item| {
+            let new_file = file.with_binding(item);
+            match lambda.eval(&new_file) {
+                Value::Empty => None,
+                value => Some(value),
+            }
+        });
+        Value::List(List::new_lazy(self.items_type.clone(), iter))
+    }
+}
+
+pub(super) fn new_filter_map(
+    target: Box<dyn Evaluator>,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(input_item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "FilterMap method can only be applied to List type".to_string(),
+        ));
+    };
+    let lambda = lambda.build(bindings, &input_item_type)?;
+    let output_item_type = lambda.expected_type();
+    let lambda = Arc::new(lambda);
+
+    Ok(Box::new(FilterMap {
+        target,
+        lambda,
+        items_type: Arc::new(output_item_type),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_filter_map_drops_empty() -> Result<(), FindItError> {
+        let expr =
+            read_expr("[1, 2, 3, 4, 5].filterMap($n IF $n % 2 = 1 THEN $n * 10 ELSE size END)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("[10, 30, 50]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_map_same_as_map_and_filter() -> Result<(), FindItError> {
+        let expr = read_expr("files.filter_map($f $f.content)")?;
+        let path = Path::new("./tests/test_cases/order_by/test_files/next/emma/amelia");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("files.map($f $f.content).filter($c $c IS SOME)")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_map_keeps_false_and_zero() -> Result<(), FindItError> {
+        let expr = read_expr("[0, 1].filterMap($n $n = 1)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[FALSE, TRUE]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_map_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2].filterMap($n $n AS STRING)")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_map_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.filterMap($f $f.name)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_return_error() {
+        let err = read_expr("12.filterMap($n $n)").err();
+        assert!(err.is_some())
+    }
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -11,8 +11,8 @@ use crate::{
 
 struct FlatMap {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
-    items_type: Rc<ValueType>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
 }
 
 impl Evaluator for FlatMap {
@@ -54,7 +54,7 @@ pub(super) fn new_flat_map(
             "FlatMap lambda must return a List".to_string(),
         ));
     };
-    let lambda = Rc::new(lambda);
+    let lambda = Arc::new(lambda);
 
     Ok(Box::new(FlatMap {
         target,
@@ -69,7 +69,7 @@ mod tests {
         fs::File,
         io::{BufRead, BufReader},
         path::{Path, PathBuf},
-        rc::Rc,
+        sync::Arc,
     };
 
     use itertools::Itertools;
@@ -89,7 +89,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![
                     Value::Number(11),
                     Value::Number(15),
@@ -125,7 +125,7 @@ mod tests {
             .collect();
         assert_eq!(
             value,
-            Value::List(List::new_from_vec(Rc::new(ValueType::String), expected))
+            Value::List(List::new_from_vec(Arc::new(ValueType::String), expected))
         );
         Ok(())
     }
@@ -137,7 +137,7 @@ mod tests {
 
         assert_eq!(
             expr.eval(file),
-            Value::List(List::new_from_vec(Rc::new(ValueType::String), vec![]))
+            Value::List(List::new_from_vec(Arc::new(ValueType::String), vec![]))
         );
 
         Ok(())
@@ -0,0 +1,210 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+const BINARY_UNITS: [(&str, u64); 5] = [
+    ("B", 1),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+];
+const DECIMAL_UNITS: [(&str, u64); 5] = [
+    ("B", 1),
+    ("KB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+];
+
+struct FormatSize {
+    target: Box<dyn Evaluator>,
+    unit: Option<Box<dyn Evaluator>>,
+}
+
+fn find_unit(unit: &str) -> Option<(&'static str, u64)> {
+    BINARY_UNITS
+        .iter()
+        .chain(DECIMAL_UNITS.iter())
+        .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        .copied()
+}
+
+fn auto_unit(size: u64) -> (&'static str, u64) {
+    BINARY_UNITS
+        .iter()
+        .rev()
+        .find(|(_, factor)| size >= *factor)
+        .copied()
+        .unwrap_or(BINARY_UNITS[0])
+}
+
+fn format_size(size: u64, (name, factor): (&str, u64)) -> String {
+    if factor == 1 {
+        format!("{} {}", size, name)
+    } else {
+        format!("{:.1} {}", size as f64 / factor as f64, name)
+    }
+}
+
+impl Evaluator for FormatSize {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(size) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let unit = match &self.unit {
+            Some(unit) => {
+                let Value::String(unit) = unit.eval(file) else {
+                    return Value::Empty;
+                };
+                if unit.eq_ignore_ascii_case("auto") {
+                    auto_unit(size)
+                } else if let Some(unit) = find_unit(&unit) {
+                    unit
+                } else {
+                    return Value::Empty;
+                }
+            }
+            None => auto_unit(size),
+        };
+        format_size(size, unit).into()
+    }
+}
+
+pub(super) fn new_format_size(
+    target: Box<dyn Evaluator>,
+    unit: &Option<Box<Expression>>,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "FormatSize method can only be applied to Number type".to_string(),
+        ));
+    }
+    let unit = match unit {
+        Some(unit) => {
+            let unit = unit.build(bindings)?;
+            if unit.expected_type() != ValueType::String {
+                return Err(FindItError::BadExpression(
+                    "FormatSize method unit must be a String".to_string(),
+                ));
+            }
+            Some(unit)
+        }
+        None => None,
+    };
+    Ok(Box::new(FormatSize { target, unit }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+        Ok(expr.eval(file))
+    }
+
+    #[test]
+    fn test_format_size_auto() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("1073741824.format_size(\"auto\")")?,
+            Value::String("1.0 GiB".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_no_argument() -> Result<(), FindItError> {
+        assert_eq!(eval("1536.humanSize()")?, Value::String("1.5 KiB".into()));
+        assert_eq!(eval("1536.human_size")?, Value::String("1.5 KiB".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_small_values() -> Result<(), FindItError> {
+        assert_eq!(eval("512.format_size()")?, Value::String("512 B".into()));
+        assert_eq!(eval("0.format_size()")?, Value::String("0 B".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_decimal_unit() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("2500000.formatSize(\"MB\")")?,
+            Value::String("2.5 MB".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_binary_unit() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("1073741824.format_size(\"MiB\")")?,
+            Value::String("1024.0 MiB".into())
+        );
+        assert_eq!(
+            eval("1099511627776.format_size(\"TiB\")")?,
+            Value::String("1.0 TiB".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_unknown_unit() -> Result<(), FindItError> {
+        assert_eq!(eval("1024.format_size(\"XB\")")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_empty_target() -> Result<(), FindItError> {
+        assert_eq!(eval("size.format_size()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_size_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("size.format_size(\"KB\")")?;
+
+        assert_eq!(expr.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_number_return_error() {
+        let err = read_expr("\"a\".format_size()").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn no_string_unit_return_error() {
+        let err = read_expr("size.format_size(1)").err();
+
+        assert!(err.is_some());
+    }
+}
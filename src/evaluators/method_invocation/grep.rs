@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::{
+    errors::FindItError,
+    evaluators::{
+        expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+        functions::string_functions::compile_regex,
+    },
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{List, Value, ValueType},
+};
+
+pub(super) fn read_pattern(pattern: &dyn Evaluator, file: &FileWrapper) -> Option<Regex> {
+    let Value::String(pattern) = pattern.eval(file) else {
+        return None;
+    };
+    compile_regex(&pattern, file)
+}
+
+struct GrepString {
+    target: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for GrepString {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::String))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(regex) = read_pattern(self.pattern.as_ref(), file) else {
+            return Value::Empty;
+        };
+        let items = str
+            .lines()
+            .filter(|line| regex.is_match(line))
+            .map(|line| Value::String(line.to_string()));
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
+    }
+}
+
+struct GrepFile {
+    target: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for GrepFile {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::String))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(regex) = read_pattern(self.pattern.as_ref(), file) else {
+            return Value::Empty;
+        };
+        let Some(lines) = file.read_lines(&path) else {
+            return Value::Empty;
+        };
+        let items = lines
+            .filter(move |line| regex.is_match(line))
+            .map(Value::String);
+        Value::List(List::new_lazy(Arc::new(ValueType::String), items))
+    }
+}
+
+pub(super) fn new_grep(
+    target: Box<dyn Evaluator>,
+    pattern: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let pattern = pattern.build(bindings)?;
+    if pattern.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Grep method pattern must be a String".to_string(),
+        ));
+    }
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(GrepString { target, pattern })),
+        ValueType::Path => Ok(Box::new(GrepFile { target, pattern })),
+        _ => Err(FindItError::BadExpression(
+            "Grep method can only be applied to String or Path types".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{List, Value, ValueType},
+    };
+
+    fn lines(lines: &[&str]) -> Value {
+        Value::List(List::new_from_vec(
+            Arc::new(ValueType::String),
+            lines.iter().map(|l| Value::String(l.to_string())).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_grep_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("app.log");
+        fs::write(
+            &path,
+            "INFO starting\nERROR no disk\nINFO retry\nERROR still no disk\nDEBUG done\n",
+        )?;
+        let expr = read_expr("grep(\"^ERROR\")")?;
+        let file = &FileWrapper::new(path, 1);
+
+        assert_eq!(
+            expr.eval(file),
+            lines(&["ERROR no disk", "ERROR still no disk"])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_file_no_matches() -> Result<(), FindItError> {
+        let expr = read_expr("grep(\"no such text\")")?;
+        let path = Path::new("tests/test_cases/display/test_files/week-362.txt");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), lines(&[]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_file_is_lazy() -> Result<(), FindItError> {
+        let expr = read_expr("grep(\"e\").first()")?;
+        let path = Path::new("tests/test_cases/display/test_files/week-362.txt");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::String("quo eligendi amet harum ullam minus quasi ut.".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_string() -> Result<(), FindItError> {
+        let expr = read_expr("\"one\\ntwo\\nthree\".grep(\"o\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), lines(&["one", "two"]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_bad_pattern() -> Result<(), FindItError> {
+        let expr = read_expr("\"one\".grep(\"(\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        let expr = read_expr("grep(\"[a\")")?;
+        let path = Path::new("tests/test_cases/display/test_files/week-362.txt");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_no_file() -> Result<(), FindItError> {
+        let expr = read_expr("grep(\"a\")")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_empty_pattern() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".grep(content)")?;
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_return_type() -> Result<(), FindItError> {
+        let expr = read_expr("grep(\"a\")")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_grep_bad_types() {
+        assert!(read_expr("12.grep(\"a\")").is_err());
+        assert!(read_expr("\"abc\".grep(1)").is_err());
+    }
+}
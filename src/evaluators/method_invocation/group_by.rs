@@ -1,4 +1,4 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     class_type::{Class, ClassType},
@@ -11,13 +11,26 @@ use crate::{
 
 const KEY_FIELD_NAME: &str = "key";
 const VALUES_FIELD_NAME: &str = "values";
+const VALUE_FIELD_NAME: &str = "value";
+
+fn group(list: List, lambda: &dyn Evaluator, file: &FileWrapper) -> Vec<(Value, Vec<Value>)> {
+    // This should work, the key is "muteable" only when a list move from lazy to eager, which should work well for hash and equals.
+    #[allow(clippy::mutable_key_type)]
+    let mut groups = HashMap::new();
+    for item in list.items() {
+        let new_file = file.with_binding(item.clone());
+        let key = lambda.eval(&new_file);
+        groups.entry(key).or_insert_with(Vec::new).push(item);
+    }
+    groups.into_iter().collect()
+}
 
 struct GroupBy {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
-    class_type: Rc<ValueType>,
-    item_type: Rc<ValueType>,
-    class_internal_type: Rc<ClassType>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    class_type: Arc<ValueType>,
+    item_type: Arc<ValueType>,
+    class_internal_type: Arc<ClassType>,
 }
 
 impl Evaluator for GroupBy {
@@ -28,14 +41,7 @@ impl Evaluator for GroupBy {
         let Value::List(value) = self.target.eval(file) else {
             return Value::Empty;
         };
-        // This should work, the key is "muteable" only when a list move from lazy to eager, which should work well for hash and equals.
-        #[allow(clippy::mutable_key_type)]
-        let mut groups = HashMap::new();
-        for item in value.items() {
-            let new_file = file.with_binding(item.clone());
-            let key = self.lambda.eval(&new_file);
-            groups.entry(key).or_insert_with(Vec::new).push(item);
-        }
+        let groups = group(value, self.lambda.as_ref().as_ref(), file);
         let lst = List::new_eager(
             self.class_type.clone(),
             groups.into_iter().map(|(key, val)| {
@@ -64,15 +70,15 @@ pub(super) fn new_group_by(
     };
     let lambda = lambda.build(bindings, &item_type)?;
     let key_type = lambda.expected_type().clone();
-    let lambda = Rc::new(lambda);
-    let class_internal_type = Rc::new(ClassType::new(&[
+    let lambda = Arc::new(lambda);
+    let class_internal_type = Arc::new(ClassType::new(&[
         (KEY_FIELD_NAME.to_string(), key_type),
         (
             VALUES_FIELD_NAME.to_string(),
             ValueType::List(item_type.clone()),
         ),
     ]));
-    let class_type = Rc::new(ValueType::Class(class_internal_type.clone()));
+    let class_type = Arc::new(ValueType::Class(class_internal_type.clone()));
 
     Ok(Box::new(GroupBy {
         target,
@@ -83,6 +89,66 @@ pub(super) fn new_group_by(
     }))
 }
 
+struct AggregateBy {
+    target: Box<dyn Evaluator>,
+    key: Box<dyn Evaluator>,
+    aggregation: Box<dyn Evaluator>,
+    class_type: Arc<ValueType>,
+    item_type: Arc<ValueType>,
+    class_internal_type: Arc<ClassType>,
+}
+
+impl Evaluator for AggregateBy {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.class_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let groups = group(value, self.key.as_ref(), file);
+        let lst = List::new_eager(
+            self.class_type.clone(),
+            groups.into_iter().map(|(key, val)| {
+                let items = Value::List(List::new_eager(self.item_type.clone(), val.into_iter()));
+                let new_file = file.with_binding(items);
+                let value = self.aggregation.eval(&new_file);
+                Value::Class(Class::new(&self.class_internal_type, vec![key, value]))
+            }),
+        );
+        Value::List(lst)
+    }
+}
+
+pub(super) fn new_aggregate_by(
+    target: Box<dyn Evaluator>,
+    key: &LambdaFunction,
+    aggregation: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "AggregateBy method can only be applied to List type".to_string(),
+        ));
+    };
+    let key = key.build(bindings, &item_type)?;
+    let aggregation = aggregation.build(bindings, &ValueType::List(item_type.clone()))?;
+    let class_internal_type = Arc::new(ClassType::new(&[
+        (KEY_FIELD_NAME.to_string(), key.expected_type()),
+        (VALUE_FIELD_NAME.to_string(), aggregation.expected_type()),
+    ]));
+    let class_type = Arc::new(ValueType::Class(class_internal_type.clone()));
+
+    Ok(Box::new(AggregateBy {
+        target,
+        key,
+        aggregation,
+        class_type,
+        item_type,
+        class_internal_type,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -132,6 +198,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_aggregate_by_count() -> Result<(), FindItError> {
+        let expr =
+            read_expr("files.aggregateBy($f $f.extension, $g $g.length()).sortBy($g $g::key)")?;
+        let path = Path::new("./tests/test_cases/order_by/test_files/next/emma/amelia");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr(
+            "[{:key \"bash\", :value 1}, {:key \"json\", :value 1}, {:key \"txt\", :value 5}]",
+        )?
+        .eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_by_sum() -> Result<(), FindItError> {
+        let expr = read_expr(
+            "files.aggregate_by($f $f.extension, $g $g.map($f $f.size).sum()).sortBy($g $g::key).map($g $g::value)",
+        )?;
+        let path = Path::new("./tests/test_cases/order_by/test_files/next/emma/amelia");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr(
+            "files.groupBy($f $f.extension).sortBy($g $g::key).map($g $g::values.map($f $f.size).sum())",
+        )?
+        .eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_by_simple() -> Result<(), FindItError> {
+        let expr =
+            read_expr("[1, 2, 3, 4, 5, 6].aggregateBy($x $x % 2, $g $g.sum()).sortBy($g $g::key)")?;
+        let path = Path::new("./no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("[{:key 0, :value 12}, {:key 1, :value 9}]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_by_empty_return_when_not_a_list() -> Result<(), FindItError> {
+        let expr = read_expr("files.aggregateBy($x $x.extension, $g $g.length())")?;
+        let path = Path::new("./no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn aggregate_by_no_list_return_error() {
+        let err = read_expr("extension.aggregateBy($x $x, $g $g.length())").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn aggregate_by_missing_aggregation_return_error() {
+        let err = read_expr("[1].aggregateBy($x $x)").err();
+
+        assert!(err.is_some());
+    }
+
     #[test]
     fn no_list_return_error() -> Result<(), FindItError> {
         let err = read_expr("extension.groupBy($x $x.extension)").err();
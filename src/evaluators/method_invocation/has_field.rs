@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use crate::{
+    class_type::ClassType,
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct HasField {
+    target: Box<dyn Evaluator>,
+    name: Box<dyn Evaluator>,
+    class_type: Arc<ClassType>,
+}
+
+impl Evaluator for HasField {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(_) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(name) = self.name.eval(file) else {
+            return Value::Empty;
+        };
+        self.class_type.has_field(&name).into()
+    }
+}
+
+pub(super) fn new_has_field(
+    target: Box<dyn Evaluator>,
+    name: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(class_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "HasField method can only be applied to Class type".to_string(),
+        ));
+    };
+    let name = name.build(bindings)?;
+    if name.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "HasField method argument must be a String".to_string(),
+        ));
+    }
+    Ok(Box::new(HasField {
+        target,
+        name,
+        class_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_has_field_true() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\", :size 12}.hasField(\"size\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_field_false() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\", :size 12}.has_field(\"Size\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Bool(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_field_empty_name() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\"}.has_field(content)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_field_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f {:name $f.name}).first().has_field(\"name\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_field_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\"}.has_field(\"name\")")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Bool);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_string_argument_return_error() {
+        let err = read_expr("{:name \"a\"}.has_field(1)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn no_class_return_error() {
+        let err = read_expr("\"name\".has_field(\"name\")").err();
+
+        assert!(err.is_some());
+    }
+}
@@ -0,0 +1,379 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{List, Value, ValueType},
+};
+
+fn read_lines(target: &dyn Evaluator, file: &FileWrapper) -> Option<impl Iterator<Item = String>> {
+    let Value::Path(path) = target.eval(file) else {
+        return None;
+    };
+    if !path.is_file() {
+        file.report(&|| format!("Can not read the lines of [{}]: not a file", path.display()));
+        return None;
+    }
+    file.read_lines(&path)
+}
+
+struct HeadLines {
+    target: Box<dyn Evaluator>,
+    count: Box<dyn Evaluator>,
+}
+impl Evaluator for HeadLines {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::String))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(count) = self.count.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(lines) = read_lines(self.target.as_ref(), file) else {
+            return Value::Empty;
+        };
+        let items = lines.take(count as usize).map(Value::String);
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
+    }
+}
+
+struct TailLines {
+    target: Box<dyn Evaluator>,
+    count: Box<dyn Evaluator>,
+}
+impl Evaluator for TailLines {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::String))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(count) = self.count.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(lines) = read_lines(self.target.as_ref(), file) else {
+            return Value::Empty;
+        };
+        let count = count as usize;
+        let mut last = VecDeque::new();
+        if count > 0 {
+            for line in lines {
+                if last.len() == count {
+                    last.pop_front();
+                }
+                last.push_back(line);
+            }
+        }
+        let items = last.into_iter().map(Value::String);
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
+    }
+}
+
+struct NthLine {
+    target: Box<dyn Evaluator>,
+    index: Box<dyn Evaluator>,
+}
+impl Evaluator for NthLine {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Number(index) = self.index.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(index) = (index as usize).checked_sub(1) else {
+            return Value::Empty;
+        };
+        let Some(mut lines) = read_lines(self.target.as_ref(), file) else {
+            return Value::Empty;
+        };
+        lines.nth(index).into()
+    }
+}
+
+struct FirstLine {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for FirstLine {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Some(mut lines) = read_lines(self.target.as_ref(), file) else {
+            return Value::Empty;
+        };
+        lines.next().into()
+    }
+}
+
+struct Shebang {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for Shebang {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Some(mut lines) = read_lines(self.target.as_ref(), file) else {
+            return Value::Empty;
+        };
+        lines
+            .next()
+            .and_then(|line| {
+                line.strip_prefix("#!")?
+                    .split_whitespace()
+                    .next()
+                    .map(str::to_string)
+            })
+            .into()
+    }
+}
+
+fn build_count(
+    method: &str,
+    target: &dyn Evaluator,
+    count: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    check_path(method, target)?;
+    let count = count.build(bindings)?;
+    if count.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(format!(
+            "{} method argument must be a Number",
+            method
+        )));
+    }
+    Ok(count)
+}
+
+pub(super) fn new_head_lines(
+    target: Box<dyn Evaluator>,
+    count: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let count = build_count("HeadLines", target.as_ref(), count, bindings)?;
+    Ok(Box::new(HeadLines { target, count }))
+}
+
+pub(super) fn new_tail_lines(
+    target: Box<dyn Evaluator>,
+    count: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let count = build_count("TailLines", target.as_ref(), count, bindings)?;
+    Ok(Box::new(TailLines { target, count }))
+}
+
+pub(super) fn new_nth_line(
+    target: Box<dyn Evaluator>,
+    index: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let index = build_count("NthLine", target.as_ref(), index, bindings)?;
+    Ok(Box::new(NthLine { target, index }))
+}
+
+fn check_path(method: &str, target: &dyn Evaluator) -> Result<(), FindItError> {
+    if target.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(format!(
+            "{} method can only be applied to Path type",
+            method
+        )));
+    }
+    Ok(())
+}
+
+pub(super) fn new_first_line(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    check_path("FirstLine", target.as_ref())?;
+    Ok(Box::new(FirstLine { target }))
+}
+
+pub(super) fn new_shebang(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    check_path("Shebang", target.as_ref())?;
+    Ok(Box::new(Shebang { target }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{List, Value, ValueType},
+    };
+
+    const TEST_FILE: &str = "tests/test_cases/display/test_files/week-362.txt";
+
+    fn eval(sql: &str, path: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = &FileWrapper::new(Path::new(path).to_path_buf(), 1);
+        Ok(expr.eval(file))
+    }
+
+    fn lines(lines: &[&str]) -> Value {
+        Value::List(List::new_from_vec(
+            Arc::new(ValueType::String),
+            lines.iter().map(|l| Value::String(l.to_string())).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_head_lines() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("headLines(2)", TEST_FILE)?,
+            lines(&[
+                "quo eligendi amet harum ullam minus quasi ut.",
+                "magni neque sed est incidunt expedita.",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_head_lines_more_than_the_file() -> Result<(), FindItError> {
+        let all = eval("lines()", TEST_FILE)?;
+
+        assert_eq!(eval("head_lines(100)", TEST_FILE)?, all);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_head_lines_zero() -> Result<(), FindItError> {
+        assert_eq!(eval("head(0)", TEST_FILE)?, lines(&[]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_lines() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("tailLines(3)", TEST_FILE)?,
+            lines(&[
+                "adipisci debitis facilis molestiae soluta repellat aut.",
+                "vero libero repudiandae fugiat ducimus occaecati.",
+                "",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_lines_more_than_the_file() -> Result<(), FindItError> {
+        let all = eval("lines()", TEST_FILE)?;
+
+        assert_eq!(eval("tail_lines(100)", TEST_FILE)?, all);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_lines_zero() -> Result<(), FindItError> {
+        assert_eq!(eval("tail(0)", TEST_FILE)?, lines(&[]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nth_line() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("nthLine(2)", TEST_FILE)?,
+            Value::String("magni neque sed est incidunt expedita.".into())
+        );
+        assert_eq!(eval("nth_line(0)", TEST_FILE)?, Value::Empty);
+        assert_eq!(eval("nth_line(100)", TEST_FILE)?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_line_and_shebang() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let script = dir.path().join("run.sh");
+        fs::write(&script, "#!/bin/bash -e\necho hi\n")?;
+        let env_script = dir.path().join("run.py");
+        fs::write(&env_script, "#!  /usr/bin/env python3\nprint(1)\n")?;
+        let text = dir.path().join("text.txt");
+        fs::write(&text, "# not a script\n")?;
+        let empty = dir.path().join("empty.txt");
+        fs::write(&empty, "")?;
+        let path = |p: &std::path::PathBuf| p.to_str().unwrap_or_default().to_string();
+
+        assert_eq!(
+            eval("firstLine()", &path(&script))?,
+            Value::String("#!/bin/bash -e".into())
+        );
+        assert_eq!(
+            eval("first_line().hasPrefix(\"#!\")", &path(&script))?,
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval("shebang()", &path(&script))?,
+            Value::String("/bin/bash".into())
+        );
+        assert_eq!(
+            eval("shebang()", &path(&env_script))?,
+            Value::String("/usr/bin/env".into())
+        );
+        assert_eq!(eval("shebang()", &path(&text))?, Value::Empty);
+        assert_eq!(eval("firstLine()", &path(&empty))?, Value::Empty);
+        assert_eq!(eval("shebang()", &path(&empty))?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_a_file() -> Result<(), FindItError> {
+        assert_eq!(eval("headLines(2)", ".")?, Value::Empty);
+        assert_eq!(eval("tailLines(2)", ".")?, Value::Empty);
+        assert_eq!(eval("nthLine(2)", ".")?, Value::Empty);
+        assert_eq!(eval("firstLine()", ".")?, Value::Empty);
+        assert_eq!(eval("shebang()", "no/such/file")?, Value::Empty);
+        assert_eq!(eval("headLines(2)", "no/such/file")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_count() -> Result<(), FindItError> {
+        assert_eq!(eval("headLines(size)", "no/such/file")?, Value::Empty);
+        assert_eq!(eval("tailLines(size)", "no/such/file")?, Value::Empty);
+        assert_eq!(eval("nthLine(size)", "no/such/file")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_return_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("headLines(1)")?.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+        assert_eq!(
+            read_expr("tailLines(1)")?.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+        assert_eq!(read_expr("nthLine(1)")?.expected_type(), ValueType::String);
+        assert_eq!(read_expr("firstLine()")?.expected_type(), ValueType::String);
+        assert_eq!(read_expr("shebang()")?.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_arguments() {
+        assert!(read_expr("\"abc\".headLines(1)").is_err());
+        assert!(read_expr("12.tailLines(1)").is_err());
+        assert!(read_expr("[1].nthLine(1)").is_err());
+        assert!(read_expr("headLines(\"1\")").is_err());
+        assert!(read_expr("tailLines()").is_err());
+        assert!(read_expr("\"abc\".firstLine()").is_err());
+        assert!(read_expr("12.shebang()").is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    errors::FindItError,
+    evaluators::{
+        expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+        method_invocation::grep::read_pattern,
+    },
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+const MATCH_COLOR: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+struct Highlight {
+    target: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+}
+impl Evaluator for Highlight {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        if !file.color() {
+            return Value::String(str);
+        }
+        let Some(regex) = read_pattern(self.pattern.as_ref(), file) else {
+            return Value::String(str);
+        };
+        let mut highlighted = String::new();
+        let mut last = 0;
+        for found in regex.find_iter(&str).filter(|found| !found.is_empty()) {
+            highlighted.push_str(&str[last..found.start()]);
+            highlighted.push_str(MATCH_COLOR);
+            highlighted.push_str(found.as_str());
+            highlighted.push_str(RESET);
+            last = found.end();
+        }
+        highlighted.push_str(&str[last..]);
+        highlighted.into()
+    }
+}
+
+pub(super) fn new_highlight(
+    target: Box<dyn Evaluator>,
+    pattern: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Highlight method can only be applied to String type".to_string(),
+        ));
+    }
+    let pattern = pattern.build(bindings)?;
+    if pattern.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Highlight method pattern must be a String".to_string(),
+        ));
+    }
+    Ok(Box::new(Highlight { target, pattern }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str, color: bool) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1).with_color(color);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn highlight_with_color() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"a TODO and todo\".highlight(\"(?i)todo\")", true)?,
+            Value::String("a \x1b[1;31mTODO\x1b[0m and \x1b[1;31mtodo\x1b[0m".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn highlight_without_color_is_unchanged() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"a TODO and todo\".highlight(\"(?i)todo\")", false)?,
+            Value::String("a TODO and todo".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn highlight_skips_empty_matches() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"baab\".highlight(\"a*\")", true)?,
+            Value::String("b\x1b[1;31maa\x1b[0mb".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn highlight_with_bad_pattern_is_unchanged() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"text\".highlight(\"[\")", true)?,
+            Value::String("text".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn highlight_of_empty_is_empty() -> Result<(), FindItError> {
+        assert_eq!(eval("content.highlight(\"a\")", true)?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn highlight_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("name.highlight(\"a\")")?.expected_type(),
+            ValueType::String
+        );
+        assert!(read_expr("size.highlight(\"a\")").is_err());
+        assert!(read_expr("name.highlight(1)").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct IfEmpty {
+    target: Box<dyn Evaluator>,
+    default: Box<dyn Evaluator>,
+    value_type: ValueType,
+}
+impl Evaluator for IfEmpty {
+    fn expected_type(&self) -> ValueType {
+        self.value_type.clone()
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        match self.target.eval(file) {
+            Value::Empty => self.default.eval(file),
+            value => value,
+        }
+    }
+}
+
+struct IfPresent {
+    target: Box<dyn Evaluator>,
+    value: Box<dyn Evaluator>,
+}
+impl Evaluator for IfPresent {
+    fn expected_type(&self) -> ValueType {
+        self.value.expected_type()
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        match self.target.eval(file) {
+            Value::Empty => Value::Empty,
+            _ => self.value.eval(file),
+        }
+    }
+}
+
+pub(super) fn new_if_empty(
+    target: Box<dyn Evaluator>,
+    default: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let default = default.build(bindings)?;
+    let value_type = match (target.expected_type(), default.expected_type()) {
+        (ValueType::Empty, value_type) => value_type,
+        (value_type, ValueType::Empty) => value_type,
+        (target_type, default_type) if target_type == default_type => target_type,
+        (target_type, default_type) => {
+            return Err(FindItError::BadExpression(format!(
+                "IfEmpty method argument must have the same type as the target, target type is: {target_type} while argument type is {default_type}"
+            )));
+        }
+    };
+    Ok(Box::new(IfEmpty {
+        target,
+        default,
+        value_type,
+    }))
+}
+
+pub(super) fn new_if_present(
+    target: Box<dyn Evaluator>,
+    value: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let value = value.build(bindings)?;
+    Ok(Box::new(IfPresent { target, value }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn if_empty_on_empty_target() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("content.ifEmpty(\"none\")")?,
+            Value::String("none".into())
+        );
+        assert_eq!(eval("NULL.ifEmpty(3)")?, Value::Number(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn if_empty_on_present_target() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"text\".ifEmpty(\"none\")")?,
+            Value::String("text".into())
+        );
+        assert_eq!(eval("12.if_empty(NULL)")?, Value::Number(12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn if_present_on_empty_target() -> Result<(), FindItError> {
+        assert_eq!(eval("content.ifPresent(\"yes\")")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn if_present_on_present_target() -> Result<(), FindItError> {
+        assert_eq!(eval("name.ifPresent(42)")?, Value::Number(42));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("content.ifEmpty(\"\")")?.expected_type(),
+            ValueType::String
+        );
+        assert_eq!(
+            read_expr("NULL.ifEmpty(1)")?.expected_type(),
+            ValueType::Number
+        );
+        assert_eq!(
+            read_expr("size.if_empty(NULL)")?.expected_type(),
+            ValueType::Number
+        );
+        assert_eq!(
+            read_expr("content.ifPresent(1)")?.expected_type(),
+            ValueType::Number
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn if_empty_with_different_types() {
+        assert!(read_expr("size.ifEmpty(\"none\")").is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+/// The content of a line, without its line ending.
+fn content_of(line: &str) -> &str {
+    line.trim_end_matches(['\n', '\r'])
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    let content = content_of(line);
+    &content[..content.len() - content.trim_start_matches([' ', '\t']).len()]
+}
+
+fn is_blank(line: &str) -> bool {
+    content_of(line).trim_start_matches([' ', '\t']).is_empty()
+}
+
+struct Indent {
+    target: Box<dyn Evaluator>,
+    count: Box<dyn Evaluator>,
+}
+impl Evaluator for Indent {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::String(str), Value::Number(count)) =
+            (self.target.eval(file), self.count.eval(file))
+        else {
+            return Value::Empty;
+        };
+        let Ok(count) = usize::try_from(count) else {
+            return Value::Empty;
+        };
+        let indent = " ".repeat(count);
+        str.split_inclusive('\n')
+            .map(|line| {
+                if content_of(line).is_empty() {
+                    line.to_string()
+                } else {
+                    format!("{indent}{line}")
+                }
+            })
+            .collect::<String>()
+            .into()
+    }
+}
+
+struct Dedent {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for Dedent {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let common = str
+            .split_inclusive('\n')
+            .filter(|line| !is_blank(line))
+            .map(leading_whitespace)
+            .reduce(|common, prefix| {
+                let len = common
+                    .chars()
+                    .zip(prefix.chars())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                // Only spaces and tabs, so each char is a single byte.
+                &common[..len]
+            })
+            .unwrap_or_default();
+        str.split_inclusive('\n')
+            .map(|line| match line.strip_prefix(common) {
+                Some(line) => line,
+                // A blank line that is shorter than the common prefix.
+                None => &line[content_of(line).len()..],
+            })
+            .collect::<String>()
+            .into()
+    }
+}
+
+pub(super) fn new_indent(
+    target: Box<dyn Evaluator>,
+    count: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Indent method can only be applied to String type".to_string(),
+        ));
+    }
+    let count = count.build(bindings)?;
+    if count.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "Indent method argument must be a Number".to_string(),
+        ));
+    }
+    Ok(Box::new(Indent { target, count }))
+}
+
+pub(super) fn new_dedent(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(Dedent { target })),
+        _ => Err(FindItError::BadExpression(
+            "Dedent method can only be applied to String type".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn indent_multi_line_string() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"one\\n\\n  two\\r\\nthree\".indent(2)")?,
+            Value::String("  one\n\n    two\r\n  three".into())
+        );
+        assert_eq!(eval("\"one\\n\".indent(0)")?, Value::String("one\n".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn dedent_unevenly_indented_text() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"    one\\n      two\\n\\n  \\n    three\\n\".dedent()")?,
+            Value::String("one\n  two\n\n\nthree\n".into())
+        );
+        assert_eq!(
+            eval("\"\\t a\\r\\n\\tb\".dedent()")?,
+            Value::String(" a\r\nb".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dedent_without_common_indentation() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"  one\\ntwo\".dedent()")?,
+            Value::String("  one\ntwo".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dedent_reverses_indent() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"a\\n b\\n\\nc\".indent(4).dedent()")?,
+            Value::String("a\n b\n\nc".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_target() -> Result<(), FindItError> {
+        assert_eq!(eval("content.indent(2)")?, Value::Empty);
+        assert_eq!(eval("content.dedent()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("name.indent(1)")?.expected_type(),
+            ValueType::String
+        );
+        assert_eq!(
+            read_expr("name.dedent()")?.expected_type(),
+            ValueType::String
+        );
+        assert!(read_expr("size.indent(1)").is_err());
+        assert!(read_expr("name.indent(\"1\")").is_err());
+        assert!(read_expr("size.dedent()").is_err());
+
+        Ok(())
+    }
+}
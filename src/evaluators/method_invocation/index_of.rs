@@ -1,11 +1,11 @@
-use std::ops::Deref;
+use std::{ops::Deref, sync::Arc};
 
 use crate::{
     errors::FindItError,
     evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
     file_wrapper::FileWrapper,
     parser::ast::expression::Expression,
-    value::{Value, ValueType},
+    value::{List, Value, ValueType},
 };
 
 struct IndexOf {
@@ -52,6 +52,51 @@ impl Evaluator for IndexOfString {
     }
 }
 
+struct IndicesOf {
+    target: Box<dyn Evaluator>,
+    item_to_find: Box<dyn Evaluator>,
+}
+impl Evaluator for IndicesOf {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::Number))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let item = self.item_to_find.eval(file);
+
+        let indices = target_value
+            .items()
+            .into_iter()
+            .enumerate()
+            .filter(|f| f.1 == item)
+            .map(|f| f.0.into());
+        Value::List(List::new_eager(Arc::new(ValueType::Number), indices))
+    }
+}
+
+struct IndicesOfString {
+    target: Box<dyn Evaluator>,
+    item_to_find: Box<dyn Evaluator>,
+}
+impl Evaluator for IndicesOfString {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::Number))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::String(target_value), Value::String(item)) =
+            (self.target.eval(file), self.item_to_find.eval(file))
+        else {
+            return Value::Empty;
+        };
+        let indices = target_value
+            .match_indices(item.as_str())
+            .map(|(index, _)| index.into());
+        Value::List(List::new_eager(Arc::new(ValueType::Number), indices))
+    }
+}
+
 pub(super) fn new_index_of(
     target: Box<dyn Evaluator>,
     item_to_find: &Expression,
@@ -88,9 +133,45 @@ pub(super) fn new_index_of(
     }
 }
 
+pub(super) fn new_indices_of(
+    target: Box<dyn Evaluator>,
+    item_to_find: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::List(items_type) => {
+            let item_to_find = item_to_find.build(bindings)?;
+            if &item_to_find.expected_type() != items_type.deref() {
+                return Err(FindItError::BadExpression(
+                    "IndicesOf item must be the same as the list items".to_string(),
+                ));
+            }
+            Ok(Box::new(IndicesOf {
+                target,
+                item_to_find,
+            }))
+        }
+        ValueType::String => {
+            let item_to_find = item_to_find.build(bindings)?;
+            if item_to_find.expected_type() != ValueType::String {
+                return Err(FindItError::BadExpression(
+                    "IndicesOf item must be a string".to_string(),
+                ));
+            }
+            Ok(Box::new(IndicesOfString {
+                target,
+                item_to_find,
+            }))
+        }
+        _ => Err(FindItError::BadExpression(
+            "IndicesOf method can only be applied to List or String type".to_string(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -216,4 +297,67 @@ mod tests {
         let err = read_expr("\"test\".index_of(true)").err();
         assert!(err.is_some())
     }
+
+    #[test]
+    fn indices_of_returns_all_the_indices() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 10, 4, 2, 5, 12].indices_of(2)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), read_expr("[1, 4]")?.eval(file));
+
+        Ok(())
+    }
+
+    #[test]
+    fn indices_of_returns_empty_list_when_not_found() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 10].allIndicesOf(3).length()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn indices_of_string_returns_byte_offsets() -> Result<(), FindItError> {
+        let expr = read_expr("\"aaa é aa\".indicesOf(\"aa\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), read_expr("[0, 7]")?.eval(file));
+
+        Ok(())
+    }
+
+    #[test]
+    fn indices_of_string_returns_empty_when_needed() -> Result<(), FindItError> {
+        let expr = read_expr("content.indices_of(\"\\n\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn indices_of_return_type() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".indices_of(\"b\")")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::Number))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn indices_of_different_value_types() {
+        assert!(read_expr("[1, 2, 3].indices_of(true)").is_err());
+        assert!(read_expr("\"test\".indices_of(1)").is_err());
+        assert!(read_expr("123.indices_of(1)").is_err());
+    }
 }
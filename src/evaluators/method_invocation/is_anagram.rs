@@ -0,0 +1,124 @@
+use itertools::Itertools;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct IsAnagram {
+    target: Box<dyn Evaluator>,
+    other: Box<dyn Evaluator>,
+}
+impl Evaluator for IsAnagram {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(other) = self.other.eval(file) else {
+            return Value::Empty;
+        };
+        (target_value.chars().counts() == other.chars().counts()).into()
+    }
+}
+pub(super) fn new_is_anagram(
+    target: Box<dyn Evaluator>,
+    other: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "IsAnagram method can only be applied to String type".to_string(),
+        ));
+    }
+    let other = other.build(bindings)?;
+    if other.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "IsAnagram method argument must be a String".to_string(),
+        ));
+    }
+    Ok(Box::new(IsAnagram { target, other }))
+}
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_is_anagram_true() -> Result<(), FindItError> {
+        let expr = read_expr("\"listen\".is_anagram(\"silent\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), true.into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_anagram_false() -> Result<(), FindItError> {
+        let expr = read_expr("\"aab\".isAnagram(\"abb\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), false.into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_anagram_no_target() -> Result<(), FindItError> {
+        let expr = read_expr("content.is_anagram(\"a\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_anagram_empty_other() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".is_anagram(content)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_anagram_return_type() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".is_anagram(\"cba\")")?;
+
+        assert_eq!(expr.expected_type(), ValueType::Bool);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_anagram_no_str() {
+        let err = read_expr("\"abc\".is_anagram(12)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn test_is_anagram_no_str_two() {
+        let err = read_expr("12.is_anagram(\"a\")").err();
+
+        assert!(err.is_some());
+    }
+}
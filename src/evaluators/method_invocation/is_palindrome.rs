@@ -0,0 +1,92 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+struct IsPalindrome {
+    target: Box<dyn Evaluator>,
+}
+
+impl Evaluator for IsPalindrome {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Bool
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(s) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        s.chars().eq(s.chars().rev()).into()
+    }
+}
+
+pub(super) fn new_is_palindrome(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(IsPalindrome { target })),
+        _ => Err(FindItError::BadExpression(
+            "IsPalindrome method can only be applied to String type".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn is_palindrome_no_string_expr() {
+        let err = read_expr("12.isPalindrome()").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn is_palindrome_too_many_args() {
+        let err = read_expr("\"abc\".isPalindrome(\"def\")").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn is_palindrome_null_str_return_empty() {
+        let eval = read_expr("content.isPalindrome()").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+        assert_eq!(value, Value::Empty)
+    }
+
+    #[test]
+    fn is_palindrome_expect_bool() {
+        let expr = read_expr("\"test\".is_palindrome").unwrap();
+        assert_eq!(expr.expected_type(), ValueType::Bool);
+    }
+
+    #[test]
+    fn is_palindrome_true() {
+        let eval = read_expr("\"a\\u05d0b\\u05d0a\".is_palindrome()").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+
+        assert_eq!(value, Value::Bool(true))
+    }
+
+    #[test]
+    fn is_palindrome_false() {
+        let eval = read_expr("\"abca\".is_palindrome()").unwrap();
+        let path = Path::new("no/such/file");
+        let wrapper = FileWrapper::new(path.to_path_buf(), 2);
+        let value = eval.eval(&wrapper);
+
+        assert_eq!(value, Value::Bool(false))
+    }
+}
@@ -0,0 +1,287 @@
+use serde_json::Value as JsonValue;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+#[derive(Debug, PartialEq)]
+enum Step {
+    Field(String),
+    Index(usize),
+}
+
+fn parse_selector(selector: &str) -> Option<Vec<Step>> {
+    let selector = selector.trim();
+    let mut rest = selector.strip_prefix('$').unwrap_or(selector);
+    let mut steps = vec![];
+    let mut first = true;
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix('[') {
+            let (inside, tail) = tail.split_once(']')?;
+            let inside = inside.trim();
+            let quoted = inside
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .or_else(|| inside.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')));
+            match quoted {
+                Some(name) => steps.push(Step::Field(name.to_string())),
+                None => steps.push(Step::Index(inside.parse().ok()?)),
+            }
+            rest = tail;
+        } else {
+            let tail = match rest.strip_prefix('.') {
+                Some(tail) => tail,
+                None if first => rest,
+                None => return None,
+            };
+            let end = tail.find(['.', '[']).unwrap_or(tail.len());
+            if end == 0 {
+                return None;
+            }
+            steps.push(Step::Field(tail[..end].to_string()));
+            rest = &tail[end..];
+        }
+        first = false;
+    }
+    Some(steps)
+}
+
+fn select<'a>(json: &'a JsonValue, steps: &[Step]) -> Option<&'a JsonValue> {
+    steps.iter().try_fold(json, |json, step| match step {
+        Step::Field(name) => json.as_object()?.get(name),
+        Step::Index(index) => json.as_array()?.get(*index),
+    })
+}
+
+fn to_value(json: &JsonValue) -> Value {
+    match json {
+        JsonValue::Null => Value::Empty,
+        JsonValue::String(str) => str.as_str().into(),
+        JsonValue::Bool(_) | JsonValue::Number(_) | JsonValue::Array(_) | JsonValue::Object(_) => {
+            json.to_string().into()
+        }
+    }
+}
+
+struct JsonGet {
+    target: Box<dyn Evaluator>,
+    selector: Box<dyn Evaluator>,
+}
+impl Evaluator for JsonGet {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(selector) = self.selector.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(steps) = parse_selector(&selector) else {
+            file.report(&|| format!("Invalid JSON selector: `{selector}`"));
+            return Value::Empty;
+        };
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Ok(json) = serde_json::from_str::<JsonValue>(&str) else {
+            return Value::Empty;
+        };
+        select(&json, &steps).map_or(Value::Empty, to_value)
+    }
+}
+
+pub(super) fn new_json_get(
+    target: Box<dyn Evaluator>,
+    selector: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "JsonGet method can only be applied to String type".to_string(),
+        ));
+    }
+    let selector = selector.build(bindings)?;
+    if selector.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "JsonGet method selector must be a String".to_string(),
+        ));
+    }
+    Ok(Box::new(JsonGet { target, selector }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    use super::{Step, parse_selector};
+
+    const DOCUMENT: &str = r#"{
+        "name": "findit",
+        "version": "0.1.3",
+        "size": 120,
+        "stable": false,
+        "license": null,
+        "authors": [{"name": "yift"}, {"name": "other"}],
+        "dependencies": {"chrono": {"version": "0.4"}, "odd.name": 1},
+        "tags": ["cli", "find"]
+    }"#;
+
+    fn eval_on_document(selector: &str) -> Result<Value, FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("package.json");
+        fs::write(&path, DOCUMENT)?;
+        let file = &FileWrapper::new(path, 1);
+        let expr = read_expr(&format!("content.jsonGet(\"{selector}\")"))?;
+        Ok(expr.eval(file))
+    }
+
+    #[test]
+    fn test_parse_selector() {
+        assert_eq!(parse_selector("$"), Some(vec![]));
+        assert_eq!(parse_selector(""), Some(vec![]));
+        assert_eq!(
+            parse_selector("$.a.b[2]['c.d'][\"e\"]"),
+            Some(vec![
+                Step::Field("a".into()),
+                Step::Field("b".into()),
+                Step::Index(2),
+                Step::Field("c.d".into()),
+                Step::Field("e".into()),
+            ])
+        );
+        assert_eq!(
+            parse_selector("a.b"),
+            Some(vec![Step::Field("a".into()), Step::Field("b".into())])
+        );
+        assert_eq!(parse_selector("$a"), Some(vec![Step::Field("a".into())]));
+        assert_eq!(parse_selector("$.a..b"), None);
+        assert_eq!(parse_selector("$.a[x]"), None);
+        assert_eq!(parse_selector("$.a[1"), None);
+        assert_eq!(parse_selector("$[0]b"), None);
+    }
+
+    #[test]
+    fn test_top_level_string() -> Result<(), FindItError> {
+        assert_eq!(
+            eval_on_document("$.version")?,
+            Value::String("0.1.3".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_fields() -> Result<(), FindItError> {
+        assert_eq!(
+            eval_on_document("$.dependencies.chrono.version")?,
+            Value::String("0.4".into())
+        );
+        assert_eq!(
+            eval_on_document("$.authors[1].name")?,
+            Value::String("other".into())
+        );
+        assert_eq!(
+            eval_on_document("dependencies['odd.name']")?,
+            Value::String("1".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scalars_as_text() -> Result<(), FindItError> {
+        assert_eq!(eval_on_document("$.size")?, Value::String("120".into()));
+        assert_eq!(eval_on_document("$.stable")?, Value::String("false".into()));
+        assert_eq!(eval_on_document("$.license")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_objects_and_arrays_as_json() -> Result<(), FindItError> {
+        assert_eq!(
+            eval_on_document("$.tags")?,
+            Value::String("[\"cli\",\"find\"]".into())
+        );
+        assert_eq!(
+            eval_on_document("$.authors[0]")?,
+            Value::String("{\"name\":\"yift\"}".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_path() -> Result<(), FindItError> {
+        assert_eq!(eval_on_document("$.nothing")?, Value::Empty);
+        assert_eq!(eval_on_document("$.tags[5]")?, Value::Empty);
+        assert_eq!(eval_on_document("$.name.first")?, Value::Empty);
+        assert_eq!(eval_on_document("$.tags.first")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_selector() -> Result<(), FindItError> {
+        assert_eq!(eval_on_document("$.tags[first]")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chain_and_cast() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        let expr = read_expr(
+            "\"{\\\"a\\\": {\\\"b\\\": [1, 20]}}\".jsonGet(\"a\").jsonPath(\"b[1]\") AS NUMBER",
+        )?;
+
+        assert_eq!(expr.eval(file), Value::Number(20));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_json() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        let expr = read_expr("\"{not json\".jsonGet(\"$.a\")")?;
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_content() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        let expr = read_expr("content.jsonGet(\"$.a\")")?;
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("content.json_get(\"$.a\")")?;
+
+        assert_eq!(expr.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_types() {
+        assert!(read_expr("12.jsonGet(\"$.a\")").is_err());
+        assert!(read_expr("content.jsonGet(12)").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{List, Value, ValueType},
+};
+
+struct Keys {
+    target: Box<dyn Evaluator>,
+    keys: Arc<Vec<Value>>,
+}
+
+impl Evaluator for Keys {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::String))
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(_) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        Value::List(List::new_from_vec(
+            Arc::new(ValueType::String),
+            self.keys.as_ref().clone(),
+        ))
+    }
+}
+
+pub(super) fn new_keys(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(class_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Keys method can only be applied to Class type".to_string(),
+        ));
+    };
+    let keys = class_type
+        .fields()
+        .map(|(name, _)| Value::String(name.clone()))
+        .collect();
+    Ok(Box::new(Keys {
+        target,
+        keys: Arc::new(keys),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_keys() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\", :size 12, :ok TRUE}.keys()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("[\"name\", \"size\", \"ok\"]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_keys_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\"}.keys")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_keys_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f {:name $f.name}).first().keys()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_class_return_error() {
+        let err = read_expr("12.keys()").err();
+
+        assert!(err.is_some());
+    }
+}
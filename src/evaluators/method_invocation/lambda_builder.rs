@@ -1,7 +1,7 @@
 use crate::{
     errors::FindItError,
     evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
-    parser::ast::methods::LambdaFunction,
+    parser::ast::methods::{BinaryLambdaFunction, LambdaFunction},
     value::ValueType,
 };
 
@@ -16,3 +16,18 @@ impl LambdaFunction {
         self.body.build(&new_bindings)
     }
 }
+
+impl BinaryLambdaFunction {
+    pub(super) fn build(
+        &self,
+        bindings: &BindingsTypes,
+        first_type: &ValueType,
+        second_type: &ValueType,
+    ) -> Result<Box<dyn Evaluator>, FindItError> {
+        let new_bindings = bindings
+            .with(&self.first_parameter, first_type.clone())
+            .with(&self.second_parameter, second_type.clone());
+
+        self.body.build(&new_bindings)
+    }
+}
@@ -17,6 +17,7 @@ impl Evaluator for Length {
         match target_value {
             Value::List(list) => list.count().into(),
             Value::String(s) => s.len().into(),
+            Value::Bytes(b) => b.len().into(),
             Value::Path(f) => {
                 if let Ok(metadata) = std::fs::metadata(&f)
                     && metadata.is_file()
@@ -34,9 +35,11 @@ impl Evaluator for Length {
 
 pub(super) fn new_length(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
     match target.expected_type() {
-        ValueType::List(_) | ValueType::String | ValueType::Path => Ok(Box::new(Length { target })),
+        ValueType::List(_) | ValueType::String | ValueType::Path | ValueType::Bytes => {
+            Ok(Box::new(Length { target }))
+        }
         _ => Err(FindItError::BadExpression(
-            "Length method can only be applied to List, String or Path types".to_string(),
+            "Length method can only be applied to List, String, Path or Bytes types".to_string(),
         )),
     }
 }
@@ -1,8 +1,4 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    rc::Rc,
-};
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -16,14 +12,14 @@ struct LinesString {
 }
 impl Evaluator for LinesString {
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::String))
+        ValueType::List(Arc::new(ValueType::String))
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::String(str) = self.target.eval(file) else {
             return Value::Empty;
         };
         let items = str.lines().map(|s| Value::String(s.to_string()));
-        Value::List(List::new_eager(Rc::new(ValueType::String), items))
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
     }
 }
 
@@ -32,18 +28,17 @@ struct LinesFile {
 }
 impl Evaluator for LinesFile {
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::String))
+        ValueType::List(Arc::new(ValueType::String))
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::Path(path) = self.target.eval(file) else {
             return Value::Empty;
         };
-        let Ok(file) = File::open(path) else {
+        let Some(lines) = file.read_lines(&path) else {
             return Value::Empty;
         };
-        let buf = BufReader::new(file);
-        let items = buf.lines().map_while(Result::ok).map(Value::String);
-        Value::List(List::new_lazy(Rc::new(ValueType::String), items))
+        let items = lines.map(Value::String);
+        Value::List(List::new_lazy(Arc::new(ValueType::String), items))
     }
 }
 
@@ -59,7 +54,7 @@ pub(super) fn new_lines(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>
 
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -77,7 +72,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![
                     Value::String("one".into()),
                     Value::String("two".into()),
@@ -107,7 +102,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::String))
+            ValueType::List(Arc::new(ValueType::String))
         );
 
         Ok(())
@@ -128,7 +123,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![
                     Value::String("quo eligendi amet harum ullam minus quasi ut.".into()),
                     Value::String("magni neque sed est incidunt expedita.".into()),
@@ -153,7 +148,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::String))
+            ValueType::List(Arc::new(ValueType::String))
         );
 
         Ok(())
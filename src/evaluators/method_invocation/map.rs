@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -10,8 +10,8 @@ use crate::{
 
 struct Map {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
-    items_type: Rc<ValueType>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
 }
 
 impl Evaluator for Map {
@@ -44,12 +44,12 @@ pub(super) fn new_map(
     };
     let lambda = lambda.build(bindings, &input_item_type)?;
     let output_item_type = lambda.expected_type().clone();
-    let lambda = Rc::new(lambda);
+    let lambda = Arc::new(lambda);
 
     Ok(Box::new(Map {
         target,
         lambda,
-        items_type: Rc::new(output_item_type),
+        items_type: Arc::new(output_item_type),
     }))
 }
 
@@ -57,7 +57,7 @@ pub(super) fn new_map(
 mod tests {
     use std::{
         path::{Path, PathBuf},
-        rc::Rc,
+        sync::Arc,
     };
 
     use crate::{
@@ -75,7 +75,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(10), Value::Number(20), Value::Number(30)].into_iter(),
             ))
         );
@@ -0,0 +1,203 @@
+use std::{collections::HashSet, path::PathBuf, sync::Arc};
+
+use crate::{
+    class_type::{Class, ClassType},
+    content::ContentOptions,
+    debugger::create_debugger,
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator},
+    file_wrapper::FileWrapper,
+    parser::ast::methods::LambdaFunction,
+    value::{Value, ValueType},
+};
+
+/// The values are kept as is, only the class type (with the new names) is replaced.
+struct MapKeys {
+    target: Box<dyn Evaluator>,
+    class_type: Arc<ClassType>,
+}
+
+impl Evaluator for MapKeys {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Class(self.class_type.clone())
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let details = target_value.values().cloned().collect();
+        Value::Class(Class::new(&self.class_type, details))
+    }
+}
+
+pub(super) fn new_map_keys(
+    target: Box<dyn Evaluator>,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(target_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "MapKeys method can only be applied to Class type".to_string(),
+        ));
+    };
+    let lambda = lambda.build(bindings, &ValueType::String)?;
+    if lambda.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "MapKeys method lambda must return a String".to_string(),
+        ));
+    }
+    // The fields of a class are known when the expression is built, so the new names are
+    // calculated once, here, from the old names only.
+    let debugger = Arc::new(create_debugger(None)?);
+    let file =
+        FileWrapper::new_with_debugger(PathBuf::new(), 0, &debugger, ContentOptions::default());
+    let mut names = HashSet::new();
+    let mut fields = vec![];
+    for (name, tp) in target_type.fields() {
+        let Value::String(new_name) = lambda.eval(&file.with_binding(name.as_str().into())) else {
+            return Err(FindItError::BadExpression(format!(
+                "MapKeys method lambda returned no name for the field {name}"
+            )));
+        };
+        if !names.insert(new_name.clone()) {
+            return Err(FindItError::BadExpression(format!(
+                "MapKeys method lambda returned the name {new_name} more than once"
+            )));
+        }
+        fields.push((new_name, tp.clone()));
+    }
+
+    Ok(Box::new(MapKeys {
+        target,
+        class_type: Arc::new(ClassType::new(&fields)),
+    }))
+}
+
+struct MapValues {
+    target: Box<dyn Evaluator>,
+    lambdas: Vec<Box<dyn Evaluator>>,
+    class_type: Arc<ClassType>,
+}
+
+impl Evaluator for MapValues {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Class(self.class_type.clone())
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let details = target_value
+            .values()
+            .zip(&self.lambdas)
+            .map(|(value, lambda)| lambda.eval(&file.with_binding(value.clone())))
+            .collect();
+        Value::Class(Class::new(&self.class_type, details))
+    }
+}
+
+pub(super) fn new_map_values(
+    target: Box<dyn Evaluator>,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(target_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "MapValues method can only be applied to Class type".to_string(),
+        ));
+    };
+    // Each field can have a different type, so the lambda is built for each one of them.
+    let mut lambdas = vec![];
+    let mut fields = vec![];
+    for (name, tp) in target_type.fields() {
+        let lambda = lambda.build(bindings, tp)?;
+        fields.push((name.clone(), lambda.expected_type()));
+        lambdas.push(lambda);
+    }
+
+    Ok(Box::new(MapValues {
+        target,
+        lambdas,
+        class_type: Arc::new(ClassType::new(&fields)),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_map_keys() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(
+            read_expr("{:Name \"a\", :SIZE 12}.map_keys($k $k.toLower())::size")?.eval(file),
+            Value::Number(12)
+        );
+        assert_eq!(
+            read_expr("{:Name \"a\", :SIZE 12}.mapKeys($k \"f_\" + $k).keys()")?
+                .eval(file)
+                .to_string(),
+            "[f_Name, f_SIZE]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_keys_errors() {
+        assert!(read_expr("{:a 1, :A 2}.map_keys($k $k.toLower())").is_err());
+        assert!(read_expr("{:a 1}.map_keys($k $k.length())").is_err());
+        assert!(read_expr("{:a 1}.map_keys($k content)").is_err());
+        assert!(read_expr("[1].map_keys($k $k)").is_err());
+    }
+
+    #[test]
+    fn test_map_values() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        let expr = read_expr("{:name \"abc\", :size 12}.map_values($v $v AS STRING)")?;
+
+        assert_eq!(expr.eval(file).to_string(), "{\"name\":abc, \"size\":12}");
+        assert_eq!(
+            read_expr("{:name \"abc\", :size 12}.mapValues($v $v AS STRING)::size")?.eval(file),
+            Value::String("12".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_values_type() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"abc\", :size 12}.map_values($v [$v])")?;
+        let ValueType::Class(class_type) = expr.expected_type() else {
+            panic!("Not a class");
+        };
+
+        assert_eq!(
+            class_type.to_string(),
+            "class<name:list<string>;size:list<number>;>"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_values_of_empty() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        let expr = read_expr("[{:a 1}].skip(1).first().map_values($v $v + 1)")?;
+
+        assert_eq!(expr.eval(file), Value::Empty);
+        assert!(read_expr("12.map_values($v $v)").is_err());
+
+        Ok(())
+    }
+}
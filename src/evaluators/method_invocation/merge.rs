@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use crate::{
+    class_type::{Class, ClassType},
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+enum Source {
+    Target(usize),
+    Other(usize),
+}
+
+struct Merge {
+    target: Box<dyn Evaluator>,
+    other: Box<dyn Evaluator>,
+    sources: Vec<Source>,
+    class_type: Arc<ClassType>,
+}
+
+impl Evaluator for Merge {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Class(self.class_type.clone())
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Class(other_value) = self.other.eval(file) else {
+            return Value::Empty;
+        };
+        let details = self
+            .sources
+            .iter()
+            .map(|source| match source {
+                Source::Target(index) => target_value.clone().get(*index),
+                Source::Other(index) => other_value.clone().get(*index),
+            })
+            .collect();
+        Value::Class(Class::new(&self.class_type, details))
+    }
+}
+
+pub(super) fn new_merge(
+    target: Box<dyn Evaluator>,
+    other: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(target_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Merge method can only be applied to Class type".to_string(),
+        ));
+    };
+    let other = other.build(bindings)?;
+    let ValueType::Class(other_type) = other.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Merge method argument must be a Class".to_string(),
+        ));
+    };
+    let mut fields = vec![];
+    let mut sources = vec![];
+    for (index, (name, tp)) in target_type.fields().enumerate() {
+        match other_type.get_index_and_type(name) {
+            Ok((other_index, tp)) => {
+                fields.push((name.clone(), tp));
+                sources.push(Source::Other(other_index));
+            }
+            Err(_) => {
+                fields.push((name.clone(), tp.clone()));
+                sources.push(Source::Target(index));
+            }
+        }
+    }
+    for (index, (name, tp)) in other_type.fields().enumerate() {
+        if !target_type.has_field(name) {
+            fields.push((name.clone(), tp.clone()));
+            sources.push(Source::Other(index));
+        }
+    }
+
+    Ok(Box::new(Merge {
+        target,
+        other,
+        sources,
+        class_type: Arc::new(ClassType::new(&fields)),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError, evaluators::expr::read_expr, file_wrapper::FileWrapper, value::Value,
+    };
+
+    #[test]
+    fn test_merge_disjoint() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\"}.merge({:size 12})")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("{:name \"a\", :size 12}")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_right_wins() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\", :size 1, :ok TRUE}.merge({:size 12, :extra \"x\"})")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("{:name \"a\", :size 12, :ok TRUE, :extra \"x\"}")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_right_type_wins() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\", :size 1}.merge({:size \"big\"})::size")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::String("big".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_empty_other() -> Result<(), FindItError> {
+        let expr = read_expr("{:name \"a\"}.merge(files.map($f {:size $f.size}).first())")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_class_argument_return_error() {
+        let err = read_expr("{:name \"a\"}.merge(12)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn no_class_return_error() {
+        let err = read_expr("12.merge({:name \"a\"})").err();
+
+        assert!(err.is_some());
+    }
+}
@@ -3,78 +3,162 @@ use crate::{
     evaluators::{
         expr::{BindingsTypes, Evaluator, EvaluatorFactory},
         extract::MeExtractor,
+        functions::time::{format::new_format, parse::new_parse},
         method_invocation::{
+            age::new_age,
             all::new_all,
+            ancestor::new_ancestor,
             any::new_any,
             avg::new_avg,
-            contains::new_contains,
-            distinct::{new_distinct, new_distinct_by},
+            contains::{new_contains, new_contains_matching},
+            count_matches::new_count_matches,
+            depth_in::new_depth_in,
+            distinct::{
+                new_distinct, new_distinct_by, new_distinct_count, new_distinct_count_by,
+                new_sorted_distinct,
+            },
+            encoding::{Encoding, new_decode, new_encode},
+            entries::new_entries,
+            entropy::new_entropy,
             filter::new_filter,
+            filter_map::new_filter_map,
             first::new_first,
             flat_map::new_flat_map,
-            group_by::new_group_by,
+            format_size::new_format_size,
+            grep::new_grep,
+            group_by::{new_aggregate_by, new_group_by},
+            has_field::new_has_field,
             has_prefix::new_has_prefix,
             has_suffix::new_has_suffix,
-            index_of::new_index_of,
+            head_lines::{
+                new_first_line, new_head_lines, new_nth_line, new_shebang, new_tail_lines,
+            },
+            highlight::new_highlight,
+            if_empty::{new_if_empty, new_if_present},
+            indent::{new_dedent, new_indent},
+            index_of::{new_index_of, new_indices_of},
+            is_anagram::new_is_anagram,
+            is_palindrome::new_is_palindrome,
             join::new_join,
+            json_get::new_json_get,
+            keys::new_keys,
             last::new_last,
             length::new_length,
             lines::new_lines,
             map::new_map,
+            map_fields::{new_map_keys, new_map_values},
             max::new_max,
+            merge::new_merge,
             min::new_min,
+            mode::new_mode,
+            percentile::new_percentile,
+            regex_replace::new_regex_replace_all,
             remove_prefix::new_remove_prefix,
             remove_suffix::new_remove_suffix,
             reverse::new_reverse,
+            scan::new_scan,
+            shell_split::new_shell_split,
             skip::new_skip,
             sort::{new_sort, new_sort_by},
             split::new_split,
+            split_at::new_split_at,
             sum::new_sum,
             take::new_take,
+            take_last::{Tail, new_take_last},
+            tee::new_tee,
             to_lower::new_to_lower,
             to_upper::new_to_upper,
+            top_n::new_top_n,
             trim::{new_trim, new_trim_head, new_trim_tail},
+            url_encoding::{new_url_decode, new_url_encode},
+            values::new_values,
+            variance::{Spread, new_variance},
+            with_extension::{new_add_extension, new_extension, new_with_extension},
             words::new_words,
+            wrap::new_wrap,
+            xattr::new_xattr,
+            zip_longest::new_zip_longest,
+            zip_with::new_zip_with,
         },
     },
     parser::ast::methods::{Method, MethodInvocation},
 };
 
+mod age;
 mod all;
+mod ancestor;
 mod any;
 mod avg;
 mod contains;
+mod count_matches;
+#[cfg(test)]
+mod counting_list;
 mod debug;
+mod depth_in;
 mod distinct;
+mod encoding;
+mod entries;
+mod entropy;
 mod enumerate;
 mod filter;
+mod filter_map;
 mod first;
 mod flat_map;
+mod format_size;
+mod grep;
 mod group_by;
+mod has_field;
 mod has_prefix;
 mod has_suffix;
+mod head_lines;
+mod highlight;
+mod if_empty;
+mod indent;
 mod index_of;
+mod is_anagram;
+mod is_palindrome;
 mod join;
+mod json_get;
+mod keys;
 mod lambda_builder;
 mod last;
 mod length;
 mod lines;
 mod map;
+mod map_fields;
 mod max;
+mod merge;
 mod min;
+mod mode;
+mod percentile;
+mod regex_replace;
 mod remove_prefix;
 mod remove_suffix;
 mod reverse;
+mod scan;
+mod shell_split;
 mod skip;
 mod sort;
 mod split;
+mod split_at;
 mod sum;
 mod take;
+mod take_last;
+mod tee;
 mod to_lower;
 mod to_upper;
+mod top_n;
 mod trim;
+mod url_encoding;
+mod values;
+mod variance;
 mod walk;
+mod with_extension;
 mod words;
+mod wrap;
+mod xattr;
+mod zip_longest;
+mod zip_with;
 
 impl EvaluatorFactory for MethodInvocation {
     fn build(&self, bindings: &BindingsTypes) -> Result<Box<dyn Evaluator>, FindItError> {
@@ -95,32 +179,105 @@ impl EvaluatorFactory for MethodInvocation {
             Method::Sum => new_sum(target),
             Method::Avg => new_avg(target),
             Method::Max => new_max(target),
+            Method::Mode => new_mode(target),
+            Method::Percentile(percent) => new_percentile(target, percent, bindings),
+            Method::Variance => new_variance(target, Spread::Variance),
+            Method::StdDev => new_variance(target, Spread::StdDev),
             Method::Min => new_min(target),
             Method::Sort => new_sort(target),
             Method::Distinct => new_distinct(target),
             Method::DistinctBy(lambda) => new_distinct_by(target, lambda, bindings),
-            Method::SortBy(lambda) => new_sort_by(target, lambda, bindings),
+            Method::DistinctCount => new_distinct_count(target),
+            Method::DistinctCountBy(lambda) => new_distinct_count_by(target, lambda, bindings),
+            Method::SortBy(keys) => new_sort_by(target, keys, bindings),
             Method::Skip(by) => new_skip(target, by, bindings),
             Method::Take(limit) => new_take(target, limit, bindings),
+            Method::SkipLast(by) => new_take_last(target, by, bindings, Tail::Skip),
+            Method::TakeLast(limit) => new_take_last(target, limit, bindings, Tail::Take),
+            Method::SplitAt(index) => new_split_at(target, index, bindings),
             Method::Join(delimiter) => new_join(target, delimiter, bindings),
             Method::Split(delimiter) => new_split(target, delimiter, bindings),
             Method::Lines => new_lines(target),
+            Method::EncodeBase64 => new_encode(target, Encoding::Base64),
+            Method::DecodeBase64 => new_decode(target, Encoding::Base64),
+            Method::EncodeHex => new_encode(target, Encoding::Hex),
+            Method::DecodeHex => new_decode(target, Encoding::Hex),
+            Method::UrlEncode => new_url_encode(target),
+            Method::UrlDecode => new_url_decode(target),
+            Method::JsonGet(selector) => new_json_get(target, selector, bindings),
+            Method::IfEmpty(default) => new_if_empty(target, default, bindings),
+            Method::IfPresent(value) => new_if_present(target, value, bindings),
             Method::Words => new_words(target),
+            Method::ShellSplit => new_shell_split(target),
             Method::First => new_first(target),
             Method::Last => new_last(target),
             Method::Contains(item_to_find) => new_contains(target, item_to_find, bindings),
+            Method::ContainsMatching(lambda) => new_contains_matching(target, lambda, bindings),
             Method::IndexOf(item_to_find) => new_index_of(target, item_to_find, bindings),
+            Method::IndicesOf(item_to_find) => new_indices_of(target, item_to_find, bindings),
             Method::FlatMap(lambda) => new_flat_map(target, lambda, bindings),
             Method::All(lambda) => new_all(target, lambda, bindings),
             Method::Any(lambda) => new_any(target, lambda, bindings),
             Method::GroupBy(lambda) => new_group_by(target, lambda, bindings),
             Method::Enumerate => enumerate::new_enumerate(target),
-            Method::Walk => walk::new_walker(target),
+            Method::Walk(max_depth) => {
+                walk::new_walker(target, max_depth, walk::WalkKind::Files, bindings)
+            }
+            Method::WalkFiles(max_depth) => {
+                walk::new_walker(target, max_depth, walk::WalkKind::Files, bindings)
+            }
+            Method::WalkDirs(max_depth) => {
+                walk::new_walker(target, max_depth, walk::WalkKind::Dirs, bindings)
+            }
             Method::HasPrefix(prefix) => new_has_prefix(target, prefix, bindings),
             Method::HasSuffix(suffix) => new_has_suffix(target, suffix, bindings),
             Method::RemovePrefix(prefix) => new_remove_prefix(target, prefix, bindings),
             Method::RemoveSuffix(suffix) => new_remove_suffix(target, suffix, bindings),
             Method::Debug(lambda) => debug::new_debug(target, lambda, bindings),
+            Method::IsPalindrome => new_is_palindrome(target),
+            Method::IsAnagram(other) => new_is_anagram(target, other, bindings),
+            Method::ZipLongest(other, default) => new_zip_longest(target, other, default, bindings),
+            Method::Keys => new_keys(target),
+            Method::Values => new_values(target),
+            Method::Entries => new_entries(target),
+            Method::HasField(name) => new_has_field(target, name, bindings),
+            Method::Merge(other) => new_merge(target, other, bindings),
+            Method::MapKeys(lambda) => new_map_keys(target, lambda, bindings),
+            Method::MapValues(lambda) => new_map_values(target, lambda, bindings),
+            Method::Tee(lambda) => new_tee(target, lambda, bindings),
+            Method::SortedDistinct => new_sorted_distinct(target),
+            Method::FormatSize(unit) => new_format_size(target, unit, bindings),
+            Method::Age => new_age(target),
+            Method::FormatDate(format) => new_format(target, format.build(bindings)?),
+            Method::ParseDate(format) => new_parse(target, format.build(bindings)?),
+            Method::AggregateBy(key, aggregation) => {
+                new_aggregate_by(target, key, aggregation, bindings)
+            }
+            Method::WithExtension(extension) => new_with_extension(target, extension, bindings),
+            Method::AddExtension(extension) => new_add_extension(target, extension, bindings),
+            Method::Extension => new_extension(target),
+            Method::TopN(count, lambda) => new_top_n(target, count, lambda, bindings),
+            Method::Ancestor(levels) => new_ancestor(target, levels, bindings),
+            Method::Xattr(name) => new_xattr(target, name, bindings),
+            Method::Entropy => new_entropy(target),
+            Method::HeadLines(count) => new_head_lines(target, count, bindings),
+            Method::TailLines(count) => new_tail_lines(target, count, bindings),
+            Method::NthLine(index) => new_nth_line(target, index, bindings),
+            Method::FirstLine => new_first_line(target),
+            Method::Shebang => new_shebang(target),
+            Method::Grep(pattern) => new_grep(target, pattern, bindings),
+            Method::CountMatches(pattern) => new_count_matches(target, pattern, bindings),
+            Method::Highlight(pattern) => new_highlight(target, pattern, bindings),
+            Method::RegexReplaceAll(pattern, replacement) => {
+                new_regex_replace_all(target, pattern, replacement, bindings)
+            }
+            Method::Indent(count) => new_indent(target, count, bindings),
+            Method::Wrap(width) => new_wrap(target, width, bindings),
+            Method::Dedent => new_dedent(target),
+            Method::DepthIn(root) => new_depth_in(target, root, bindings),
+            Method::FilterMap(lambda) => new_filter_map(target, lambda, bindings),
+            Method::Scan(initial, lambda) => new_scan(target, initial, lambda, bindings),
+            Method::ZipWith(other, lambda) => new_zip_with(target, other, lambda, bindings),
         }
     }
 }
@@ -0,0 +1,124 @@
+use std::{collections::HashMap, ops::Deref};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+struct Mode {
+    target: Box<dyn Evaluator>,
+    item_type: ValueType,
+}
+impl Evaluator for Mode {
+    fn expected_type(&self) -> ValueType {
+        self.item_type.clone()
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        // The index of the first appearance of every item, so ties go to the item that came first.
+        #[allow(clippy::mutable_key_type)]
+        let mut frequencies: HashMap<Value, (usize, usize)> = HashMap::new();
+        for (index, item) in value.items().into_iter().enumerate() {
+            frequencies.entry(item).or_insert((0, index)).0 += 1;
+        }
+        frequencies
+            .into_iter()
+            .max_by(
+                |(_, (left_count, left_index)), (_, (right_count, right_index))| {
+                    left_count
+                        .cmp(right_count)
+                        .then(right_index.cmp(left_index))
+                },
+            )
+            .map(|(item, _)| item)
+            .unwrap_or(Value::Empty)
+    }
+}
+
+pub(super) fn new_mode(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Mode method can only be applied to a List".to_string(),
+        ));
+    };
+    let item_type = item_type.deref().clone();
+    Ok(Box::new(Mode { target, item_type }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_simple_mode() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3, 2, 1, 2].mode()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mode_tie_returns_the_first() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(
+            read_expr("[\"rs\", \"md\", \"md\", \"rs\", \"txt\"].mode()")?.eval(file),
+            Value::String("rs".into())
+        );
+        assert_eq!(
+            read_expr("[3, 1, 2].mostCommon()")?.eval(file),
+            Value::Number(3)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mode_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\", \"b\"].mode()")?;
+
+        assert_eq!(expr.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mode_nop_return_empty() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f $f.extension).mode()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mode_empty_list_return_nothing() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 3, 4].filter($n $n > 10).mode()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_mode() {
+        let err = read_expr("12.mode()").err();
+        assert!(err.is_some())
+    }
+}
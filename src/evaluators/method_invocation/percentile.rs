@@ -0,0 +1,160 @@
+use crate::{
+    errors::FindItError,
+    evaluators::{
+        cast::is_numeric,
+        expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    },
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+use std::ops::Deref;
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => Some(*n as f64),
+        Value::Float(n) => Some(n.get()),
+        _ => None,
+    }
+}
+
+struct Percentile {
+    target: Box<dyn Evaluator>,
+    percent: Box<dyn Evaluator>,
+}
+impl Evaluator for Percentile {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Float
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Some(percent) = as_f64(&self.percent.eval(file)) else {
+            return Value::Empty;
+        };
+        if !(0.0..=100.0).contains(&percent) {
+            return Value::Empty;
+        }
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let mut items = value
+            .items()
+            .into_iter()
+            .filter_map(|item| as_f64(&item))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            return Value::Empty;
+        }
+        items.sort_by(f64::total_cmp);
+        // Linear interpolation between the two closest ranks.
+        let rank = percent / 100.0 * (items.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let fraction = rank - lower as f64;
+        (items[lower] + (items[upper] - items[lower]) * fraction).into()
+    }
+}
+
+pub(super) fn new_percentile(
+    target: Box<dyn Evaluator>,
+    percent: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Percentile method can only be applied to a List of numbers".to_string(),
+        ));
+    };
+    if !is_numeric(item_type.deref()) {
+        return Err(FindItError::BadExpression(
+            "Percentile method can only be applied to List of Number or Float type".to_string(),
+        ));
+    }
+    let percent = percent.build(bindings)?;
+    if !is_numeric(&percent.expected_type()) {
+        return Err(FindItError::BadExpression(
+            "Percentile method argument must be a Number or a Float".to_string(),
+        ));
+    }
+    Ok(Box::new(Percentile { target, percent }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn test_median_percentile() -> Result<(), FindItError> {
+        assert_eq!(eval("[3, 1, 2].percentile(50)")?, Value::from(2.0));
+        assert_eq!(eval("[4, 1, 3, 2].percentile(50)")?, Value::from(2.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_and_max_percentile() -> Result<(), FindItError> {
+        assert_eq!(eval("[7, 3, 10, 5].percentile(0)")?, Value::from(3.0));
+        assert_eq!(eval("[7, 3, 10, 5].percentile(100)")?, Value::from(10.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolated_percentile() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("[10, 20, 30, 40, 50].percentile(95)")?,
+            Value::from(48.0)
+        );
+        assert_eq!(eval("[1.5, 0.5].percentile(25.0)")?, Value::from(0.75));
+        assert_eq!(eval("[8].percentile(95)")?, Value::from(8.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_out_of_range_percentile() -> Result<(), FindItError> {
+        assert_eq!(eval("[1, 2].percentile(101)")?, Value::Empty);
+        assert_eq!(eval("[1, 2].percentile(0 - 1)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile_of_empty_list() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("[1, 3, 4].filter($n $n > 10).percentile(50)")?,
+            Value::Empty
+        );
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        assert_eq!(
+            read_expr("files.map($f $f.size).percentile(50)")?.eval(file),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("[1, 2].percentile(90)")?.expected_type(),
+            ValueType::Float
+        );
+        assert!(read_expr("12.percentile(90)").is_err());
+        assert!(read_expr("[\"a\"].percentile(90)").is_err());
+        assert!(read_expr("[1, 2].percentile(\"90\")").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    errors::FindItError,
+    evaluators::{
+        expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+        method_invocation::grep::read_pattern,
+    },
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct RegexReplaceAll {
+    target: Box<dyn Evaluator>,
+    pattern: Box<dyn Evaluator>,
+    replacement: Box<dyn Evaluator>,
+}
+impl Evaluator for RegexReplaceAll {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(replacement) = self.replacement.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(regex) = read_pattern(self.pattern.as_ref(), file) else {
+            return Value::Empty;
+        };
+        regex
+            .replace_all(&str, replacement.as_str())
+            .into_owned()
+            .into()
+    }
+}
+
+pub(super) fn new_regex_replace_all(
+    target: Box<dyn Evaluator>,
+    pattern: &Expression,
+    replacement: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "RegexReplaceAll method can only be applied to String type".to_string(),
+        ));
+    }
+    let pattern = pattern.build(bindings)?;
+    let replacement = replacement.build(bindings)?;
+    if pattern.expected_type() != ValueType::String
+        || replacement.expected_type() != ValueType::String
+    {
+        return Err(FindItError::BadExpression(
+            "RegexReplaceAll method pattern and replacement must be Strings".to_string(),
+        ));
+    }
+    Ok(Box::new(RegexReplaceAll {
+        target,
+        pattern,
+        replacement,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn replace_with_group_references() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"a1b22c333\".regex_replace_all(\"(\\\\d+)\", \"[$1]\")")?,
+            Value::String("a[1]b[22]c[333]".into())
+        );
+        assert_eq!(
+            eval(
+                "\"2024-01-31\".regexReplaceAll(\"(?<y>\\\\d+)-(\\\\d+)-(\\\\d+)\", \"$3/$2/${y}\")"
+            )?,
+            Value::String("31/01/2024".into())
+        );
+        assert_eq!(
+            eval("\"ab\".regex_replace_all(\"[ab]\", \"<$0>\")")?,
+            Value::String("<a><b>".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn replace_without_matches() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"abc\".regex_replace_all(\"\\\\d\", \"#\")")?,
+            Value::String("abc".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_regex_is_empty() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"abc\".regex_replace_all(\"(\", \"#\")")?,
+            Value::Empty
+        );
+        assert_eq!(
+            eval("content.regex_replace_all(\"a\", \"#\")")?,
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("name.regex_replace_all(\"a\", \"b\")")?.expected_type(),
+            ValueType::String
+        );
+        assert!(read_expr("size.regex_replace_all(\"a\", \"b\")").is_err());
+        assert!(read_expr("name.regex_replace_all(1, \"b\")").is_err());
+        assert!(read_expr("name.regex_replace_all(\"a\", 1)").is_err());
+        assert!(read_expr("name.regex_replace_all(\"a\")").is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -26,7 +26,7 @@ impl Evaluator for ReverseString {
 
 struct ReverseList {
     target: Box<dyn Evaluator>,
-    item_type: Rc<ValueType>,
+    item_type: Arc<ValueType>,
 }
 impl Evaluator for ReverseList {
     fn expected_type(&self) -> ValueType {
@@ -59,7 +59,7 @@ pub(super) fn new_reverse(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluato
 
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         evaluators::expr::read_expr,
@@ -87,7 +87,7 @@ mod tests {
         let expr = read_expr("[1].REVERSE()").unwrap();
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
     }
 
@@ -116,7 +116,7 @@ mod tests {
         assert_eq!(
             value,
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(3), Value::Number(2), Value::Number(1)].into_iter(),
             ))
         );
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::{expression::Expression, methods::BinaryLambdaFunction},
+    value::{List, Value, ValueType},
+};
+
+struct Scan {
+    target: Box<dyn Evaluator>,
+    initial: Box<dyn Evaluator>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    accumulator_type: Arc<ValueType>,
+}
+
+impl Evaluator for Scan {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.accumulator_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let initial = self.initial.eval(file);
+        let lambda = self.lambda.clone();
+        let file = file.clone();
+        let iter = value
+            .items()
+            .into_iter()
+            .scan(initial, move |accumulator, item| {
+                let new_file = file.with_binding(accumulator.clone()).with_binding(item);
+                *accumulator = lambda.eval(&new_file);
+                Some(accumulator.clone())
+            });
+        Value::List(List::new_lazy(self.accumulator_type.clone(), iter))
+    }
+}
+
+pub(super) fn new_scan(
+    target: Box<dyn Evaluator>,
+    initial: &Expression,
+    lambda: &BinaryLambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Scan method can only be applied to List type".to_string(),
+        ));
+    };
+    let initial = initial.build(bindings)?;
+    let accumulator_type = initial.expected_type();
+    let lambda = lambda.build(bindings, &accumulator_type, &item_type)?;
+    if lambda.expected_type() != accumulator_type {
+        return Err(FindItError::BadExpression(
+            "Scan method function must return the same type as the initial value".to_string(),
+        ));
+    }
+
+    Ok(Box::new(Scan {
+        target,
+        initial,
+        lambda: Arc::new(lambda),
+        accumulator_type: Arc::new(accumulator_type),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_scan_running_total() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].scan(0, $acc $x $acc + $x)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[1, 3, 6]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_different_item_type() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\", \"bb\", \"ccc\"].scan(10, $total $s $total + $s.length())")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[11, 13, 16]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_strings() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\", \"b\", \"c\"].scan(\"\", $acc $s $acc + $s)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[\"a\", \"ab\", \"abc\"]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_empty_list() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2].filter($x $x > 5).scan(0, $acc $x $acc + $x).length()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f $f.size).scan(0, $acc $x $acc + $x)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scan_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\"].scan(0, $acc $s $acc + $s.length())")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::Number))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_return_error() {
+        let err = read_expr("12.scan(0, $acc $x $acc + $x)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn different_lambda_type_return_error() {
+        let err = read_expr("[1].scan(0, $acc $x $acc > $x)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn single_parameter_return_error() {
+        let err = read_expr("[1].scan(0, $acc 1 + $acc)").err();
+        assert!(err.is_some())
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{List, Value, ValueType},
+};
+
+/// Split a command line into arguments, like a POSIX shell would (without any expansion).
+/// Returns `None` if a quote is not closed or the line ends with an escape.
+fn shell_split(line: &str) -> Option<Vec<String>> {
+    let mut args = vec![];
+    let mut current: Option<String> = None;
+    let mut chars = line.chars();
+    while let Some(chr) = chars.next() {
+        match chr {
+            ' ' | '\t' | '\n' | '\r' => {
+                if let Some(arg) = current.take() {
+                    args.push(arg);
+                }
+            }
+            '\\' => match chars.next()? {
+                // An escaped new line is a line continuation.
+                '\n' => {}
+                escaped => current.get_or_insert_default().push(escaped),
+            },
+            '\'' => {
+                let arg = current.get_or_insert_default();
+                loop {
+                    match chars.next()? {
+                        '\'' => break,
+                        chr => arg.push(chr),
+                    }
+                }
+            }
+            '"' => {
+                let arg = current.get_or_insert_default();
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => match chars.next()? {
+                            '\n' => {}
+                            escaped @ ('"' | '\\' | '$' | '`') => arg.push(escaped),
+                            other => {
+                                arg.push('\\');
+                                arg.push(other);
+                            }
+                        },
+                        chr => arg.push(chr),
+                    }
+                }
+            }
+            chr => current.get_or_insert_default().push(chr),
+        }
+    }
+    args.extend(current);
+    Some(args)
+}
+
+struct ShellSplit {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for ShellSplit {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(Arc::new(ValueType::String))
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(args) = shell_split(&target_value) else {
+            return Value::Empty;
+        };
+        let items = args.into_iter().map(Value::String);
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
+    }
+}
+
+pub(super) fn new_shell_split(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(ShellSplit { target })),
+        _ => Err(FindItError::BadExpression(
+            "ToArgs method can only be applied to String type".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{List, Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    fn args(args: &[&str]) -> Value {
+        Value::List(List::new_from_vec(
+            Arc::new(ValueType::String),
+            args.iter().map(|a| Value::String(a.to_string())).collect(),
+        ))
+    }
+
+    #[test]
+    fn simple_command() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"ls  -la\\t/tmp \".toArgs()")?,
+            args(&["ls", "-la", "/tmp"])
+        );
+        assert_eq!(eval("\"   \".to_args()")?, args(&[]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn quoted_arguments() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"echo \\\"hello world\\\"\".toArgs()")?,
+            args(&["echo", "hello world"])
+        );
+        assert_eq!(
+            eval("\"echo 'it \\\"is\\\"' a'b'\\\"c\\\" ''\".shellSplit()")?,
+            args(&["echo", "it \"is\"", "abc", ""])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_characters() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"touch my\\\\ file \\\\'x\\\\\\\\\".toArgs()")?,
+            args(&["touch", "my file", "'x\\"])
+        );
+        assert_eq!(
+            eval("\"echo \\\"a\\\\\\\"b\\\\n\\\" 'c\\\\d'\".toArgs()")?,
+            args(&["echo", "a\"b\\n", "c\\d"])
+        );
+        assert_eq!(eval("\"one \\\\\\ntwo\".toArgs()")?, args(&["one", "two"]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unbalanced_quotes() -> Result<(), FindItError> {
+        assert_eq!(eval("\"echo 'hello\".toArgs()")?, Value::Empty);
+        assert_eq!(eval("\"echo \\\"hello\".toArgs()")?, Value::Empty);
+        assert_eq!(eval("\"echo \\\\\".toArgs()")?, Value::Empty);
+        assert_eq!(eval("content.toArgs()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("name.toArgs()")?.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+        assert!(read_expr("12.toArgs()").is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -34,7 +34,7 @@ impl Evaluator for SkipString {
 struct SkipList {
     target: Box<dyn Evaluator>,
     by: Box<dyn Evaluator>,
-    items_type: Rc<ValueType>,
+    items_type: Arc<ValueType>,
 }
 impl Evaluator for SkipList {
     fn expected_type(&self) -> ValueType {
@@ -79,7 +79,7 @@ pub(super) fn new_skip(
 
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -162,7 +162,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(4), Value::Number(5),].into_iter(),
             ))
         );
@@ -179,7 +179,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![].into_iter(),
             ))
         );
@@ -193,7 +193,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{cmp::Ordering, sync::Arc};
 
 use itertools::Itertools;
 
@@ -12,7 +12,7 @@ use crate::{
 
 struct Sort {
     target: Box<dyn Evaluator>,
-    item_type: Rc<ValueType>,
+    item_type: Arc<ValueType>,
 }
 impl Evaluator for Sort {
     fn expected_type(&self) -> ValueType {
@@ -29,8 +29,8 @@ impl Evaluator for Sort {
 
 struct SortBy {
     target: Box<dyn Evaluator>,
-    lambda: Rc<Box<dyn Evaluator>>,
-    items_type: Rc<ValueType>,
+    keys: Vec<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
 }
 impl Evaluator for SortBy {
     fn expected_type(&self) -> ValueType {
@@ -43,9 +43,12 @@ impl Evaluator for SortBy {
         let items = value.items().into_iter().sorted_by(|a, b| {
             let file_a = file.with_binding(a.clone());
             let file_b = file.with_binding(b.clone());
-            let key_a = self.lambda.eval(&file_a);
-            let key_b = self.lambda.eval(&file_b);
-            key_a.cmp(&key_b)
+            // Every key is only evaluated if all the previous keys are equal.
+            self.keys
+                .iter()
+                .map(|key| key.eval(&file_a).cmp(&key.eval(&file_b)))
+                .find(|order| order.is_ne())
+                .unwrap_or(Ordering::Equal)
         });
         Value::List(List::new_eager(self.items_type.clone(), items))
     }
@@ -64,7 +67,7 @@ pub(super) fn new_sort(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>,
 
 pub(super) fn new_sort_by(
     target: Box<dyn Evaluator>,
-    lambda: &LambdaFunction,
+    keys: &[LambdaFunction],
     bindings: &BindingsTypes,
 ) -> Result<Box<dyn Evaluator>, FindItError> {
     let ValueType::List(items_type) = target.expected_type() else {
@@ -73,10 +76,13 @@ pub(super) fn new_sort_by(
         ));
     };
     let items_type = items_type.clone();
-    let lambda = lambda.build(bindings, &items_type)?;
+    let keys = keys
+        .iter()
+        .map(|key| key.build(bindings, &items_type))
+        .collect::<Result<Vec<_>, _>>()?;
     Ok(Box::new(SortBy {
         target,
-        lambda: Rc::new(lambda),
+        keys,
         items_type: items_type.clone(),
     }))
 }
@@ -85,7 +91,7 @@ pub(super) fn new_sort_by(
 mod tests {
     use std::{
         path::{Path, PathBuf},
-        rc::Rc,
+        sync::Arc,
     };
 
     use crate::{
@@ -102,7 +108,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(1), Value::Number(10), Value::Number(14)].into_iter(),
             ))
         );
@@ -118,7 +124,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![
                     Value::String("z".into()),
                     Value::String("gq".into()),
@@ -132,13 +138,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sort_by_multiple_keys() -> Result<(), FindItError> {
+        let expr = read_expr(
+            "[\"ccc.rs\", \"d.txt\", \"aa.rs\", \"b.rs\", \"a.txt\"].sortBy($f $f.extension(), $n $n.length(), $s $s)",
+        )?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::List(List::new_eager(
+                Arc::new(ValueType::String),
+                vec![
+                    Value::String("b.rs".into()),
+                    Value::String("aa.rs".into()),
+                    Value::String("ccc.rs".into()),
+                    Value::String("a.txt".into()),
+                    Value::String("d.txt".into()),
+                ]
+                .into_iter(),
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_by_files_with_multiple_keys() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("big.rs"), "fn main() {}")?;
+        std::fs::write(dir.path().join("small.rs"), "")?;
+        std::fs::write(dir.path().join("notes.md"), "abc")?;
+        let expr = read_expr("files.sortBy($f $f.extension, $f $f.size).map($f $f.name)")?;
+        let file = &FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::List(List::new_eager(
+                Arc::new(ValueType::String),
+                vec![
+                    Value::String("notes.md".into()),
+                    Value::String("small.rs".into()),
+                    Value::String("big.rs".into()),
+                ]
+                .into_iter(),
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_by_with_bad_second_key() {
+        assert!(read_expr("[1, 2].sortBy($a $a, $b $b.length())").is_err());
+        assert!(read_expr("[1, 2].sortBy($a $a,)").is_err());
+    }
+
     #[test]
     fn test_sort_expected_type() -> Result<(), FindItError> {
         let expr = read_expr("[1, 2, 3, 4, 5, 6].sort()")?;
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
@@ -161,7 +223,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -14,7 +14,7 @@ struct Split {
 }
 impl Evaluator for Split {
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::String))
+        ValueType::List(Arc::new(ValueType::String))
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::String(target_value) = self.target.eval(file) else {
@@ -29,7 +29,7 @@ impl Evaluator for Split {
         let items = target_value
             .split(&delimiter)
             .map(|s| Value::String(s.to_string()));
-        Value::List(List::new_eager(Rc::new(ValueType::String), items))
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
     }
 }
 pub(super) fn new_split(
@@ -52,7 +52,7 @@ pub(super) fn new_split(
 }
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -70,7 +70,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![
                     Value::String("a".into()),
                     Value::String("b".into()),
@@ -122,7 +122,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::String))
+            ValueType::List(Arc::new(ValueType::String))
         );
 
         Ok(())
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use crate::{
+    class_type::{Class, ClassType},
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{List, Value, ValueType},
+};
+
+const HEAD_FIELD_NAME: &str = "head";
+const TAIL_FIELD_NAME: &str = "tail";
+
+struct SplitAt {
+    target: Box<dyn Evaluator>,
+    index: Box<dyn Evaluator>,
+    items_type: Arc<ValueType>,
+    class_type: Arc<ClassType>,
+}
+
+impl Evaluator for SplitAt {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Class(self.class_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Number(index) = self.index.eval(file) else {
+            return Value::Empty;
+        };
+        let mut items = value.items().into_iter();
+        let head = items.by_ref().take(index as usize).collect();
+        let tail = items.collect();
+        Value::Class(Class::new(
+            &self.class_type,
+            vec![
+                Value::List(List::new_from_vec(self.items_type.clone(), head)),
+                Value::List(List::new_from_vec(self.items_type.clone(), tail)),
+            ],
+        ))
+    }
+}
+
+pub(super) fn new_split_at(
+    target: Box<dyn Evaluator>,
+    index: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(items_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "SplitAt method can only be applied to List type".to_string(),
+        ));
+    };
+    let index = index.build(bindings)?;
+    if index.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "SplitAt method argument must be a Number".to_string(),
+        ));
+    }
+    let list_type = ValueType::List(items_type.clone());
+    let class_type = Arc::new(ClassType::new(&[
+        (HEAD_FIELD_NAME.to_string(), list_type.clone()),
+        (TAIL_FIELD_NAME.to_string(), list_type),
+    ]));
+
+    Ok(Box::new(SplitAt {
+        target,
+        index,
+        items_type,
+        class_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn split_in_the_middle() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("[1, 2, 3, 4, 5].split_at(2)::head as text")?,
+            Value::String("[1, 2]".into())
+        );
+        assert_eq!(
+            eval("[1, 2, 3, 4, 5].splitAt(2)::tail as text")?,
+            Value::String("[3, 4, 5]".into())
+        );
+        assert_eq!(
+            eval("[1, 2, 3].split_at(1) as text")?,
+            Value::String("{\"head\":[1], \"tail\":[2, 3]}".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_at_the_edges() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("[1, 2].split_at(0) as text")?,
+            Value::String("{\"head\":[], \"tail\":[1, 2]}".into())
+        );
+        assert_eq!(
+            eval("[1, 2].split_at(10) as text")?,
+            Value::String("{\"head\":[1, 2], \"tail\":[]}".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_empty_value() -> Result<(), FindItError> {
+        assert_eq!(eval("files.split_at(1)")?, Value::Empty);
+        assert_eq!(eval("[1, 2].split_at(size)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("[\"a\"].split_at(1)::tail")?.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+        assert!(read_expr("\"abc\".split_at(1)").is_err());
+        assert!(read_expr("[1].split_at(\"1\")").is_err());
+        assert!(read_expr("[1].split_at(1)::middle").is_err());
+
+        Ok(())
+    }
+}
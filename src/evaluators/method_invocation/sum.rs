@@ -31,18 +31,44 @@ impl Evaluator for Sum {
     }
 }
 
+struct SumFloats {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for SumFloats {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Float
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        value
+            .items()
+            .into_iter()
+            .fold(0.0, |acc, item| {
+                if let Value::Float(n) = item {
+                    acc + n.get()
+                } else {
+                    acc
+                }
+            })
+            .into()
+    }
+}
+
 pub(super) fn new_sum(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
     let ValueType::List(item_type) = target.expected_type() else {
         return Err(FindItError::BadExpression(
             "Sum method can only be applied to a List of numbers".to_string(),
         ));
     };
-    if item_type.deref() != &ValueType::Number {
-        return Err(FindItError::BadExpression(
-            "Sum method can only be applied to List of Number type".to_string(),
-        ));
+    match item_type.deref() {
+        ValueType::Number => Ok(Box::new(Sum { target })),
+        ValueType::Float => Ok(Box::new(SumFloats { target })),
+        _ => Err(FindItError::BadExpression(
+            "Sum method can only be applied to List of Number or Float type".to_string(),
+        )),
     }
-    Ok(Box::new(Sum { target }))
 }
 
 #[cfg(test)]
@@ -66,6 +92,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_float_sum() -> Result<(), FindItError> {
+        let expr = read_expr("[0.5, 1.25, 2.0].sum()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.expected_type(), ValueType::Float);
+        assert_eq!(expr.eval(file), Value::from(3.75));
+
+        Ok(())
+    }
+
     #[test]
     fn test_sum_expected_type() -> Result<(), FindItError> {
         let expr = read_expr("[1, 2, 3, 4, 5, 6].sum()")?;
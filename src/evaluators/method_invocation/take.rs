@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
@@ -34,7 +34,7 @@ impl Evaluator for TakeString {
 struct TakeList {
     target: Box<dyn Evaluator>,
     limit: Box<dyn Evaluator>,
-    items_type: Rc<ValueType>,
+    items_type: Arc<ValueType>,
 }
 impl Evaluator for TakeList {
     fn expected_type(&self) -> ValueType {
@@ -78,7 +78,7 @@ pub(super) fn new_take(
 
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -160,7 +160,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(1), Value::Number(2),].into_iter(),
             ))
         );
@@ -177,7 +177,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::Number),
+                Arc::new(ValueType::Number),
                 vec![Value::Number(1), Value::Number(2), Value::Number(3),].into_iter(),
             ))
         );
@@ -191,7 +191,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::Number))
+            ValueType::List(Arc::new(ValueType::Number))
         );
 
         Ok(())
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{List, Value, ValueType},
+};
+
+#[derive(Clone, Copy)]
+pub(super) enum Tail {
+    Take,
+    Skip,
+}
+
+struct TakeLast {
+    target: Box<dyn Evaluator>,
+    count: Box<dyn Evaluator>,
+    items_type: Arc<ValueType>,
+    tail: Tail,
+}
+impl Evaluator for TakeLast {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.items_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Number(count) = self.count.eval(file) else {
+            return Value::Empty;
+        };
+        // The list must be read in full to know where its tail starts.
+        let mut items = target_value.items().into_iter().collect::<Vec<_>>();
+        let split = items
+            .len()
+            .saturating_sub(usize::try_from(count).unwrap_or(usize::MAX));
+        let items = match self.tail {
+            Tail::Take => items.split_off(split),
+            Tail::Skip => {
+                items.truncate(split);
+                items
+            }
+        };
+        Value::List(List::new_from_vec(self.items_type.clone(), items))
+    }
+}
+
+pub(super) fn new_take_last(
+    target: Box<dyn Evaluator>,
+    count: &Expression,
+    bindings: &BindingsTypes,
+    tail: Tail,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let count = count.build(bindings)?;
+    let name = match tail {
+        Tail::Take => "TakeLast",
+        Tail::Skip => "SkipLast",
+    };
+    if count.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(format!(
+            "{name} method argument must be a Number"
+        )));
+    }
+    let ValueType::List(items_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(format!(
+            "{name} method can only be applied to List type"
+        )));
+    };
+    Ok(Box::new(TakeLast {
+        target,
+        count,
+        items_type,
+        tail,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{List, Value, ValueType},
+    };
+
+    fn numbers(items: &[u64]) -> Value {
+        Value::List(List::new_from_vec(
+            Arc::new(ValueType::Number),
+            items.iter().map(|n| Value::Number(*n)).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_take_last() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(
+            read_expr("[1, 2, 3, 4].take_last(2)")?.eval(file),
+            numbers(&[3, 4])
+        );
+        assert_eq!(
+            read_expr("[1, 2, 3].takeLast(100)")?.eval(file),
+            numbers(&[1, 2, 3])
+        );
+        assert_eq!(
+            read_expr("[1, 2, 3].take_last(0)")?.eval(file),
+            numbers(&[])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_last() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(
+            read_expr("[1, 2, 3, 4].skip_last(1)")?.eval(file),
+            numbers(&[1, 2, 3])
+        );
+        assert_eq!(
+            read_expr("[1, 2, 3].skipLast(100)")?.eval(file),
+            numbers(&[])
+        );
+        assert_eq!(
+            read_expr("[1, 2, 3].filter($n $n > 1).skip_last(0)")?.eval(file),
+            numbers(&[2, 3])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_take_last_of_empty() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+
+        assert_eq!(read_expr("files.take_last(2)")?.eval(file), Value::Empty);
+        assert_eq!(
+            read_expr("[1, 3].skip_last(size)")?.eval(file),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn take_last_return_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("[\"a\"].take_last(2)")?.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+        assert_eq!(
+            read_expr("[1].skip_last(2)")?.expected_type(),
+            ValueType::List(Arc::new(ValueType::Number))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn take_last_bad_arguments() {
+        assert!(read_expr("[1, 2].take_last(\"a\")").is_err());
+        assert!(read_expr("\"abc\".take_last(1)").is_err());
+        assert!(read_expr("[1, 2].skip_last()").is_err());
+    }
+}
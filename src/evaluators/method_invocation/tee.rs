@@ -0,0 +1,120 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator},
+    file_wrapper::FileWrapper,
+    parser::ast::methods::LambdaFunction,
+    value::{Value, ValueType},
+};
+
+struct Tee {
+    target: Box<dyn Evaluator>,
+    lambda: Box<dyn Evaluator>,
+}
+
+impl Evaluator for Tee {
+    fn expected_type(&self) -> ValueType {
+        self.target.expected_type()
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let value = self.target.eval(file);
+        let new_file = file.with_binding(value.clone());
+        self.lambda.eval(&new_file);
+        value
+    }
+}
+
+pub(super) fn new_tee(
+    target: Box<dyn Evaluator>,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let lambda = lambda.build(bindings, &target.expected_type())?;
+
+    Ok(Box::new(Tee { target, lambda }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fmt::Debug,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+    };
+
+    use crate::{
+        content::ContentOptions,
+        debugger::Debugger,
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    struct MyDebugger {
+        logs: Arc<Mutex<Vec<String>>>,
+    }
+    impl Debugger for MyDebugger {
+        fn log(&self, f: &dyn Fn() -> String) {
+            self.logs.lock().unwrap().push(f());
+        }
+    }
+    impl Debug for MyDebugger {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "MyDebugger")
+        }
+    }
+
+    #[test]
+    fn tee_evaluates_the_lambda() -> Result<(), FindItError> {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let debugger: Arc<Box<dyn Debugger>> =
+            Arc::new(Box::new(MyDebugger { logs: logs.clone() }));
+        let expr = read_expr("[1, 2, 3].tee($l $l.length().debug($n \"items: \" + $n)).sum()")?;
+        let file =
+            FileWrapper::new_with_debugger(PathBuf::new(), 1, &debugger, ContentOptions::default());
+
+        assert_eq!(expr.eval(&file), Value::Number(6));
+        assert_eq!(*logs.lock().unwrap(), vec!["items: 3".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tee_ignores_the_lambda_value() -> Result<(), FindItError> {
+        let expr = read_expr("\"abc\".tee($s 12)")?;
+        let file = FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(&file), Value::String("abc".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tee_keeps_the_empty_value() -> Result<(), FindItError> {
+        let expr = read_expr("content.tee($s $s.length())")?;
+        let file = FileWrapper::new(PathBuf::from("no/such/file"), 1);
+
+        assert_eq!(expr.eval(&file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tee_return_type() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2].tee($l \"a\")")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::Number))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tee_without_lambda_return_error() {
+        let err = read_expr("12.tee()").err();
+
+        assert!(err.is_some());
+    }
+}
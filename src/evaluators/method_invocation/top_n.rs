@@ -0,0 +1,297 @@
+use std::{cmp::Reverse, collections::BinaryHeap, sync::Arc};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::{expression::Expression, methods::LambdaFunction},
+    value::{List, Value, ValueType},
+};
+
+struct TopN {
+    target: Box<dyn Evaluator>,
+    count: Box<dyn Evaluator>,
+    lambda: Box<dyn Evaluator>,
+    items_type: Arc<ValueType>,
+}
+impl Evaluator for TopN {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.items_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::Number(count) = self.count.eval(file) else {
+            return Value::Empty;
+        };
+        // A count that does not fit in memory can only mean all the items.
+        let count = usize::try_from(count).unwrap_or(usize::MAX);
+        if count == 0 {
+            return Value::List(List::new_from_vec(self.items_type.clone(), vec![]));
+        }
+        // A min heap of the best items so far, on equal keys the earlier item is the better one.
+        // The heap grows with the list, so a huge count will not allocate more than the list.
+        let mut heap = BinaryHeap::new();
+        for (index, item) in value.items().into_iter().enumerate() {
+            let new_file = file.with_binding(item.clone());
+            let key = self.lambda.eval(&new_file);
+            heap.push(Reverse((key, Reverse(index), item)));
+            if heap.len() > count {
+                heap.pop();
+            }
+        }
+        let items = heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, _, item))| item);
+        Value::List(List::new_eager(self.items_type.clone(), items))
+    }
+}
+
+pub(super) fn new_top_n(
+    target: Box<dyn Evaluator>,
+    count: &Expression,
+    lambda: &LambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(items_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "TopN method can only be applied to a List type".to_string(),
+        ));
+    };
+    let count = count.build(bindings)?;
+    if count.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "TopN method first argument must be a Number".to_string(),
+        ));
+    }
+    let lambda = lambda.build(bindings, &items_type)?;
+    Ok(Box::new(TopN {
+        target,
+        count,
+        lambda,
+        items_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::Arc,
+        time::Instant,
+    };
+
+    use crate::{
+        errors::FindItError,
+        evaluators::{
+            expr::{BindingsTypes, Evaluator, read_expr},
+            method_invocation::top_n::new_top_n,
+        },
+        file_wrapper::FileWrapper,
+        parser::{ast::methods::LambdaFunction, parse_expression},
+        value::{List, Value, ValueType},
+    };
+
+    #[test]
+    fn test_top_n_numbers() -> Result<(), FindItError> {
+        let expr = read_expr("[5, 1, 9, 3, 7, 2].topN(3, $n $n)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[9, 7, 5]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_huge_count() -> Result<(), FindItError> {
+        let expr = read_expr("[5, 1, 9].topN(18446744073709551615, $n $n)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[9, 5, 1]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_same_as_full_sort() -> Result<(), FindItError> {
+        let expr =
+            read_expr("[\"ccc\", \"a\", \"dddd\", \"bb\", \"eeeee\"].top_n(2, $s $s.length())")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr(
+            "[\"ccc\", \"a\", \"dddd\", \"bb\", \"eeeee\"].sortBy($s $s.length()).reverse().take(2)",
+        )?
+        .eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_keep_first_on_tie() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a1\", \"b2\", \"c2\", \"d1\", \"e2\"].topN(2, $s $s.skip(1))")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[\"b2\", \"c2\"]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_more_than_length() -> Result<(), FindItError> {
+        let expr = read_expr("[2, 3, 1].topN(10, $n $n)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[3, 2, 1]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_zero() -> Result<(), FindItError> {
+        let expr = read_expr("[2, 3, 1].topN(0, $n $n).length()")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(0));
+
+        Ok(())
+    }
+
+    struct LargeList;
+    impl Evaluator for LargeList {
+        fn expected_type(&self) -> ValueType {
+            ValueType::List(Arc::new(ValueType::Number))
+        }
+        fn eval(&self, _: &FileWrapper) -> Value {
+            Value::List(List::new_lazy(
+                Arc::new(ValueType::Number),
+                (0..200_000_u64).map(|n| Value::Number(n * 7_919 % 100_003)),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_top_n_large_input() -> Result<(), FindItError> {
+        let lambda = LambdaFunction {
+            parameter: "n".into(),
+            body: Box::new(parse_expression("$n")?),
+        };
+        let count = parse_expression("10")?;
+        let expr = new_top_n(
+            Box::new(LargeList),
+            &count,
+            &lambda,
+            &BindingsTypes::default(),
+        )?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let mut all = (0..200_000_u64)
+            .map(|n| n * 7_919 % 100_003)
+            .collect::<Vec<_>>();
+        all.sort_by(|a, b| b.cmp(a));
+        let expected = Value::List(List::new_eager(
+            Arc::new(ValueType::Number),
+            all.into_iter().take(10).map(Value::Number),
+        ));
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    /// Run with `cargo test --release benchmark_top_n -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn benchmark_top_n_against_full_sort() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("large.txt");
+        let lines = (0..500_000_u64)
+            .map(|n| "x".repeat(usize::try_from(n * 7_919 % 1_009).unwrap_or_default()))
+            .collect::<Vec<_>>();
+        fs::write(&path, lines.join("\n"))?;
+        let file = &FileWrapper::new(path, 1);
+        let top_n = read_expr("lines().topN(10, $l $l.length())")?;
+        let sort = read_expr("lines().sortBy($l $l.length()).reverse().take(10)")?;
+
+        let start = Instant::now();
+        let top_n = top_n.eval(file);
+        let top_n_time = start.elapsed();
+        let start = Instant::now();
+        let sort = sort.eval(file);
+        let sort_time = start.elapsed();
+
+        let lengths = |value: Value| match value {
+            Value::List(list) => list
+                .items()
+                .into_iter()
+                .map(|line| line.to_string().len())
+                .collect::<Vec<_>>(),
+            _ => vec![],
+        };
+        assert_eq!(lengths(top_n), lengths(sort));
+        assert!(
+            top_n_time < sort_time,
+            "topN took {top_n_time:?}, sortBy().reverse().take() took {sort_time:?}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_empty_list() -> Result<(), FindItError> {
+        let expr = read_expr("files.topN(2, $f $f.size)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_empty_count() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2].topN(size, $n $n)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\"].topN(1, $s $s.length())")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_return_error() {
+        let err = read_expr("12.topN(1, $n $n)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn no_number_count_return_error() {
+        let err = read_expr("[1].topN(\"a\", $n $n)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn missing_lambda_return_error() {
+        let err = read_expr("[1].topN(1)").err();
+        assert!(err.is_some())
+    }
+}
@@ -0,0 +1,174 @@
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+
+/// Everything but the RFC 3986 unreserved characters.
+const RESERVED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Make sure that every `%` is followed by two hex digits.
+fn is_valid_percent_encoding(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.iter().enumerate().all(|(i, b)| {
+        *b != b'%'
+            || bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+    })
+}
+
+struct UrlEncode {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for UrlEncode {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        utf8_percent_encode(&str, RESERVED).to_string().into()
+    }
+}
+
+struct UrlDecode {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for UrlDecode {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::String(str) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        if !is_valid_percent_encoding(&str) {
+            return Value::Empty;
+        }
+        match percent_decode_str(&str).decode_utf8() {
+            Ok(decoded) => decoded.to_string().into(),
+            Err(_) => Value::Empty,
+        }
+    }
+}
+
+pub(super) fn new_url_encode(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(UrlEncode { target })),
+        _ => Err(FindItError::BadExpression(
+            "UrlEncode method can only be applied to String type".to_string(),
+        )),
+    }
+}
+
+pub(super) fn new_url_decode(
+    target: Box<dyn Evaluator>,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String => Ok(Box::new(UrlDecode { target })),
+        _ => Err(FindItError::BadExpression(
+            "UrlDecode method can only be applied to String type".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(sql: &str) -> Result<Value, FindItError> {
+        let expr = read_expr(sql)?;
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(expr.eval(file))
+    }
+
+    #[test]
+    fn test_url_encode() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"my file (1).txt\".url_encode()")?,
+            Value::String("my%20file%20%281%29.txt".into())
+        );
+        assert_eq!(
+            eval("\"a-b_c.d~e/f?g=ü\".percentEncode()")?,
+            Value::String("a-b_c.d~e%2Ff%3Fg%3D%C3%BC".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_decode() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"my%20file%20%281%29.txt\".url_decode()")?,
+            Value::String("my file (1).txt".into())
+        );
+        assert_eq!(
+            eval("\"%C3%bc+x\".percent_decode()")?,
+            Value::String("ü+x".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"100% find ü it?\".urlEncode().urlDecode()")?,
+            Value::String("100% find ü it?".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid_input() -> Result<(), FindItError> {
+        assert_eq!(eval("\"%GG\".url_decode()")?, Value::Empty);
+        assert_eq!(eval("\"100%\".url_decode()")?, Value::Empty);
+        assert_eq!(eval("\"%4\".url_decode()")?, Value::Empty);
+        assert_eq!(eval("\"%FF\".url_decode()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() -> Result<(), FindItError> {
+        assert_eq!(eval("content.url_encode()")?, Value::Empty);
+        assert_eq!(eval("content.url_decode()")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("name.url_encode()")?.expected_type(),
+            ValueType::String
+        );
+        assert_eq!(
+            read_expr("name.url_decode()")?.expected_type(),
+            ValueType::String
+        );
+        assert!(read_expr("12.url_encode()").is_err());
+        assert!(read_expr("content_bytes.url_decode()").is_err());
+
+        Ok(())
+    }
+}
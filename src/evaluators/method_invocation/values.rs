@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use crate::{
+    class_type::ClassType,
+    errors::FindItError,
+    evaluators::expr::Evaluator,
+    file_wrapper::FileWrapper,
+    value::{List, Value, ValueType},
+};
+
+struct Values {
+    target: Box<dyn Evaluator>,
+    item_type: Arc<ValueType>,
+}
+
+impl Evaluator for Values {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.item_type.clone())
+    }
+
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Class(class) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        Value::List(List::new_eager(
+            self.item_type.clone(),
+            class.values().cloned(),
+        ))
+    }
+}
+
+pub(super) fn fields_type(class_type: &ClassType, method: &str) -> Result<ValueType, FindItError> {
+    let mut types = class_type.fields().map(|(_, tp)| tp);
+    let Some(first) = types.next() else {
+        return Ok(ValueType::Empty);
+    };
+    if types.any(|tp| tp != first) {
+        return Err(FindItError::BadExpression(format!(
+            "{} method can only be applied to a Class with all fields of the same type",
+            method
+        )));
+    }
+    Ok(first.clone())
+}
+
+pub(super) fn new_values(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::Class(class_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "Values method can only be applied to Class type".to_string(),
+        ));
+    };
+    let item_type = Arc::new(fields_type(&class_type, "Values")?);
+    Ok(Box::new(Values { target, item_type }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_values() -> Result<(), FindItError> {
+        let expr = read_expr("{:width 10, :height 20}.values()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        let expected = read_expr("[10, 20]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("{:first \"a\", :last \"b\"}.values")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::String))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.map($f {:name $f.name}).first().values()")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_types_return_error() {
+        let err = read_expr("{:name \"a\", :size 12}.values()").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn no_class_return_error() {
+        let err = read_expr("\"a\".values()").err();
+
+        assert!(err.is_some());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    errors::FindItError,
+    evaluators::{cast::is_numeric, expr::Evaluator},
+    file_wrapper::FileWrapper,
+    value::{Value, ValueType},
+};
+use std::ops::Deref;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Spread {
+    Variance,
+    StdDev,
+}
+
+struct Variance {
+    target: Box<dyn Evaluator>,
+    spread: Spread,
+}
+impl Evaluator for Variance {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Float
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let items = value
+            .items()
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Number(n) => Some(n as f64),
+                Value::Float(n) => Some(n.get()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            return Value::Empty;
+        }
+        let count = items.len() as f64;
+        let mean = items.iter().sum::<f64>() / count;
+        // The population variance, as the list is usually all the values and not a sample.
+        let variance = items.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count;
+        match self.spread {
+            Spread::Variance => variance.into(),
+            Spread::StdDev => variance.sqrt().into(),
+        }
+    }
+}
+
+pub(super) fn new_variance(
+    target: Box<dyn Evaluator>,
+    spread: Spread,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(format!(
+            "{spread:?} method can only be applied to a List of numbers"
+        )));
+    };
+    if !is_numeric(item_type.deref()) {
+        return Err(FindItError::BadExpression(format!(
+            "{spread:?} method can only be applied to List of Number or Float type"
+        )));
+    }
+    Ok(Box::new(Variance { target, spread }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn test_variance_and_stddev() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("[2, 4, 4, 4, 5, 5, 7, 9].variance()")?,
+            Value::from(4.0)
+        );
+        assert_eq!(eval("[2, 4, 4, 4, 5, 5, 7, 9].stddev()")?, Value::from(2.0));
+        assert_eq!(eval("[1.5, 2.5].variance()")?, Value::from(0.25));
+        assert_eq!(eval("[1.5, 2.5].std_dev()")?, Value::from(0.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_single_item_is_zero() -> Result<(), FindItError> {
+        assert_eq!(eval("[7].variance()")?, Value::from(0.0));
+        assert_eq!(eval("[7].stddev()")?, Value::from(0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_list_is_empty() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("[1, 3, 4].filter($n $n > 10).variance()")?,
+            Value::Empty
+        );
+        let file = &FileWrapper::new(Path::new("no/such/file").to_path_buf(), 1);
+        assert_eq!(
+            read_expr("files.map($f $f.size).stddev()")?.eval(file),
+            Value::Empty
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_variance_types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("[1, 2].variance()")?.expected_type(),
+            ValueType::Float
+        );
+        assert_eq!(
+            read_expr("[1, 2].stddev()")?.expected_type(),
+            ValueType::Float
+        );
+        assert!(read_expr("12.variance()").is_err());
+        assert!(read_expr("[\"a\"].stddev()").is_err());
+
+        Ok(())
+    }
+}
@@ -1,25 +1,39 @@
 use std::fs::{self, ReadDir};
 use std::path::PathBuf;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::{
     errors::FindItError,
-    evaluators::expr::Evaluator,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
     file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
     value::{List, Value, ValueType},
 };
 
+/// The kind of descendants to walk over.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum WalkKind {
+    Files,
+    Dirs,
+}
+
 struct Walker {
     stack: Vec<ReadDir>,
+    max_depth: Option<usize>,
+    kind: WalkKind,
 }
 
 impl Walker {
-    fn new(path: PathBuf) -> Self {
+    fn new(path: PathBuf, max_depth: Option<usize>, kind: WalkKind) -> Self {
         let stack = match fs::read_dir(path) {
-            Ok(rd) => vec![rd],
-            Err(_) => vec![],
+            Ok(rd) if max_depth != Some(0) => vec![rd],
+            _ => vec![],
         };
-        Self { stack }
+        Self {
+            stack,
+            max_depth,
+            kind,
+        }
     }
 }
 impl Iterator for Walker {
@@ -31,10 +45,16 @@ impl Iterator for Walker {
                 Some(entry) => {
                     let path = entry.path();
                     if path.is_dir() {
-                        if let Ok(rd) = fs::read_dir(&path) {
+                        // The files of the top of the stack are `stack.len()` levels deep.
+                        if self.max_depth.is_none_or(|max| self.stack.len() < max)
+                            && let Ok(rd) = fs::read_dir(&path)
+                        {
                             self.stack.push(rd);
                         }
-                    } else if path.is_file() {
+                        if self.kind == WalkKind::Dirs {
+                            return Some(Value::Path(path));
+                        }
+                    } else if path.is_file() && self.kind == WalkKind::Files {
                         return Some(Value::Path(path));
                     }
                 }
@@ -49,32 +69,64 @@ impl Iterator for Walker {
 
 struct Walk {
     target: Box<dyn Evaluator>,
+    max_depth: Option<Box<dyn Evaluator>>,
+    kind: WalkKind,
 }
 impl Evaluator for Walk {
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::Path))
+        ValueType::List(Arc::new(ValueType::Path))
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::Path(path) = self.target.eval(file) else {
             return Value::Empty;
         };
-        let walker = Walker::new(path);
-        Value::List(List::new_lazy(Rc::new(ValueType::Path), walker))
+        let max_depth = match &self.max_depth {
+            None => None,
+            Some(max_depth) => {
+                let Value::Number(max_depth) = max_depth.eval(file) else {
+                    return Value::Empty;
+                };
+                Some(usize::try_from(max_depth).unwrap_or(usize::MAX))
+            }
+        };
+        let walker = Walker::new(path, max_depth, self.kind);
+        Value::List(List::new_lazy(Arc::new(ValueType::Path), walker))
     }
 }
 
-pub(super) fn new_walker(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
-    match target.expected_type() {
-        ValueType::Path => Ok(Box::new(Walk { target })),
-        _ => Err(FindItError::BadExpression(
+pub(super) fn new_walker(
+    target: Box<dyn Evaluator>,
+    max_depth: &Option<Box<Expression>>,
+    kind: WalkKind,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(
             "Walk method can only be applied to Path types".to_string(),
-        )),
+        ));
     }
+    let max_depth = match max_depth {
+        Some(max_depth) => {
+            let max_depth = max_depth.build(bindings)?;
+            if max_depth.expected_type() != ValueType::Number {
+                return Err(FindItError::BadExpression(
+                    "Walk method max depth must be a Number".to_string(),
+                ));
+            }
+            Some(max_depth)
+        }
+        None => None,
+    };
+    Ok(Box::new(Walk {
+        target,
+        max_depth,
+        kind,
+    }))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::{fs, path::Path};
 
     use crate::{
         errors::FindItError, evaluators::expr::read_expr, file_wrapper::FileWrapper, value::Value,
@@ -128,4 +180,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn walk_with_max_depth() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("one/two"))?;
+        fs::write(dir.path().join("top.txt"), "")?;
+        fs::write(dir.path().join("one/first.txt"), "")?;
+        fs::write(dir.path().join("one/two/second.txt"), "")?;
+        let file = &FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        for (max_depth, expected) in [
+            ("0", "[]"),
+            ("1", "[top.txt]"),
+            ("2", "[first.txt, top.txt]"),
+            ("3", "[first.txt, second.txt, top.txt]"),
+            ("", "[first.txt, second.txt, top.txt]"),
+        ] {
+            let expr = read_expr(&format!(
+                "me.walk({max_depth}).map($f $f.name).sort() as text"
+            ))?;
+
+            assert_eq!(
+                expr.eval(file),
+                Value::String(expected.into()),
+                "{max_depth}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_max_depth_must_be_a_number() {
+        assert!(read_expr("me.walk(\"2\")").is_err());
+    }
+
+    #[test]
+    fn walk_files_and_dirs() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir_all(dir.path().join("one/two"))?;
+        fs::write(dir.path().join("top.txt"), "")?;
+        fs::write(dir.path().join("one/first.rs"), "")?;
+        fs::write(dir.path().join("one/two/second.rs"), "")?;
+        let file = &FileWrapper::new(dir.path().to_path_buf(), 1);
+
+        for (expr, expected) in [
+            ("walk_files()", "[first.rs, second.rs, top.txt]"),
+            ("descendants_files()", "[first.rs, second.rs, top.txt]"),
+            ("walkDirs()", "[one, two]"),
+            ("walk_dirs(1)", "[one]"),
+            ("walk_files(1)", "[top.txt]"),
+        ] {
+            let expr_text = format!("me.{expr}.map($f $f.name).sort() as text");
+            assert_eq!(
+                read_expr(&expr_text)?.eval(file),
+                Value::String(expected.into()),
+                "{expr}"
+            );
+        }
+        assert_eq!(
+            read_expr("walk_files().filter($f $f.extension = \"rs\").length()")?.eval(file),
+            Value::Number(2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_dirs_of_file_is_empty_list() -> Result<(), FindItError> {
+        let expr = read_expr("me.walk_dirs().length()")?;
+        let path = Path::new("tests/test_cases/order_by/test_files/next/emma/amelia/big-13.json");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Number(0));
+        assert!(read_expr("12.walk_dirs()").is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,270 @@
+use std::path::Path;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+struct WithExtension {
+    target: Box<dyn Evaluator>,
+    extension: Box<dyn Evaluator>,
+}
+impl Evaluator for WithExtension {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Path
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(extension) = self.extension.eval(file) else {
+            return Value::Empty;
+        };
+        path.with_extension(extension).into()
+    }
+}
+
+struct AddExtension {
+    target: Box<dyn Evaluator>,
+    extension: Box<dyn Evaluator>,
+}
+impl Evaluator for AddExtension {
+    fn expected_type(&self) -> ValueType {
+        ValueType::Path
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(mut path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(extension) = self.extension.eval(file) else {
+            return Value::Empty;
+        };
+        path.add_extension(extension);
+        path.into()
+    }
+}
+
+struct Extension {
+    target: Box<dyn Evaluator>,
+}
+impl Evaluator for Extension {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let extension = match self.target.eval(file) {
+            Value::Path(path) => path.extension().map(|e| e.to_string_lossy().into_owned()),
+            Value::String(str) => Path::new(&str)
+                .extension()
+                .map(|e| e.to_string_lossy().into_owned()),
+            _ => None,
+        };
+        extension.filter(|e| !e.is_empty()).into()
+    }
+}
+
+fn build_extension(
+    method: &str,
+    target: &dyn Evaluator,
+    extension: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(format!(
+            "{} method can only be applied to Path type",
+            method
+        )));
+    }
+    let extension = extension.build(bindings)?;
+    if extension.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(format!(
+            "{} method argument must be a String",
+            method
+        )));
+    }
+    Ok(extension)
+}
+
+pub(super) fn new_with_extension(
+    target: Box<dyn Evaluator>,
+    extension: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let extension = build_extension("WithExtension", target.as_ref(), extension, bindings)?;
+    Ok(Box::new(WithExtension { target, extension }))
+}
+
+pub(super) fn new_add_extension(
+    target: Box<dyn Evaluator>,
+    extension: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let extension = build_extension("AddExtension", target.as_ref(), extension, bindings)?;
+    Ok(Box::new(AddExtension { target, extension }))
+}
+
+pub(super) fn new_extension(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>, FindItError> {
+    match target.expected_type() {
+        ValueType::String | ValueType::Path => Ok(Box::new(Extension { target })),
+        _ => Err(FindItError::BadExpression(
+            "Extension method can only be applied to String or Path types".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_with_extension() -> Result<(), FindItError> {
+        let expr = read_expr("me.with_extension(\"bak\")")?;
+        let file = &FileWrapper::new(PathBuf::from("dir/file.txt"), 1);
+
+        assert_eq!(expr.eval(file), Value::Path(PathBuf::from("dir/file.bak")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_extension_no_target() -> Result<(), FindItError> {
+        let expr = read_expr("withExtension(\"bak\")")?;
+        let file = &FileWrapper::new(PathBuf::from("dir/file.tar.gz"), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::Path(PathBuf::from("dir/file.tar.bak"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_extension_no_extension() -> Result<(), FindItError> {
+        let expr = read_expr("me.with_extension(\"\")")?;
+        let file = &FileWrapper::new(PathBuf::from("dir/file.txt"), 1);
+
+        assert_eq!(expr.eval(file), Value::Path(PathBuf::from("dir/file")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_extension() -> Result<(), FindItError> {
+        let expr = read_expr("me.add_extension(\"tmp\")")?;
+        let file = &FileWrapper::new(PathBuf::from("dir/file.txt"), 1);
+
+        assert_eq!(
+            expr.eval(file),
+            Value::Path(PathBuf::from("dir/file.txt.tmp"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_extension_to_file_without_extension() -> Result<(), FindItError> {
+        let expr = read_expr("addExtension(\"tmp\")")?;
+        let file = &FileWrapper::new(PathBuf::from("dir/file"), 1);
+
+        assert_eq!(expr.eval(file), Value::Path(PathBuf::from("dir/file.tmp")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_extension_empty_argument() -> Result<(), FindItError> {
+        let expr = read_expr("me.with_extension(content)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_extension_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("parent.parent.parent.add_extension(\"a\")")?;
+        let path = Path::new("file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extension_of_string() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(PathBuf::from("dir/file.txt"), 1);
+        for (path, expected) in [
+            ("src/main.rs", Value::String("rs".into())),
+            ("archive.tar.gz", Value::String("gz".into())),
+            ("/a.b/Makefile", Value::Empty),
+            (".bashrc", Value::Empty),
+            ("dir/.config.toml", Value::String("toml".into())),
+            ("file.", Value::Empty),
+            ("", Value::Empty),
+        ] {
+            let expr = read_expr(&format!("\"{path}\".extension()"))?;
+            assert_eq!(expr.eval(file), expected, "{path}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extension_of_path() -> Result<(), FindItError> {
+        let file = &FileWrapper::new(PathBuf::from("dir/file.tar.gz"), 1);
+
+        assert_eq!(
+            read_expr("me.extension()")?.eval(file),
+            Value::String("gz".into())
+        );
+        assert_eq!(
+            read_expr("extension()")?.eval(file),
+            Value::String("gz".into())
+        );
+        assert_eq!(read_expr("parent.extension()")?.eval(file), Value::Empty);
+        assert_eq!(read_expr("content.extension()")?.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_type() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("me.with_extension(\"a\")")?.expected_type(),
+            ValueType::Path
+        );
+        assert_eq!(
+            read_expr("me.add_extension(\"a\")")?.expected_type(),
+            ValueType::Path
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_path_return_error() {
+        assert!(read_expr("\"a\".with_extension(\"a\")").err().is_some());
+        assert!(read_expr("\"a\".add_extension(\"a\")").err().is_some());
+        assert!(read_expr("12.extension()").err().is_some());
+    }
+
+    #[test]
+    fn no_string_argument_return_error() {
+        assert!(read_expr("me.with_extension(1)").err().is_some());
+        assert!(read_expr("me.add_extension(1)").err().is_some());
+    }
+}
@@ -1,7 +1,7 @@
 use std::{
     fs::File,
     io::{BufRead, BufReader},
-    rc::Rc,
+    sync::Arc,
 };
 
 use crate::{
@@ -16,7 +16,7 @@ struct StringWords {
 }
 impl Evaluator for StringWords {
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::String))
+        ValueType::List(Arc::new(ValueType::String))
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::String(target_value) = self.target.eval(file) else {
@@ -25,7 +25,7 @@ impl Evaluator for StringWords {
         let items = target_value
             .split_whitespace()
             .map(|s| Value::String(s.to_string()));
-        Value::List(List::new_eager(Rc::new(ValueType::String), items))
+        Value::List(List::new_eager(Arc::new(ValueType::String), items))
     }
 }
 struct FileWords {
@@ -33,7 +33,7 @@ struct FileWords {
 }
 impl Evaluator for FileWords {
     fn expected_type(&self) -> ValueType {
-        ValueType::List(Rc::new(ValueType::String))
+        ValueType::List(Arc::new(ValueType::String))
     }
     fn eval(&self, file: &FileWrapper) -> Value {
         let Value::Path(path) = self.target.eval(file) else {
@@ -49,7 +49,7 @@ impl Evaluator for FileWords {
                 .collect::<Vec<_>>()
         });
 
-        Value::List(List::new_lazy(Rc::new(ValueType::String), items))
+        Value::List(List::new_lazy(Arc::new(ValueType::String), items))
     }
 }
 
@@ -64,7 +64,7 @@ pub(super) fn new_words(target: Box<dyn Evaluator>) -> Result<Box<dyn Evaluator>
 }
 #[cfg(test)]
 mod tests {
-    use std::{path::Path, rc::Rc};
+    use std::{path::Path, sync::Arc};
 
     use crate::{
         errors::FindItError,
@@ -82,7 +82,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![
                     Value::String("one".into()),
                     Value::String("two".into()),
@@ -112,7 +112,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::String))
+            ValueType::List(Arc::new(ValueType::String))
         );
 
         Ok(())
@@ -134,7 +134,7 @@ mod tests {
         assert_eq!(
             expr.eval(file),
             Value::List(List::new_eager(
-                Rc::new(ValueType::String),
+                Arc::new(ValueType::String),
                 vec![
                     Value::String("quo".into()),
                     Value::String("eligendi".into()),
@@ -217,7 +217,7 @@ mod tests {
 
         assert_eq!(
             expr.expected_type(),
-            ValueType::List(Rc::new(ValueType::String))
+            ValueType::List(Arc::new(ValueType::String))
         );
 
         Ok(())
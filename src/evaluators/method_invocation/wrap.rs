@@ -0,0 +1,141 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{Value, ValueType},
+};
+
+/// Wrap a single line (without its line ending) into lines of at most `width` characters.
+/// Words that are longer than the width are kept intact on their own line.
+fn wrap_line(line: &str, width: usize) -> String {
+    let mut wrapped = String::new();
+    let mut current_width = 0;
+    for word in line.split_whitespace() {
+        let word_width = word.chars().count();
+        if current_width == 0 {
+            // First word of the line.
+        } else if current_width + 1 + word_width <= width {
+            wrapped.push(' ');
+            current_width += 1;
+        } else {
+            wrapped.push('\n');
+            current_width = 0;
+        }
+        wrapped.push_str(word);
+        current_width += word_width;
+    }
+    wrapped
+}
+
+struct Wrap {
+    target: Box<dyn Evaluator>,
+    width: Box<dyn Evaluator>,
+}
+impl Evaluator for Wrap {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let (Value::String(str), Value::Number(width)) =
+            (self.target.eval(file), self.width.eval(file))
+        else {
+            return Value::Empty;
+        };
+        let Ok(width) = usize::try_from(width) else {
+            return Value::Empty;
+        };
+        str.split('\n')
+            .map(|line| wrap_line(line, width))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into()
+    }
+}
+
+pub(super) fn new_wrap(
+    target: Box<dyn Evaluator>,
+    width: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Wrap method can only be applied to String type".to_string(),
+        ));
+    }
+    let width = width.build(bindings)?;
+    if width.expected_type() != ValueType::Number {
+        return Err(FindItError::BadExpression(
+            "Wrap method argument must be a Number".to_string(),
+        ));
+    }
+    Ok(Box::new(Wrap { target, width }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(Path::new("/no/such/file").to_path_buf(), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn wrap_paragraph() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"the quick brown fox jumps over the lazy dog\".wrap(10)")?,
+            Value::String("the quick\nbrown fox\njumps over\nthe lazy\ndog".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_keeps_long_words() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"a extraordinary b\".wrap(5)")?,
+            Value::String("a\nextraordinary\nb".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_keeps_existing_newlines() -> Result<(), FindItError> {
+        assert_eq!(
+            eval("\"one two three\\n\\nfour five\".wrap(7)")?,
+            Value::String("one two\nthree\n\nfour\nfive".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_empty_and_negative() -> Result<(), FindItError> {
+        assert_eq!(eval("content.wrap(10)")?, Value::Empty);
+        assert_eq!(eval("\"a b\".wrap(0 - 1)")?, Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn types() -> Result<(), FindItError> {
+        assert_eq!(
+            read_expr("name.wrap(80)")?.expected_type(),
+            ValueType::String
+        );
+        assert!(read_expr("size.wrap(80)").is_err());
+        assert!(read_expr("name.wrap(\"80\")").is_err());
+        assert!(read_expr("name.wrap()").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    platform::xattr,
+    value::{Value, ValueType},
+};
+
+struct Xattr {
+    target: Box<dyn Evaluator>,
+    name: Box<dyn Evaluator>,
+}
+impl Evaluator for Xattr {
+    fn expected_type(&self) -> ValueType {
+        ValueType::String
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::Path(path) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::String(name) = self.name.eval(file) else {
+            return Value::Empty;
+        };
+        let Some(value) = xattr(&path, &name) else {
+            return Value::Empty;
+        };
+        String::from_utf8_lossy(&value).into_owned().into()
+    }
+}
+
+pub(super) fn new_xattr(
+    target: Box<dyn Evaluator>,
+    name: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    if target.expected_type() != ValueType::Path {
+        return Err(FindItError::BadExpression(
+            "Xattr method can only be applied to Path type".to_string(),
+        ));
+    }
+    let name = name.build(bindings)?;
+    if name.expected_type() != ValueType::String {
+        return Err(FindItError::BadExpression(
+            "Xattr method argument must be a String".to_string(),
+        ));
+    }
+    Ok(Box::new(Xattr { target, name }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_xattr_value() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "test")?;
+        if ::xattr::set(&path, "user.project", b"findit").is_err() {
+            // The file system does not support extended attributes.
+            return Ok(());
+        }
+        let expr = read_expr("xattr(\"user.project\")")?;
+        let file = &FileWrapper::new(path, 1);
+
+        assert_eq!(expr.eval(file), Value::String("findit".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xattr_missing_attribute() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "test")?;
+        let expr = read_expr("me.extended_attr(\"user.no.such.attribute\")")?;
+        let file = &FileWrapper::new(path, 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xattr_missing_file() -> Result<(), FindItError> {
+        let expr = read_expr("xattr(\"user.project\")")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xattr_empty_name() -> Result<(), FindItError> {
+        let expr = read_expr("xattr(content)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xattr_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("xattr(\"user.project\")")?;
+
+        assert_eq!(expr.expected_type(), ValueType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_path_return_error() {
+        let err = read_expr("\"a\".xattr(\"user.project\")").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn no_string_return_error() {
+        let err = read_expr("xattr(1)").err();
+        assert!(err.is_some())
+    }
+}
@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::{
+    class_type::{Class, ClassType},
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::expression::Expression,
+    value::{List, Value, ValueType},
+};
+
+const FIRST_FIELD_NAME: &str = "first";
+const SECOND_FIELD_NAME: &str = "second";
+
+struct ZipLongest {
+    target: Box<dyn Evaluator>,
+    other: Box<dyn Evaluator>,
+    default: Box<dyn Evaluator>,
+    fill_first: bool,
+    fill_second: bool,
+    class_type: Arc<ValueType>,
+    class_internal_type: Arc<ClassType>,
+}
+
+impl Evaluator for ZipLongest {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.class_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::List(other_value) = self.other.eval(file) else {
+            return Value::Empty;
+        };
+        let default = self.default.eval(file);
+        if default == Value::Empty {
+            return Value::Empty;
+        }
+        let first_default = if self.fill_first {
+            default.clone()
+        } else {
+            Value::Empty
+        };
+        let second_default = if self.fill_second {
+            default
+        } else {
+            Value::Empty
+        };
+        let class_internal_type = self.class_internal_type.clone();
+        let lst = List::new_lazy(
+            self.class_type.clone(),
+            target_value
+                .items()
+                .into_iter()
+                .zip_longest(other_value.items())
+                .map(move |pair| {
+                    let (first, second) = match pair {
+                        EitherOrBoth::Both(first, second) => (first, second),
+                        EitherOrBoth::Left(first) => (first, second_default.clone()),
+                        EitherOrBoth::Right(second) => (first_default.clone(), second),
+                    };
+                    Value::Class(Class::new(&class_internal_type, vec![first, second]))
+                }),
+        );
+        Value::List(lst)
+    }
+}
+
+pub(super) fn new_zip_longest(
+    target: Box<dyn Evaluator>,
+    other: &Expression,
+    default: &Expression,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(item_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "ZipLongest method can only be applied to List type".to_string(),
+        ));
+    };
+    let other = other.build(bindings)?;
+    let ValueType::List(other_item_type) = other.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "ZipLongest method first argument must be a list".to_string(),
+        ));
+    };
+    let default = default.build(bindings)?;
+    let fill_first = default.expected_type() == *item_type;
+    let fill_second = default.expected_type() == *other_item_type;
+    if !fill_first && !fill_second {
+        return Err(FindItError::BadExpression(
+            "ZipLongest method default value must be the same as the items of one of the lists"
+                .to_string(),
+        ));
+    }
+    let class_internal_type = Arc::new(ClassType::new(&[
+        (FIRST_FIELD_NAME.to_string(), (*item_type).clone()),
+        (SECOND_FIELD_NAME.to_string(), (*other_item_type).clone()),
+    ]));
+    let class_type = Arc::new(ValueType::Class(class_internal_type.clone()));
+
+    Ok(Box::new(ZipLongest {
+        target,
+        other,
+        default,
+        fill_first,
+        fill_second,
+        class_type,
+        class_internal_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::{
+        errors::FindItError, evaluators::expr::read_expr, file_wrapper::FileWrapper, value::Value,
+    };
+
+    #[test]
+    fn test_zip_longest_shorter_other() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].zipLongest([10], 0)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected =
+            read_expr("[{:first 1, :second 10}, {:first 2, :second 0}, {:first 3, :second 0}]")?
+                .eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_longest_shorter_target() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\"].zip_longest([\"b\", \"c\"], \"-\")")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected =
+            read_expr("[{:first \"a\", :second \"b\"}, {:first \"-\", :second \"c\"}]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_longest_empty_target() -> Result<(), FindItError> {
+        let expr = read_expr("files.zipLongest([@a], @b)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_longest_empty_other() -> Result<(), FindItError> {
+        let expr = read_expr("[@a].zipLongest(files, @b)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_longest_empty_default() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\"].zipLongest([\"b\"], content)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_return_error() {
+        let err = read_expr("12.zipLongest([1], 2)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn other_no_list_return_error() {
+        let err = read_expr("[1].zipLongest(1, 2)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn test_zip_longest_different_types() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].zipLongest([\"a\"], 0)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let Value::List(list) = expr.eval(file) else {
+            panic!("Expected a list");
+        };
+        let items = list.items().into_iter().collect::<Vec<_>>();
+        assert_eq!(items.len(), 3);
+        let Value::Class(first) = items[0].clone() else {
+            panic!("Expected a class");
+        };
+        assert_eq!(first.clone().get(0), Value::Number(1));
+        assert_eq!(first.get(1), Value::String("a".into()));
+        let Value::Class(last) = items[2].clone() else {
+            panic!("Expected a class");
+        };
+        assert_eq!(last.clone().get(0), Value::Number(3));
+        assert_eq!(last.get(1), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_longest_different_types_fill_other() -> Result<(), FindItError> {
+        let expr = read_expr("[1].zipLongest([\"a\", \"b\"], \"-\")")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let Value::List(list) = expr.eval(file) else {
+            panic!("Expected a list");
+        };
+        let items = list.items().into_iter().collect::<Vec<_>>();
+        let Value::Class(last) = items[1].clone() else {
+            panic!("Expected a class");
+        };
+        assert_eq!(last.clone().get(0), Value::Empty);
+        assert_eq!(last.get(1), Value::String("b".into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_different_type_return_error() {
+        let err = read_expr("[1].zipLongest([\"a\"], true)").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn default_different_type_than_both_return_error() {
+        let err = read_expr("[1].zipLongest([2], \"a\")").err();
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn missing_default_return_error() {
+        let err = read_expr("[1].zipLongest([2])").err();
+
+        assert!(err.is_some());
+    }
+}
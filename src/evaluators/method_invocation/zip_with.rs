@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::FindItError,
+    evaluators::expr::{BindingsTypes, Evaluator, EvaluatorFactory},
+    file_wrapper::FileWrapper,
+    parser::ast::{expression::Expression, methods::BinaryLambdaFunction},
+    value::{List, Value, ValueType},
+};
+
+struct ZipWith {
+    target: Box<dyn Evaluator>,
+    other: Box<dyn Evaluator>,
+    lambda: Arc<Box<dyn Evaluator>>,
+    items_type: Arc<ValueType>,
+}
+
+impl Evaluator for ZipWith {
+    fn expected_type(&self) -> ValueType {
+        ValueType::List(self.items_type.clone())
+    }
+    fn eval(&self, file: &FileWrapper) -> Value {
+        let Value::List(target_value) = self.target.eval(file) else {
+            return Value::Empty;
+        };
+        let Value::List(other_value) = self.other.eval(file) else {
+            return Value::Empty;
+        };
+        let lambda = self.lambda.clone();
+        let file = file.clone();
+        let iter = target_value
+            .items()
+            .into_iter()
+            .zip(other_value.items())
+            .map(move |(first, second)| {
+                let new_file = file.with_binding(first).with_binding(second);
+                lambda.eval(&new_file)
+            });
+        Value::List(List::new_lazy(self.items_type.clone(), iter))
+    }
+}
+
+pub(super) fn new_zip_with(
+    target: Box<dyn Evaluator>,
+    other: &Expression,
+    lambda: &BinaryLambdaFunction,
+    bindings: &BindingsTypes,
+) -> Result<Box<dyn Evaluator>, FindItError> {
+    let ValueType::List(first_type) = target.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "ZipWith method can only be applied to List type".to_string(),
+        ));
+    };
+    let other = other.build(bindings)?;
+    let ValueType::List(second_type) = other.expected_type() else {
+        return Err(FindItError::BadExpression(
+            "ZipWith method first argument must be a List".to_string(),
+        ));
+    };
+    let lambda = lambda.build(bindings, &first_type, &second_type)?;
+    let items_type = Arc::new(lambda.expected_type());
+
+    Ok(Box::new(ZipWith {
+        target,
+        other,
+        lambda: Arc::new(lambda),
+        items_type,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        value::{Value, ValueType},
+    };
+
+    #[test]
+    fn test_zip_with_addition() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].zipWith([10, 20, 30], $x $y $x + $y)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[11, 22, 33]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_with_concatenation() -> Result<(), FindItError> {
+        let expr = read_expr("[\"a\", \"b\"].zip_with([1, 2], $s $n $s + \"=\" + $n)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[\"a=1\", \"b=2\"]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_with_stops_at_shorter_list() -> Result<(), FindItError> {
+        let expr = read_expr("[1, 2, 3].zipWith([10], $x $y $x + $y)")?;
+        let file = &FileWrapper::new(PathBuf::new(), 1);
+
+        let expected = read_expr("[11]")?.eval(file);
+        assert_eq!(expr.eval(file), expected);
+
+        let expr = read_expr("[1].zipWith([10, 20, 30], $x $y $x + $y)")?;
+        assert_eq!(expr.eval(file), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_with_empty_other() -> Result<(), FindItError> {
+        let expr = read_expr("[@a].zipWith(files, $x $y $x = $y)")?;
+        let path = Path::new("no/such/file");
+        let file = &FileWrapper::new(path.to_path_buf(), 1);
+
+        assert_eq!(expr.eval(file), Value::Empty);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_with_expected_type() -> Result<(), FindItError> {
+        let expr = read_expr("[1].zipWith([2], $x $y $x > $y)")?;
+
+        assert_eq!(
+            expr.expected_type(),
+            ValueType::List(Arc::new(ValueType::Bool))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_list_return_error() {
+        let err = read_expr("1.zipWith([2], $x $y $x + $y)").err();
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn other_no_list_return_error() {
+        let err = read_expr("[1].zipWith(2, $x $y $x + $y)").err();
+        assert!(err.is_some())
+    }
+}
@@ -0,0 +1,252 @@
+use chrono::{DateTime, Local};
+use serde_json::{Map, Value as JsonValue};
+
+use crate::errors::FindItError;
+
+/// The metadata of a file that is not read from the file system, for places where there is no
+/// file system (like a browser). When a file has such metadata, the properties that need the file
+/// system (like `size`, `modified` or `content`) are taken from it instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMetadata {
+    pub exists: bool,
+    pub is_dir: bool,
+    pub is_link: bool,
+    pub size: Option<u64>,
+    pub created: Option<DateTime<Local>>,
+    pub modified: Option<DateTime<Local>>,
+    pub owner: Option<String>,
+    pub group: Option<String>,
+    pub permissions: Option<u32>,
+    pub content: Option<String>,
+}
+impl Default for FileMetadata {
+    fn default() -> Self {
+        Self {
+            exists: true,
+            is_dir: false,
+            is_link: false,
+            size: None,
+            created: None,
+            modified: None,
+            owner: None,
+            group: None,
+            permissions: None,
+            content: None,
+        }
+    }
+}
+
+impl FileMetadata {
+    /// Read the metadata from a JSON object like `{"size": 12, "modified": "2025-01-01T12:00:00Z"}`.
+    /// Missing fields keep their default (an existing regular file with no known properties), and
+    /// dates are RFC 3339 strings.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the JSON is invalid, if it is not an object or if one of the fields
+    /// is unknown or has the wrong type.
+    pub fn from_json(json: &str) -> Result<Self, FindItError> {
+        let json = serde_json::from_str::<JsonValue>(json)
+            .map_err(|err| FindItError::BadMetadata(err.to_string()))?;
+        let JsonValue::Object(fields) = json else {
+            return Err(FindItError::BadMetadata(
+                "The metadata must be a JSON object".into(),
+            ));
+        };
+        if let Some(unknown) = fields
+            .keys()
+            .find(|key| !KNOWN_FIELDS.contains(&key.as_str()))
+        {
+            return Err(FindItError::BadMetadata(format!(
+                "Unknown metadata field: `{unknown}`"
+            )));
+        }
+        let default = Self::default();
+        Ok(Self {
+            exists: bool_field(&fields, "exists")?.unwrap_or(default.exists),
+            is_dir: bool_field(&fields, "is_dir")?.unwrap_or(default.is_dir),
+            is_link: bool_field(&fields, "is_link")?.unwrap_or(default.is_link),
+            size: number_field(&fields, "size")?,
+            created: date_field(&fields, "created")?,
+            modified: date_field(&fields, "modified")?,
+            owner: string_field(&fields, "owner")?,
+            group: string_field(&fields, "group")?,
+            permissions: number_field(&fields, "permissions")?
+                .map(|permissions| {
+                    u32::try_from(permissions).map_err(|_| {
+                        FindItError::BadMetadata(format!("Invalid permissions: {permissions}"))
+                    })
+                })
+                .transpose()?,
+            content: string_field(&fields, "content")?,
+        })
+    }
+
+    pub(crate) fn is_file(&self) -> bool {
+        self.exists && !self.is_dir
+    }
+}
+
+const KNOWN_FIELDS: [&str; 10] = [
+    "exists",
+    "is_dir",
+    "is_link",
+    "size",
+    "created",
+    "modified",
+    "owner",
+    "group",
+    "permissions",
+    "content",
+];
+
+fn field<'a, T>(
+    fields: &'a Map<String, JsonValue>,
+    name: &str,
+    expected: &str,
+    convert: impl Fn(&'a JsonValue) -> Option<T>,
+) -> Result<Option<T>, FindItError> {
+    match fields.get(name) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(value) => convert(value).map(Some).ok_or_else(|| {
+            FindItError::BadMetadata(format!("The `{name}` field must be {expected}"))
+        }),
+    }
+}
+
+fn bool_field(fields: &Map<String, JsonValue>, name: &str) -> Result<Option<bool>, FindItError> {
+    field(fields, name, "a boolean", JsonValue::as_bool)
+}
+
+fn number_field(fields: &Map<String, JsonValue>, name: &str) -> Result<Option<u64>, FindItError> {
+    field(fields, name, "a positive integer", JsonValue::as_u64)
+}
+
+fn string_field(
+    fields: &Map<String, JsonValue>,
+    name: &str,
+) -> Result<Option<String>, FindItError> {
+    field(fields, name, "a string", |value| {
+        value.as_str().map(ToString::to_string)
+    })
+}
+
+fn date_field(
+    fields: &Map<String, JsonValue>,
+    name: &str,
+) -> Result<Option<DateTime<Local>>, FindItError> {
+    field(fields, name, "an RFC 3339 date", |value| {
+        DateTime::parse_from_rfc3339(value.as_str()?)
+            .ok()
+            .map(Into::into)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn empty_object_is_an_existing_file() -> Result<(), FindItError> {
+        let metadata = FileMetadata::from_json("{}")?;
+
+        assert_eq!(metadata, FileMetadata::default());
+        assert!(metadata.is_file());
+        Ok(())
+    }
+
+    #[test]
+    fn read_all_the_fields() -> Result<(), FindItError> {
+        let metadata = FileMetadata::from_json(
+            r#"{
+                "exists": true,
+                "is_dir": false,
+                "is_link": true,
+                "size": 12,
+                "created": "2025-01-01T12:00:00Z",
+                "modified": "2025-02-01T12:00:00Z",
+                "owner": "root",
+                "group": "wheel",
+                "permissions": 420,
+                "content": "hello"
+            }"#,
+        )?;
+
+        assert_eq!(
+            metadata,
+            FileMetadata {
+                exists: true,
+                is_dir: false,
+                is_link: true,
+                size: Some(12),
+                created: Some(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap().into()),
+                modified: Some(Utc.with_ymd_and_hms(2025, 2, 1, 12, 0, 0).unwrap().into()),
+                owner: Some("root".into()),
+                group: Some("wheel".into()),
+                permissions: Some(0o644),
+                content: Some("hello".into()),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn null_fields_are_missing() -> Result<(), FindItError> {
+        let metadata = FileMetadata::from_json(r#"{"size": null, "owner": null}"#)?;
+
+        assert_eq!(metadata, FileMetadata::default());
+        Ok(())
+    }
+
+    #[test]
+    fn directory_is_not_a_file() -> Result<(), FindItError> {
+        let metadata = FileMetadata::from_json(r#"{"is_dir": true}"#)?;
+
+        assert!(!metadata.is_file());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_json() {
+        let err = FileMetadata::from_json("{").err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(_))));
+    }
+
+    #[test]
+    fn not_an_object() {
+        let err = FileMetadata::from_json("[1, 2]").err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(_))));
+    }
+
+    #[test]
+    fn unknown_field() {
+        let err = FileMetadata::from_json(r#"{"sise": 12}"#).err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(msg)) if msg.contains("sise")));
+    }
+
+    #[test]
+    fn wrong_field_type() {
+        let err = FileMetadata::from_json(r#"{"size": "big"}"#).err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(msg)) if msg.contains("size")));
+    }
+
+    #[test]
+    fn invalid_date() {
+        let err = FileMetadata::from_json(r#"{"modified": "yesterday"}"#).err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(msg)) if msg.contains("modified")));
+    }
+
+    #[test]
+    fn permissions_too_big() {
+        let err = FileMetadata::from_json(r#"{"permissions": 100000000000}"#).err();
+
+        assert!(matches!(err, Some(FindItError::BadMetadata(_))));
+    }
+}
@@ -1,51 +1,102 @@
 use std::{
     fmt::{Debug, Display},
     fs,
-    ops::Deref,
-    path::PathBuf,
-    rc::Rc,
+    iter::successors,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
-use crate::{debugger::Debugger, errors::FindItError, value::Value};
+use crate::{
+    content::ContentOptions, debugger::Debugger, errors::FindItError, file_metadata::FileMetadata,
+    value::Value,
+};
+
+/// A single binding, linked to the bindings that were defined before it, so adding a binding
+/// does not copy the previous ones.
+#[derive(Debug)]
+struct BindingFrame {
+    index: usize,
+    value: Value,
+    previous: Option<Arc<BindingFrame>>,
+}
 
 #[derive(Debug, Clone)]
 pub(crate) struct FileWrapper {
-    path: PathBuf,
+    path: Arc<PathBuf>,
     depth: usize,
-    bindings: Vec<Rc<Value>>,
-    debugger: Rc<Box<dyn Debugger>>,
+    bindings: Option<Arc<BindingFrame>>,
+    debugger: Arc<Box<dyn Debugger>>,
+    content: ContentOptions,
+    color: bool,
+    debug_methods: bool,
+    metadata: Option<Arc<FileMetadata>>,
 }
 impl FileWrapper {
     pub(crate) fn new_with_debugger(
         path: PathBuf,
         depth: usize,
-        debugger: &Rc<Box<dyn Debugger>>,
+        debugger: &Arc<Box<dyn Debugger>>,
+        content: ContentOptions,
     ) -> Self {
         Self {
-            path,
+            path: Arc::new(path),
             depth,
-            bindings: Vec::new(),
+            bindings: None,
             debugger: debugger.clone(),
+            content,
+            color: false,
+            debug_methods: false,
+            metadata: None,
         }
     }
 
+    /// Set whether values may be colorized (see `--color`).
+    pub(crate) fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set whether the `debug` method should also write to the standard error (see `--debug-methods`).
+    pub(crate) fn with_debug_methods(mut self, debug_methods: bool) -> Self {
+        self.debug_methods = debug_methods;
+        self
+    }
+
+    /// Take the properties that need the file system from `metadata` instead of from the file.
+    pub(crate) fn with_metadata(mut self, metadata: FileMetadata) -> Self {
+        self.metadata = Some(Arc::new(metadata));
+        self
+    }
+
     pub(crate) fn with_file(&self, path: PathBuf) -> Self {
         Self {
-            path,
+            path: Arc::new(path),
             depth: self.depth + 1,
             bindings: self.bindings.clone(),
             debugger: self.debugger.clone(),
+            content: self.content,
+            color: self.color,
+            debug_methods: self.debug_methods,
+            metadata: None,
         }
     }
 
     pub(crate) fn with_binding(&self, binding: Value) -> Self {
-        let mut new_binding = self.bindings.clone();
-        new_binding.push(Rc::new(binding));
+        // Everything else is shared, so adding a binding does not depend on the bindings before it.
+        let frame = BindingFrame {
+            index: self.bindings.as_ref().map_or(0, |frame| frame.index + 1),
+            value: binding,
+            previous: self.bindings.clone(),
+        };
         Self {
-            path: self.path.to_path_buf(),
+            path: self.path.clone(),
             depth: self.depth,
-            bindings: new_binding,
+            bindings: Some(Arc::new(frame)),
             debugger: self.debugger.clone(),
+            content: self.content,
+            color: self.color,
+            debug_methods: self.debug_methods,
+            metadata: self.metadata.clone(),
         }
     }
 
@@ -57,32 +108,92 @@ impl FileWrapper {
         &self.path
     }
 
+    /// The metadata that replaces the file system for this file, if there is one.
+    pub(crate) fn metadata(&self) -> Option<&FileMetadata> {
+        self.metadata.as_deref()
+    }
+
     pub(crate) fn read(&self) -> Result<String, FindItError> {
-        let string = fs::read_to_string(&self.path)?;
-        Ok(string)
+        if let Some(metadata) = self.metadata() {
+            return metadata.content.clone().ok_or_else(|| {
+                FindItError::BadMetadata(format!("No content for {}", self.path.display()))
+            });
+        }
+        self.content.read(self.path.as_path())
     }
 
+    /// The binding at `index`. The lambdas usually use their own bindings, which are the last
+    /// ones, so only a few frames are skipped to find it.
     pub(crate) fn get_binding(&self, index: usize) -> Value {
-        self.bindings
-            .get(index)
-            .map(|b| b.deref().clone())
+        let Some(last) = self.bindings.as_deref() else {
+            return Value::Empty;
+        };
+        let Some(skip) = last.index.checked_sub(index) else {
+            return Value::Empty;
+        };
+        successors(Some(last), |frame| frame.previous.as_deref())
+            .nth(skip)
+            .map(|frame| frame.value.clone())
             .unwrap_or(Value::Empty)
     }
 
     pub(crate) fn count(&self) -> Result<usize, FindItError> {
+        if let Some(metadata) = self.metadata() {
+            if metadata.exists && metadata.is_dir {
+                return Err(FindItError::BadMetadata(format!(
+                    "Can not list the files of {}",
+                    self.path.display()
+                )));
+            }
+            return Ok(usize::from(metadata.exists));
+        }
         if !self.path.exists() {
             return Ok(0);
         }
         if !self.path.is_dir() {
             return Ok(1);
         }
-        let paths = fs::read_dir(&self.path)?;
+        let paths = fs::read_dir(self.path.as_path())?;
         Ok(paths.count())
     }
 
-    pub(crate) fn debugger(&self) -> &Rc<Box<dyn Debugger>> {
+    pub(crate) fn debugger(&self) -> &Arc<Box<dyn Debugger>> {
         &self.debugger
     }
+
+    /// Report that the evaluation failed, the caller should still return an empty value.
+    pub(crate) fn report(&self, failure: &dyn Fn() -> String) {
+        self.debugger.report(failure);
+    }
+
+    /// True if values may be colorized (see `--color`).
+    pub(crate) fn color(&self) -> bool {
+        self.color
+    }
+
+    /// True if the `debug` method should also write to the standard error (see `--debug-methods`).
+    pub(crate) fn debug_methods(&self) -> bool {
+        self.debug_methods
+    }
+
+    /// True if failures are reported in strict mode.
+    pub(crate) fn is_strict(&self) -> bool {
+        self.debugger.is_strict()
+    }
+
+    /// The lines of a file (not necessarily this one), reporting a failure if it can not be read.
+    pub(crate) fn read_lines(
+        &self,
+        path: &Path,
+    ) -> Option<Box<dyn Iterator<Item = String> + Send>> {
+        match self.content.lines(path) {
+            Ok(lines) => Some(lines),
+            Err(err) => {
+                self.report(&|| format!("Can not read the lines of [{}]: {err}", path.display()));
+                None
+            }
+        }
+    }
 }
 impl Display for FileWrapper {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -97,10 +208,111 @@ impl FileWrapper {
 
         let debugger = debugger::create_debugger(None).unwrap();
         Self {
-            path,
+            path: Arc::new(path),
             depth,
-            bindings: Vec::new(),
-            debugger: Rc::new(debugger),
+            bindings: None,
+            debugger: Arc::new(debugger),
+            content: ContentOptions::default(),
+            color: false,
+            debug_methods: false,
+            metadata: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf, time::Instant};
+
+    use crate::{
+        errors::FindItError, evaluators::expr::read_expr, file_wrapper::FileWrapper, value::Value,
+    };
+
+    #[test]
+    fn with_binding_keep_previous_bindings() {
+        let file = FileWrapper::new(PathBuf::from("a/b"), 1);
+        let first = file.with_binding(Value::Number(1));
+        let second = first.with_binding(Value::Number(2));
+
+        assert_eq!(second.get_binding(0), Value::Number(1));
+        assert_eq!(second.get_binding(1), Value::Number(2));
+        assert_eq!(first.get_binding(1), Value::Empty);
+        assert_eq!(file.get_binding(0), Value::Empty);
+    }
+
+    #[test]
+    fn sibling_bindings_are_independent() {
+        let file = FileWrapper::new(PathBuf::from("a/b"), 1).with_binding(Value::Number(1));
+        let one = file.with_binding(Value::String("one".into()));
+        let two = file.with_binding(Value::String("two".into()));
+
+        assert_eq!(one.get_binding(1), Value::String("one".into()));
+        assert_eq!(two.get_binding(1), Value::String("two".into()));
+        assert_eq!(two.get_binding(0), Value::Number(1));
+    }
+
+    #[test]
+    fn with_binding_share_the_path() {
+        let file = FileWrapper::new(PathBuf::from("a/b"), 1);
+        let new_file = file.with_binding(Value::Number(1));
+
+        assert!(std::ptr::eq(file.path(), new_file.path()));
+    }
+
+    #[test]
+    fn with_file_keep_the_bindings() {
+        let file = FileWrapper::new(PathBuf::from("a/b"), 1).with_binding(Value::Number(1));
+        let new_file = file.with_file(PathBuf::from("c"));
+
+        assert_eq!(new_file.get_binding(0), Value::Number(1));
+        assert_eq!(new_file.path(), &PathBuf::from("c"));
+        assert_eq!(new_file.dept(), 2);
+    }
+
+    #[test]
+    fn get_binding_after_many_bindings() {
+        let file = (0..100).fold(FileWrapper::new(PathBuf::from("a/b"), 1), |file, n| {
+            file.with_binding(Value::Number(n))
+        });
+
+        assert_eq!(file.get_binding(0), Value::Number(0));
+        assert_eq!(file.get_binding(42), Value::Number(42));
+        assert_eq!(file.get_binding(99), Value::Number(99));
+        assert_eq!(file.get_binding(100), Value::Empty);
+    }
+
+    /// Run with `cargo test --release benchmark_bindings -- --ignored`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn benchmark_bindings_of_a_mapped_list_under_many_bindings() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("large.txt");
+        let lines = (0..1_000_000_u64)
+            .map(|n| (n % 100).to_string())
+            .collect::<Vec<_>>();
+        fs::write(&path, lines.join("\n"))?;
+        let file = &FileWrapper::new(path, 1);
+        let mapped = "lines().map($l $l.length()).sum()";
+        // The same mapped list, inside 32 lambdas, so every item is bound after 32 bindings.
+        let nested = (0..32).fold(mapped.to_string(), |inner, n| {
+            format!("[{n}].map($b{n} {inner}).first()")
+        });
+        let mapped = read_expr(mapped)?;
+        let nested = read_expr(&nested)?;
+
+        let start = Instant::now();
+        let mapped = mapped.eval(file);
+        let mapped_time = start.elapsed();
+        let start = Instant::now();
+        let nested = nested.eval(file);
+        let nested_time = start.elapsed();
+
+        assert_eq!(mapped, nested);
+        assert!(
+            nested_time < mapped_time * 2,
+            "a binding should not copy the bindings before it, it took {nested_time:?} under 32 bindings and {mapped_time:?} without them"
+        );
+
+        Ok(())
+    }
+}
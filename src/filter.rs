@@ -1,11 +1,51 @@
+use std::collections::VecDeque;
+use std::fs;
 use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::evaluators::expr::{Evaluator, read_expr};
+use crate::parser::include::with_expression_file;
+use crate::thread_pool::ThreadPool;
 use crate::value::Value;
 use crate::{
     cli_args::CliArgs, errors::FindItError, file_wrapper::FileWrapper, min_depth::build_min,
     walker::Walk,
 };
+
+fn passes(expr: &dyn Evaluator, sql: &str, file: &FileWrapper) -> bool {
+    file.debugger().log(&|| {
+        format!(
+            "\tEvaluating file: [{}] with filter: `{}`",
+            file.path().display(),
+            sql
+        )
+    });
+    match expr.eval(file) {
+        Value::Bool(true) => {
+            file.debugger().log(&|| {
+                format!(
+                    "\t\t File: [{}] passed filter: `{}`",
+                    file.path().display(),
+                    sql
+                )
+            });
+            true
+        }
+        Value::Empty if file.is_strict() => {
+            file.report(&|| {
+                format!(
+                    "Filter `{}` is empty for file: [{}]",
+                    sql,
+                    file.path().display()
+                )
+            });
+            false
+        }
+        _ => false,
+    }
+}
+
 struct Filter {
     next: Box<dyn Walk>,
     expr: Box<dyn Evaluator>,
@@ -16,32 +56,110 @@ impl Walk for Filter {
         self.next.enough()
     }
     fn step(&mut self, file: &FileWrapper) {
-        file.debugger().log(&|| {
-            format!(
-                "\tEvaluating file: [{}] with filter: `{}`",
-                file.path().display(),
-                self.sql
-            )
-        });
-        if self.expr.eval(file) == Value::Bool(true) {
-            file.debugger().log(&|| {
-                format!(
-                    "\t\t File: [{}] passed filter: `{}`",
-                    file.path().display(),
-                    self.sql
-                )
-            });
+        if passes(self.expr.as_ref(), &self.sql, file) {
             self.next.step(file);
         }
     }
 }
+
+struct ParallelFilter {
+    next: Box<dyn Walk>,
+    pool: ThreadPool<bool>,
+    /// The files that were scheduled, in the walk order, waiting for their result.
+    pending: VecDeque<FileWrapper>,
+}
+impl ParallelFilter {
+    fn pass_on(&mut self, passed: bool) {
+        let Some(file) = self.pending.pop_front() else {
+            return;
+        };
+        if passed && !self.next.enough() {
+            self.next.step(&file);
+        }
+    }
+    fn pass_on_ready(&mut self) {
+        while let Some(passed) = self.pool.try_next() {
+            self.pass_on(passed);
+        }
+    }
+}
+impl Walk for ParallelFilter {
+    fn enough(&self) -> bool {
+        self.next.enough()
+    }
+    fn step(&mut self, file: &FileWrapper) {
+        self.pass_on_ready();
+        // Wait for a worker to be free, so no more files than the number of workers are
+        // evaluated ahead of the walk.
+        while self.pool.pending() >= self.pool.jobs() {
+            let Some(passed) = self.pool.next() else {
+                break;
+            };
+            self.pass_on(passed);
+        }
+        // Once there are enough files, the rest are not evaluated, so their side effects (like
+        // `execute`) will not run.
+        if self.next.enough() {
+            return;
+        }
+        self.pending.push_back(file.clone());
+        self.pool.schedule(file.clone());
+        self.pass_on_ready();
+    }
+    fn finish(&mut self) {
+        while let Some(passed) = self.pool.next() {
+            self.pass_on(passed);
+        }
+    }
+}
+
+/// Read a filter, relative `INCLUDE`s are resolved relative to the file it was read from (if any).
+fn read_filter(sql: &str, path: Option<&PathBuf>) -> Result<Box<dyn Evaluator>, FindItError> {
+    match path {
+        Some(path) => with_expression_file(path, || read_expr(sql)),
+        None => read_expr(sql),
+    }
+}
+
 pub(crate) fn make_filters<W: Write + 'static>(
     args: &CliArgs,
     writer: W,
 ) -> Result<Box<dyn Walk>, FindItError> {
+    // The file of each filter (if it was read from a file), to resolve the `INCLUDE`s in it.
+    let mut sqls = args
+        .filter
+        .iter()
+        .map(|sql| (sql.clone(), None))
+        .collect::<Vec<_>>();
+    for path in &args.expr_file {
+        sqls.push((fs::read_to_string(path)?, Some(path)));
+    }
     let mut last = build_min(args, writer)?;
-    for sql in &args.filter {
-        let expr = read_expr(sql)?;
+    if let Some(jobs) = args.jobs.filter(|jobs| jobs.get() > 1)
+        && !sqls.is_empty()
+    {
+        let mut filters = vec![];
+        for (sql, path) in &sqls {
+            filters.push((read_filter(sql, *path)?, sql.clone()));
+        }
+        let pool = ThreadPool::new(
+            jobs,
+            Arc::new(move |file: &FileWrapper| {
+                // Same order as the chain of filters, where the last filter is evaluated first.
+                filters
+                    .iter()
+                    .rev()
+                    .all(|(expr, sql)| passes(expr.as_ref(), sql, file))
+            }),
+        );
+        return Ok(Box::new(ParallelFilter {
+            next: last,
+            pool,
+            pending: VecDeque::new(),
+        }));
+    }
+    for (sql, path) in &sqls {
+        let expr = read_filter(sql, *path)?;
         last = Box::new(Filter {
             expr,
             next: last,
@@ -51,3 +169,87 @@ pub(crate) fn make_filters<W: Write + 'static>(
 
     Ok(last)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        num::NonZeroUsize,
+        path::PathBuf,
+        rc::Rc,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+
+    struct Collect {
+        found: Rc<RefCell<Vec<usize>>>,
+        limit: usize,
+    }
+    impl Walk for Collect {
+        fn enough(&self) -> bool {
+            self.found.borrow().len() >= self.limit
+        }
+        fn step(&mut self, file: &FileWrapper) {
+            self.found.borrow_mut().push(file.dept());
+        }
+    }
+
+    fn parallel_filter(
+        jobs: usize,
+        limit: usize,
+        evaluated: Arc<AtomicUsize>,
+    ) -> (ParallelFilter, Rc<RefCell<Vec<usize>>>) {
+        let found = Rc::new(RefCell::new(vec![]));
+        let filter = ParallelFilter {
+            next: Box::new(Collect {
+                found: found.clone(),
+                limit,
+            }),
+            pool: ThreadPool::new(
+                NonZeroUsize::new(jobs).unwrap(),
+                Arc::new(move |file: &FileWrapper| {
+                    evaluated.fetch_add(1, Ordering::SeqCst);
+                    file.dept().is_multiple_of(2)
+                }),
+            ),
+            pending: VecDeque::new(),
+        };
+        (filter, found)
+    }
+
+    #[test]
+    fn parallel_filter_keep_the_walk_order() {
+        let (mut filter, found) = parallel_filter(3, usize::MAX, Arc::default());
+
+        for depth in 0..20 {
+            filter.step(&FileWrapper::new(PathBuf::from("a"), depth));
+        }
+        filter.finish();
+
+        assert_eq!(*found.borrow(), (0..20).step_by(2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parallel_filter_pass_on_files_without_waiting_for_the_end() {
+        let (mut filter, found) = parallel_filter(1, usize::MAX, Arc::default());
+
+        filter.step(&FileWrapper::new(PathBuf::from("a"), 2));
+
+        assert_eq!(*found.borrow(), vec![2]);
+    }
+
+    #[test]
+    fn parallel_filter_stop_evaluating_once_there_are_enough_files() {
+        let evaluated = Arc::new(AtomicUsize::new(0));
+        let (mut filter, found) = parallel_filter(2, 1, evaluated.clone());
+
+        for depth in 0..20 {
+            filter.step(&FileWrapper::new(PathBuf::from("a"), depth));
+        }
+        filter.finish();
+
+        assert_eq!(*found.borrow(), vec![0]);
+        assert!(evaluated.load(Ordering::SeqCst) <= 2);
+    }
+}
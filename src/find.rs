@@ -0,0 +1,62 @@
+use std::{cell::RefCell, ffi::OsStr, path::Path, rc::Rc};
+
+use clap::Parser;
+
+use crate::{
+    cli_args::CliArgs,
+    errors::FindItError,
+    evaluators::expr::{Evaluator, read_expr},
+    file_wrapper::FileWrapper,
+    value::Value,
+    walker::{Walk, Walkers},
+};
+
+struct Collector {
+    filter: Box<dyn Evaluator>,
+    output: Option<Box<dyn Evaluator>>,
+    found: Rc<RefCell<Vec<Value>>>,
+}
+impl Walk for Collector {
+    fn step(&mut self, file: &FileWrapper) {
+        if self.filter.eval(file) != Value::Bool(true) {
+            return;
+        }
+        let value = match &self.output {
+            Some(output) => output.eval(file),
+            None => file.path().as_path().into(),
+        };
+        self.found.borrow_mut().push(value);
+    }
+    fn enough(&self) -> bool {
+        false
+    }
+}
+
+/// Find all the files under `root` (including `root` itself) that pass the `filter` expression,
+/// using the same defaults as the command line.
+///
+/// For each file that passes the filter, the iterator returns the value of the `output`
+/// expression, or the path of the file if there is no `output` expression.
+///
+/// # Errors
+///
+/// Will return `Err` if the root does not exists, if one of the expressions is invalid or
+/// if the walk failed.
+pub fn find(
+    root: &Path,
+    filter: &str,
+    output: Option<&str>,
+) -> Result<impl Iterator<Item = Value>, FindItError> {
+    let args = CliArgs::parse_from([OsStr::new("findit"), OsStr::new("--"), root.as_os_str()]);
+    let walkers = Walkers::try_from(&args)?;
+    let found = Rc::new(RefCell::new(vec![]));
+    let mut collector: Box<dyn Walk> = Box::new(Collector {
+        filter: read_expr(filter)?,
+        output: output.map(read_expr).transpose()?,
+        found: found.clone(),
+    });
+    walkers.walk(&mut collector)?;
+    drop(collector);
+
+    Ok(found.take().into_iter())
+}
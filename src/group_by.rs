@@ -0,0 +1,236 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    io::{Error as IoError, Write},
+    mem,
+    rc::Rc,
+};
+
+use crate::{
+    cli_args::CliArgs,
+    errors::FindItError,
+    evaluators::expr::{Evaluator, read_expr},
+    file_wrapper::FileWrapper,
+    limit::make_limit,
+    output::build_output,
+    value::Value,
+    walker::Walk,
+};
+
+/// A writer that can be shared between the group headers and the output of the files.
+struct SharedWriter<W: Write>(Rc<RefCell<W>>);
+impl<W: Write> Clone for SharedWriter<W> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<W: Write> Write for SharedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+        self.0.borrow_mut().write(buf)
+    }
+    fn flush(&mut self) -> Result<(), IoError> {
+        self.0.borrow_mut().flush()
+    }
+}
+
+struct GroupBy<W: Write> {
+    key: Box<dyn Evaluator>,
+    groups: BTreeMap<Value, Vec<FileWrapper>>,
+    writer: SharedWriter<W>,
+    /// How to show the files of each group, `None` if only the counts should be shown.
+    output: Option<Box<dyn Walk>>,
+    limit: Option<Box<dyn Walk>>,
+}
+impl<W: Write> Walk for GroupBy<W> {
+    fn enough(&self) -> bool {
+        self.limit.as_deref().is_some_and(|limit| limit.enough())
+    }
+    fn step(&mut self, file: &FileWrapper) {
+        let key = self.key.eval(file);
+        self.groups.entry(key).or_default().push(file.clone());
+        if let Some(limit) = self.limit.as_deref_mut() {
+            limit.step(file);
+        }
+    }
+}
+impl<W: Write> Drop for GroupBy<W> {
+    fn drop(&mut self) {
+        #[allow(clippy::mutable_key_type)]
+        let groups = mem::take(&mut self.groups);
+        for (key, files) in groups {
+            let suffix = if files.len() == 1 { "" } else { "s" };
+            writeln!(self.writer, "{key}: {} file{suffix}", files.len()).ok();
+            self.writer.flush().ok();
+            if let Some(output) = self.output.as_deref_mut() {
+                for file in &files {
+                    write!(self.writer, "  ").ok();
+                    output.step(file);
+                }
+            }
+        }
+    }
+}
+
+struct Count<W: Write> {
+    counter: usize,
+    writer: W,
+    limit: Option<Box<dyn Walk>>,
+}
+impl<W: Write> Walk for Count<W> {
+    fn enough(&self) -> bool {
+        self.limit.as_deref().is_some_and(|limit| limit.enough())
+    }
+    fn step(&mut self, file: &FileWrapper) {
+        self.counter += 1;
+        if let Some(limit) = self.limit.as_deref_mut() {
+            limit.step(file);
+        }
+    }
+}
+impl<W: Write> Drop for Count<W> {
+    fn drop(&mut self) {
+        writeln!(self.writer, "{}", self.counter).ok();
+        self.writer.flush().ok();
+    }
+}
+
+pub(crate) fn build_group_by<W: Write + 'static>(
+    args: &CliArgs,
+    writer: W,
+) -> Result<Box<dyn Walk>, FindItError> {
+    let Some(key) = &args.group_by else {
+        if args.count {
+            return Ok(Box::new(Count {
+                counter: 0,
+                writer,
+                limit: make_limit(args),
+            }));
+        }
+        return build_output(args, writer);
+    };
+    let key = read_expr(key)?;
+    let writer = SharedWriter(Rc::new(RefCell::new(writer)));
+    let output = if args.count {
+        None
+    } else {
+        Some(build_output(args, writer.clone())?)
+    };
+    Ok(Box::new(GroupBy {
+        key,
+        groups: BTreeMap::new(),
+        writer,
+        output,
+        limit: make_limit(args),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> Result<(), IoError> {
+            Ok(())
+        }
+    }
+    impl Output {
+        fn text(&self) -> String {
+            String::from_utf8_lossy(&self.0.borrow()).to_string()
+        }
+    }
+
+    fn group(args: &[&str], dir: &Path) -> Result<String, FindItError> {
+        let mut all_args = vec!["findit"];
+        all_args.extend(args);
+        let args = CliArgs::parse_from(all_args);
+        let output = Output::default();
+        let mut walk = build_group_by(&args, output.clone())?;
+        for name in ["b.rs", "a.txt", "c.rs", "Makefile"] {
+            if walk.enough() {
+                break;
+            }
+            walk.step(&FileWrapper::new(dir.join(name), 1));
+        }
+        drop(walk);
+        Ok(output.text())
+    }
+
+    #[test]
+    fn group_by_shows_the_files_of_each_group() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+
+        let text = group(&["--group-by", "extension", "-d", "`name`"], dir.path())?;
+
+        assert_eq!(
+            text,
+            "rs: 2 files\n  b.rs\n  c.rs\ntxt: 1 file\n  a.txt\n: 1 file\n  Makefile\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn group_by_with_count_only_shows_the_counts() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+
+        let text = group(&["--group-by", "extension", "--count"], dir.path())?;
+
+        assert_eq!(text, "rs: 2 files\ntxt: 1 file\n: 1 file\n");
+        Ok(())
+    }
+
+    #[test]
+    fn group_by_without_display_shows_the_paths() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("a.txt"), "a")?;
+
+        let text = group(&["--group-by", "name.length() > 4"], dir.path())?;
+
+        let dir = dir.path().display();
+        assert_eq!(
+            text,
+            format!(
+                "false: 2 files\n  {dir}/b.rs\n  {dir}/c.rs\ntrue: 2 files\n  {dir}/a.txt\n  {dir}/Makefile\n"
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn group_by_stops_after_the_limit() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+
+        let text = group(
+            &["--group-by", "extension", "--count", "--limit", "2"],
+            dir.path(),
+        )?;
+
+        assert_eq!(text, "rs: 1 file\ntxt: 1 file\n");
+        Ok(())
+    }
+
+    #[test]
+    fn count_without_group_by() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+
+        assert_eq!(group(&["--count"], dir.path())?, "4\n");
+        assert_eq!(group(&["--count", "--limit", "3"], dir.path())?, "3\n");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_group_by_expression() {
+        let args = CliArgs::parse_from(vec!["findit", "--group-by", "size >"]);
+
+        assert!(build_group_by(&args, vec![]).is_err());
+    }
+}
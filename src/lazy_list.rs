@@ -3,49 +3,48 @@ use std::fmt::{Debug, Result as FmtResult};
 use std::hash::Hash;
 use std::ops::Deref;
 use std::{
-    cell::RefCell,
     fmt::{Display, Formatter},
     ops::DerefMut,
-    rc::Rc,
+    sync::{Arc, Mutex, PoisonError},
 };
 
 enum LazyListImpl<T> {
-    Lazy(Box<dyn Iterator<Item = T>>),
-    Eager(Rc<Vec<T>>),
+    Lazy(Box<dyn Iterator<Item = T> + Send>),
+    Eager(Arc<Vec<T>>),
 }
 pub(crate) struct LazyList<T> {
-    list: RefCell<LazyListImpl<T>>,
+    list: Mutex<LazyListImpl<T>>,
 }
 
 impl<T> From<Vec<T>> for LazyList<T> {
     fn from(value: Vec<T>) -> Self {
-        Rc::new(value).into()
+        Arc::new(value).into()
     }
 }
-impl<T> From<Rc<Vec<T>>> for LazyList<T> {
-    fn from(value: Rc<Vec<T>>) -> Self {
+impl<T> From<Arc<Vec<T>>> for LazyList<T> {
+    fn from(value: Arc<Vec<T>>) -> Self {
         LazyList {
-            list: RefCell::new(LazyListImpl::Eager(value)),
+            list: Mutex::new(LazyListImpl::Eager(value)),
         }
     }
 }
 
-impl<T> From<Box<dyn Iterator<Item = T>>> for LazyList<T> {
-    fn from(value: Box<dyn Iterator<Item = T>>) -> Self {
+impl<T> From<Box<dyn Iterator<Item = T> + Send>> for LazyList<T> {
+    fn from(value: Box<dyn Iterator<Item = T> + Send>) -> Self {
         LazyList {
-            list: RefCell::new(LazyListImpl::Lazy(value)),
+            list: Mutex::new(LazyListImpl::Lazy(value)),
         }
     }
 }
 
 impl<T> LazyList<T> {
-    fn eager(&self) -> Rc<Vec<T>> {
-        let mut list = self.list.borrow_mut();
+    fn eager(&self) -> Arc<Vec<T>> {
+        let mut list = self.list.lock().unwrap_or_else(PoisonError::into_inner);
         match list.deref_mut() {
             LazyListImpl::Eager(vec) => vec.clone(),
             LazyListImpl::Lazy(iter) => {
                 let vec: Vec<_> = iter.collect();
-                let vec = Rc::new(vec);
+                let vec = Arc::new(vec);
                 *list = LazyListImpl::Eager(vec.clone());
                 vec
             }
@@ -77,7 +76,7 @@ impl<T: Hash> Hash for LazyList<T> {
 }
 struct ListIterator<T> {
     index: usize,
-    list: Rc<Vec<T>>,
+    list: Arc<Vec<T>>,
 }
 impl<T: Clone> Iterator for ListIterator<T> {
     type Item = T;
@@ -92,13 +91,13 @@ impl<T: Clone> Iterator for ListIterator<T> {
     }
 }
 impl<T> ListIterator<T> {
-    fn new(list: Rc<Vec<T>>) -> Self {
+    fn new(list: Arc<Vec<T>>) -> Self {
         Self { index: 0, list }
     }
 }
 enum LazyListIteratorImpl<T> {
     Eager(ListIterator<T>),
-    Lazy(Box<dyn Iterator<Item = T>>),
+    Lazy(Box<dyn Iterator<Item = T> + Send>),
 }
 pub(crate) struct LazyListIterator<T> {
     iter: LazyListIteratorImpl<T>,
@@ -143,7 +142,10 @@ impl<T: Clone> IntoIterator for LazyList<T> {
     type IntoIter = LazyListIterator<T>;
     type Item = T;
     fn into_iter(self) -> Self::IntoIter {
-        self.list.into_inner().into_iter()
+        self.list
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .into_iter()
     }
 }
 impl<T: Debug> Debug for LazyList<T> {
@@ -176,7 +178,7 @@ mod tests {
     use std::{
         cmp::Ordering,
         hash::{DefaultHasher, Hash, Hasher},
-        rc::Rc,
+        sync::Arc,
         vec,
     };
 
@@ -191,7 +193,7 @@ mod tests {
 
     #[test]
     fn test_lazy_list_lazy_display() {
-        let lst: Box<dyn Iterator<Item = _>> = Box::new(vec![11, 21].into_iter());
+        let lst: Box<dyn Iterator<Item = _> + Send> = Box::new(vec![11, 21].into_iter());
         let lst: LazyList<_> = lst.into();
         assert_eq!(format!("{}", lst), "[11, 21]");
     }
@@ -214,8 +216,8 @@ mod tests {
 
     #[test]
     fn test_lazy_list_ordering() {
-        let lst_one: Box<dyn Iterator<Item = _>> = Box::new(vec![11, 21].into_iter());
-        let lst_two: Box<dyn Iterator<Item = _>> = Box::new(vec![10, 30].into_iter());
+        let lst_one: Box<dyn Iterator<Item = _> + Send> = Box::new(vec![11, 21].into_iter());
+        let lst_two: Box<dyn Iterator<Item = _> + Send> = Box::new(vec![10, 30].into_iter());
         let lst_one: LazyList<_> = lst_one.into();
         let lst_two: LazyList<_> = lst_two.into();
 
@@ -224,7 +226,7 @@ mod tests {
 
     #[test]
     fn test_lazy_clone() {
-        let lst_one: Box<dyn Iterator<Item = _>> = Box::new(vec![11, 21].into_iter());
+        let lst_one: Box<dyn Iterator<Item = _> + Send> = Box::new(vec![11, 21].into_iter());
         let lst_one: LazyList<_> = lst_one.into();
         let lst_two = lst_one.clone();
 
@@ -233,7 +235,7 @@ mod tests {
 
     #[test]
     fn test_nth_vec() {
-        let lst: LazyList<_> = Rc::new(vec![1, 2, 3, 4, 5]).into();
+        let lst: LazyList<_> = Arc::new(vec![1, 2, 3, 4, 5]).into();
 
         let num = lst.into_iter().nth(2);
 
@@ -242,7 +244,7 @@ mod tests {
 
     #[test]
     fn test_skip_iter() {
-        let lst: Box<dyn Iterator<Item = _>> = Box::new(vec![1, 2, 3, 4, 5].into_iter());
+        let lst: Box<dyn Iterator<Item = _> + Send> = Box::new(vec![1, 2, 3, 4, 5].into_iter());
         let lst: LazyList<_> = lst.into();
 
         let num = lst.into_iter().nth(2);
@@ -252,7 +254,7 @@ mod tests {
 
     #[test]
     fn test_hash() {
-        let lst: Box<dyn Iterator<Item = _>> = Box::new(vec![1, 2, 3, 4, 5].into_iter());
+        let lst: Box<dyn Iterator<Item = _> + Send> = Box::new(vec![1, 2, 3, 4, 5].into_iter());
         let lst: LazyList<_> = lst.into();
 
         let mut s = DefaultHasher::new();
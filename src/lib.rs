@@ -1,19 +1,35 @@
 #![deny(warnings)]
 
+mod cache;
 mod class_type;
 pub mod cli_args;
+mod clock;
+mod content;
 mod debugger;
 pub mod errors;
+mod evaluate;
 mod evaluators;
+mod file_metadata;
 mod file_wrapper;
 mod filter;
+mod find;
+mod group_by;
 mod lazy_list;
 mod limit;
 mod min_depth;
 mod order;
 mod output;
 pub(crate) mod parser;
+mod platform;
 mod quick_ref;
 pub mod run_func;
+mod thread_pool;
 mod value;
 mod walker;
+
+pub use class_type::{Class, ClassType};
+pub use errors::FindItError;
+pub use evaluate::{evaluate, expression_ast};
+pub use file_metadata::FileMetadata;
+pub use find::find;
+pub use value::{Float, List, Value, ValueType};
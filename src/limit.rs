@@ -30,12 +30,12 @@ pub(crate) fn make_limit(args: &CliArgs) -> Option<Box<dyn Walk>> {
 
 #[cfg(test)]
 mod tests {
-    use std::{fs, rc::Rc};
+    use std::{fs, sync::Arc};
 
     use clap::Parser;
 
     use crate::{
-        cli_args::CliArgs, debugger::create_debugger, errors::FindItError,
+        cli_args::CliArgs, content::ContentOptions, debugger::create_debugger, errors::FindItError,
         file_wrapper::FileWrapper, limit::make_limit,
     };
 
@@ -46,7 +46,7 @@ mod tests {
             .path()
             .join("limit/debug/directory")
             .join("debug.log");
-        let debugger = Rc::new(create_debugger(Some(&log_path))?);
+        let debugger = Arc::new(create_debugger(Some(&log_path))?);
 
         let args = CliArgs::parse_from(vec!["findit", "--limit", "2"]);
 
@@ -56,6 +56,7 @@ mod tests {
             temp_dir.path().join("file1.txt"),
             0,
             &debugger,
+            ContentOptions::default(),
         ));
         assert!(!step.enough());
 
@@ -63,6 +64,7 @@ mod tests {
             temp_dir.path().join("file2.txt"),
             0,
             &debugger,
+            ContentOptions::default(),
         ));
         assert!(step.enough());
 
@@ -1,12 +1,12 @@
 #![deny(warnings)]
 
-use std::io::stdout;
+use std::io::{IsTerminal, stdout};
 
 use clap::Parser;
 use findit_cli::{cli_args::CliArgs, run_func::run};
 
 fn main() {
-    let args = CliArgs::parse();
+    let args = CliArgs::parse().with_terminal_output(stdout().is_terminal());
     if let Err(e) = run(&args, stdout()) {
         eprintln!("{e}");
         std::process::exit(1)
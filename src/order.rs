@@ -1,11 +1,13 @@
-use std::{cmp::Ordering, collections::HashMap, io::Write};
+use std::{cmp::Ordering, fs, io::Write, mem, num::NonZeroUsize, sync::Arc};
 
 use crate::{
     cli_args::CliArgs,
     errors::FindItError,
     evaluators::expr::{Evaluator, read_order_by},
     file_wrapper::FileWrapper,
-    output::build_output,
+    group_by::build_group_by,
+    thread_pool::ThreadPool,
+    value::Value,
     walker::Walk,
 };
 
@@ -20,8 +22,9 @@ pub(crate) struct OrderItem {
 
 struct OrderBy {
     next: Box<dyn Walk>,
-    order: Vec<OrderItem>,
+    order: Arc<Vec<OrderItem>>,
     items: Vec<FileWrapper>,
+    pool: ThreadPool<Vec<Value>>,
 }
 
 impl Walk for OrderBy {
@@ -32,31 +35,42 @@ impl Walk for OrderBy {
         self.items.push(file.clone());
     }
 }
+impl OrderItem {
+    fn compare(&self, left: &Value, right: &Value) -> Ordering {
+        // Empty values are always last, regardless of the direction.
+        match (left, right) {
+            (Value::Empty, Value::Empty) => Ordering::Equal,
+            (Value::Empty, _) => Ordering::Greater,
+            (_, Value::Empty) => Ordering::Less,
+            _ => match self.direction {
+                OrderDirection::Asc => left.cmp(right),
+                OrderDirection::Desc => left.cmp(right).reverse(),
+            },
+        }
+    }
+}
 impl Drop for OrderBy {
     fn drop(&mut self) {
-        let mut cache = HashMap::new();
-        self.items.sort_by(|left, right| {
-            for (index, item) in self.order.iter().enumerate() {
-                let left = cache
-                    .entry((index, left.path().to_path_buf()))
-                    .or_insert_with(|| item.evaluator.eval(left))
-                    .clone();
-                let right = cache
-                    .entry((index, right.path().to_path_buf()))
-                    .or_insert_with(|| item.evaluator.eval(right))
-                    .clone();
-                let cmp = left.cmp(&right);
-                let order = match item.direction {
-                    OrderDirection::Asc => cmp,
-                    OrderDirection::Desc => cmp.reverse(),
-                };
-                if order != Ordering::Equal {
-                    return order;
-                }
-            }
-            Ordering::Equal
-        });
-        for file in &self.items {
+        let files = mem::take(&mut self.items);
+        let keys = self.pool.map(&files);
+        // Files with the same keys are sorted by their canonical path (or by their path, as it was
+        // walked, if it can not be canonicalized). The sort is stable, so ties keep the walk order.
+        let paths = files
+            .iter()
+            .map(|file| fs::canonicalize(file.path()).unwrap_or_else(|_| file.path().to_path_buf()))
+            .collect::<Vec<_>>();
+        let mut items = keys.into_iter().zip(paths).zip(files).collect::<Vec<_>>();
+        items.sort_by(
+            |((left_keys, left_path), _), ((right_keys, right_path), _)| {
+                self.order
+                    .iter()
+                    .zip(left_keys.iter().zip(right_keys))
+                    .map(|(item, (left, right))| item.compare(left, right))
+                    .find(|order| order.is_ne())
+                    .unwrap_or_else(|| left_path.cmp(right_path))
+            },
+        );
+        for (_, file) in &items {
             if self.next.enough() {
                 return;
             }
@@ -68,14 +82,134 @@ pub(crate) fn build_order_by<W: Write + 'static>(
     args: &CliArgs,
     writer: W,
 ) -> Result<Box<dyn Walk>, FindItError> {
-    let next = build_output(args, writer)?;
-    let Some(order) = &args.order_by else {
+    let next = build_group_by(args, writer)?;
+    if args.order_by.is_empty() {
         return Ok(next);
-    };
-    let order = read_order_by(order)?;
+    }
+    // Every `--order-by` adds its keys after the keys of the previous ones.
+    let mut order = vec![];
+    for order_by in &args.order_by {
+        order.extend(read_order_by(order_by)?);
+    }
+    let order = Arc::new(order);
+    let keys_order = order.clone();
+    let pool = ThreadPool::new(
+        args.jobs.unwrap_or(NonZeroUsize::MIN),
+        Arc::new(move |file: &FileWrapper| {
+            keys_order
+                .iter()
+                .map(|item| item.evaluator.eval(file))
+                .collect::<Vec<_>>()
+        }),
+    );
     Ok(Box::new(OrderBy {
         next,
         order,
         items: vec![],
+        pool,
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, fs, io::Error as IoError, path::Path, rc::Rc};
+
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Default, Clone)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> Result<(), IoError> {
+            Ok(())
+        }
+    }
+
+    fn order(args: &[&str], dir: &Path) -> Result<String, FindItError> {
+        let mut all_args = vec!["findit", "-d", "`name`"];
+        all_args.extend(args);
+        let args = CliArgs::parse_from(all_args);
+        let output = Output::default();
+        let mut walk = build_order_by(&args, output.clone())?;
+        for name in ["b.rs", "a.txt", "c.rs", "d.txt"] {
+            walk.step(&FileWrapper::new(dir.join(name), 1));
+        }
+        drop(walk);
+        Ok(String::from_utf8_lossy(&output.0.borrow()).to_string())
+    }
+
+    fn create_files(dir: &Path) -> Result<(), FindItError> {
+        fs::write(dir.join("b.rs"), "12345")?;
+        fs::write(dir.join("c.rs"), "123")?;
+        fs::write(dir.join("a.txt"), "1")?;
+        fs::write(dir.join("d.txt"), "12")?;
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_order_by_adds_keys() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        create_files(dir.path())?;
+
+        let text = order(
+            &["--order-by", "extension asc", "--order-by", "size desc"],
+            dir.path(),
+        )?;
+
+        assert_eq!(text, "b.rs\nc.rs\nd.txt\na.txt\n");
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_order_by_is_the_same_as_a_list() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        create_files(dir.path())?;
+
+        let repeated = order(&["--sort", "extension DESC", "-o", "size"], dir.path())?;
+        let list = order(&["--order-by", "extension DESC, size"], dir.path())?;
+
+        assert_eq!(repeated, "a.txt\nd.txt\nc.rs\nb.rs\n");
+        assert_eq!(repeated, list);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ties_are_sorted_by_canonical_path() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let root = fs::canonicalize(dir.path())?;
+        for name in ["b", "c"] {
+            fs::create_dir(root.join(name))?;
+            fs::write(root.join(name).join("f"), "")?;
+        }
+        std::os::unix::fs::symlink(root.join("c"), root.join("a"))?;
+        let args = CliArgs::parse_from(["findit", "-d", "`path`", "--order-by", "name"]);
+        let output = Output::default();
+        let mut walk = build_order_by(&args, output.clone())?;
+        for name in ["a", "b"] {
+            walk.step(&FileWrapper::new(root.join(name).join("f"), 1));
+        }
+        drop(walk);
+
+        let text = String::from_utf8_lossy(&output.0.borrow()).to_string();
+        let expected = format!(
+            "{}\n{}\n",
+            root.join("b").join("f").display(),
+            root.join("a").join("f").display()
+        );
+        assert_eq!(text, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_second_order_by_fails() {
+        let args = CliArgs::parse_from(vec!["findit", "-o", "size", "-o", "name DESC size"]);
+
+        assert!(build_order_by(&args, vec![]).is_err());
+    }
+}
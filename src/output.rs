@@ -1,10 +1,52 @@
+use std::env;
+use std::ffi::OsString;
 use std::io::Error as IoError;
 use std::io::Write;
 
 use crate::errors::FindItError;
 use crate::evaluators::expr::Evaluator;
 use crate::evaluators::expr::read_expr;
-use crate::{cli_args::CliArgs, file_wrapper::FileWrapper, limit::make_limit, walker::Walk};
+use crate::{
+    cli_args::{CliArgs, ColorChoice},
+    file_wrapper::FileWrapper,
+    limit::make_limit,
+    platform::mode,
+    walker::Walk,
+};
+
+const DIR_COLOR: &str = "\x1b[1;34m";
+const LINK_COLOR: &str = "\x1b[1;36m";
+const EXECUTABLE_COLOR: &str = "\x1b[1;32m";
+const RESET: &str = "\x1b[0m";
+
+/// Whether the output should be colorized. `NO_COLOR` (see <https://no-color.org>) only applies
+/// to the `auto` choice.
+fn use_color(choice: ColorChoice, terminal_output: bool, no_color: Option<OsString>) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => terminal_output && no_color.is_none_or(|no_color| no_color.is_empty()),
+    }
+}
+
+/// Whether the output (and the values that are written to it) should be colorized.
+pub(crate) fn color_output(args: &CliArgs) -> bool {
+    use_color(args.color, args.terminal_output, env::var_os("NO_COLOR"))
+}
+
+/// The `ls` like color of a file, if it should have one.
+fn file_color(file: &FileWrapper) -> Option<&'static str> {
+    let metadata = file.path().symlink_metadata().ok()?;
+    if metadata.is_symlink() {
+        Some(LINK_COLOR)
+    } else if metadata.is_dir() {
+        Some(DIR_COLOR)
+    } else if mode(&metadata).is_some_and(|bits| bits & 0o111 != 0) {
+        Some(EXECUTABLE_COLOR)
+    } else {
+        None
+    }
+}
 
 pub(crate) fn build_output<W: Write + 'static>(
     args: &CliArgs,
@@ -12,7 +54,11 @@ pub(crate) fn build_output<W: Write + 'static>(
 ) -> Result<Box<dyn Walk>, FindItError> {
     let next = make_limit(args);
     match &args.display {
-        None => Ok(Box::new(SimpleOutput { next, writer })),
+        None => Ok(Box::new(SimpleOutput {
+            next,
+            writer,
+            color: color_output(args),
+        })),
         Some(display) => {
             let fields = parse_display(
                 "display",
@@ -32,11 +78,17 @@ pub(crate) fn build_output<W: Write + 'static>(
 struct SimpleOutput<W: Write> {
     next: Option<Box<dyn Walk>>,
     writer: W,
+    color: bool,
 }
 
 impl<W: Write> Walk for SimpleOutput<W> {
     fn step(&mut self, file: &FileWrapper) {
-        writeln!(&mut self.writer, "{file}").ok();
+        match file_color(file).filter(|_| self.color) {
+            Some(color) => writeln!(&mut self.writer, "{color}{file}{RESET}").ok(),
+            None => writeln!(&mut self.writer, "{file}").ok(),
+        };
+        // Flush every line, so the results can be piped while the search is still running.
+        self.writer.flush().ok();
         if let Some(next) = self.next.as_deref_mut() {
             next.step(file);
         }
@@ -83,6 +135,7 @@ impl<W: Write> Walk for ComplexOutput<W> {
             f.write(&mut self.writer, file).ok();
         }
         writeln!(&mut self.writer).ok();
+        self.writer.flush().ok();
         if let Some(next) = self.next.as_deref_mut() {
             next.step(file);
         }
@@ -146,10 +199,61 @@ fn parse_display(
 
 #[cfg(test)]
 mod tests {
+    use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
     use clap::Parser;
 
     use super::*;
 
+    /// A writer that only keeps what was flushed.
+    #[derive(Default, Clone)]
+    struct FlushedWriter {
+        pending: Rc<RefCell<Vec<u8>>>,
+        flushed: Rc<RefCell<Vec<u8>>>,
+    }
+    impl Write for FlushedWriter {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+            self.pending.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> Result<(), IoError> {
+            let pending = self.pending.take();
+            self.flushed.borrow_mut().extend(pending);
+            Ok(())
+        }
+    }
+    impl FlushedWriter {
+        fn flushed(&self) -> String {
+            String::from_utf8_lossy(&self.flushed.borrow()).to_string()
+        }
+    }
+
+    #[test]
+    fn simple_output_flushes_every_line() -> Result<(), FindItError> {
+        let args = CliArgs::parse_from(vec!["-"]);
+        let writer = FlushedWriter::default();
+        let mut output = build_output(&args, writer.clone())?;
+
+        output.step(&FileWrapper::new(PathBuf::from("one.txt"), 1));
+        assert_eq!(writer.flushed(), "one.txt\n");
+        output.step(&FileWrapper::new(PathBuf::from("two.txt"), 1));
+        assert_eq!(writer.flushed(), "one.txt\ntwo.txt\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn complex_output_flushes_every_line() -> Result<(), FindItError> {
+        let args = CliArgs::parse_from(vec!["-", "-d", "File: `name`"]);
+        let writer = FlushedWriter::default();
+        let mut output = build_output(&args, writer.clone())?;
+
+        output.step(&FileWrapper::new(PathBuf::from("one.txt"), 1));
+        assert_eq!(writer.flushed(), "File: one.txt\n");
+
+        Ok(())
+    }
+
     #[test]
     fn empty_display_string() {
         let args = CliArgs::parse_from(vec!["-", "-d", ""]);
@@ -185,4 +289,66 @@ mod tests {
 
         assert!(err.is_some())
     }
+
+    #[test]
+    fn use_color_choices() {
+        assert!(use_color(ColorChoice::Always, false, Some("1".into())));
+        assert!(!use_color(ColorChoice::Never, true, None));
+        assert!(use_color(ColorChoice::Auto, true, None));
+        assert!(use_color(ColorChoice::Auto, true, Some("".into())));
+        assert!(!use_color(ColorChoice::Auto, true, Some("1".into())));
+        assert!(!use_color(ColorChoice::Auto, false, None));
+    }
+
+    fn output_of_dir(args: CliArgs) -> Result<String, FindItError> {
+        let dir = tempfile::tempdir()?;
+        let writer = FlushedWriter::default();
+        let mut output = build_output(&args, writer.clone())?;
+
+        output.step(&FileWrapper::new(dir.path().to_path_buf(), 1));
+
+        Ok(writer
+            .flushed()
+            .replace(dir.path().to_str().unwrap_or_default(), "dir"))
+    }
+
+    #[test]
+    fn no_color_codes_when_not_a_terminal_or_never() -> Result<(), FindItError> {
+        let args = CliArgs::parse_from(vec!["-"]);
+        assert_eq!(output_of_dir(args)?, "dir\n");
+
+        let args = CliArgs::parse_from(vec!["-", "--color=auto"]);
+        assert_eq!(output_of_dir(args)?, "dir\n");
+
+        let args = CliArgs::parse_from(vec!["-", "--color=never"]).with_terminal_output(true);
+        assert_eq!(output_of_dir(args)?, "dir\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn color_codes_when_always() -> Result<(), FindItError> {
+        let args = CliArgs::parse_from(vec!["-", "--color"]);
+        assert_eq!(output_of_dir(args)?, "\x1b[1;34mdir\x1b[0m\n");
+
+        let args = CliArgs::parse_from(vec!["-", "--color=always"]);
+        assert_eq!(output_of_dir(args)?, "\x1b[1;34mdir\x1b[0m\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn plain_files_are_not_colored() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("file.txt");
+        std::fs::write(&path, "text")?;
+        let args = CliArgs::parse_from(vec!["-", "--color=always"]);
+        let writer = FlushedWriter::default();
+        let mut output = build_output(&args, writer.clone())?;
+
+        output.step(&FileWrapper::new(path.clone(), 1));
+
+        assert_eq!(writer.flushed(), format!("{}\n", path.display()));
+        Ok(())
+    }
 }
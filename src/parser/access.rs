@@ -7,32 +7,90 @@ use crate::parser::{
     tokens::Token,
 };
 
+/// All the names of the accessors (with their aliases), used both to parse an accessor and to
+/// suggest a name for an unknown word.
+const ACCESSES: &[(&str, Access)] = &[
+    ("PARENT", Access::Parent),
+    ("NAME", Access::Name),
+    ("STEM", Access::Stem),
+    ("PATH", Access::Path),
+    ("EXTENSION", Access::Extension),
+    ("CONTENT", Access::Content),
+    ("CONTENT_BYTES", Access::ContentBytes),
+    ("CONTENTBYTES", Access::ContentBytes),
+    ("BYTES", Access::ContentBytes),
+    ("DEPTH", Access::Depth),
+    ("SIZE", Access::Size),
+    ("COUNT", Access::Count),
+    ("CHILD_COUNT", Access::ChildCount),
+    ("CHILDCOUNT", Access::ChildCount),
+    ("TOTAL_SIZE", Access::TotalSize),
+    ("TOTALSIZE", Access::TotalSize),
+    ("CREATED", Access::Created),
+    ("AGE", Access::Age),
+    ("MODIFIED", Access::Modified),
+    ("EXISTS", Access::Exists),
+    ("OWNER", Access::Owner),
+    ("GROUP", Access::Group),
+    ("PERMISSIONS", Access::Permissions),
+    ("PERMISSION", Access::Permissions),
+    ("OWNER_READ", Access::OwnerRead),
+    ("OWNERREAD", Access::OwnerRead),
+    ("OWNER_WRITE", Access::OwnerWrite),
+    ("OWNERWRITE", Access::OwnerWrite),
+    ("OWNER_EXECUTE", Access::OwnerExecute),
+    ("OWNEREXECUTE", Access::OwnerExecute),
+    ("GROUP_READ", Access::GroupRead),
+    ("GROUPREAD", Access::GroupRead),
+    ("GROUP_WRITE", Access::GroupWrite),
+    ("GROUPWRITE", Access::GroupWrite),
+    ("GROUP_EXECUTE", Access::GroupExecute),
+    ("GROUPEXECUTE", Access::GroupExecute),
+    ("OTHERS_READ", Access::OthersRead),
+    ("OTHERSREAD", Access::OthersRead),
+    ("OTHERS_WRITE", Access::OthersWrite),
+    ("OTHERSWRITE", Access::OthersWrite),
+    ("OTHERS_EXECUTE", Access::OthersExecute),
+    ("OTHERSEXECUTE", Access::OthersExecute),
+    ("SETUID", Access::Setuid),
+    ("SUID", Access::Setuid),
+    ("SETGID", Access::Setgid),
+    ("SGID", Access::Setgid),
+    ("STICKY", Access::Sticky),
+    ("ABSOLUTE", Access::Absolute),
+    ("IS_ABSOLUTE", Access::IsAbsolute),
+    ("ISABSOLUTE", Access::IsAbsolute),
+    ("IS_RELATIVE", Access::IsRelative),
+    ("ISRELATIVE", Access::IsRelative),
+    ("FILES", Access::Files),
+    ("FILE_LIST", Access::FileList),
+    ("FILELIST", Access::FileList),
+    ("DIRS", Access::Dirs),
+    ("DIRECTORIES", Access::Dirs),
+    ("IS_EMPTY_DIR", Access::IsEmptyDir),
+    ("ISEMPTYDIR", Access::IsEmptyDir),
+    ("HIDDEN", Access::Hidden),
+    ("IS_HIDDEN", Access::Hidden),
+    ("ISHIDDEN", Access::Hidden),
+    ("ME", Access::Me),
+    ("SELF", Access::Me),
+    ("THIS", Access::Me),
+];
+
 impl Access {
     pub(super) fn from_str(name: &str) -> Option<Self> {
-        match name {
-            "PARENT" => Some(Access::Parent),
-            "NAME" => Some(Access::Name),
-            "STEM" => Some(Access::Stem),
-            "PATH" => Some(Access::Path),
-            "EXTENSION" => Some(Access::Extension),
-            "CONTENT" => Some(Access::Content),
-            "DEPTH" => Some(Access::Depth),
-            "SIZE" => Some(Access::Size),
-            "COUNT" => Some(Access::Count),
-            "CREATED" => Some(Access::Created),
-            "MODIFIED" => Some(Access::Modified),
-            "EXISTS" => Some(Access::Exists),
-            "OWNER" => Some(Access::Owner),
-            "GROUP" => Some(Access::Group),
-            "PERMISSIONS" | "PERMISSION" => Some(Access::Permissions),
-            "ABSOLUTE" => Some(Access::Absolute),
-            "FILES" => Some(Access::Files),
-            "ME" | "SELF" | "THIS" => Some(Access::Me),
-            _ => None,
-        }
+        ACCESSES
+            .iter()
+            .find(|(access_name, _)| *access_name == name)
+            .map(|(_, access)| *access)
     }
 }
 
+/// All the names of the accessors, used to suggest a name for an unknown word.
+pub(super) fn access_names() -> impl Iterator<Item = &'static &'static str> {
+    ACCESSES.iter().map(|(name, _)| name)
+}
+
 pub(super) fn read_access(
     access: Access,
     lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
@@ -61,13 +119,24 @@ pub(super) fn read_access(
 #[cfg(test)]
 mod tests {
     use crate::parser::{
+        access::access_names,
         ast::{access::Access, expression::Expression},
         parse_expression,
-        parser_error::ParserError,
+        parser_error::ContextualParserError,
     };
 
     #[test]
-    fn test_access_without_brackets() -> Result<(), ParserError> {
+    fn all_access_names_are_accessors() {
+        for name in access_names() {
+            assert!(
+                Access::from_str(name).is_some(),
+                "{name} is not an accessor"
+            );
+        }
+    }
+
+    #[test]
+    fn test_access_without_brackets() -> Result<(), ContextualParserError> {
         let source = "content";
         let expr = parse_expression(source)?;
 
@@ -77,7 +146,7 @@ mod tests {
     }
 
     #[test]
-    fn test_access_with_brackets() -> Result<(), ParserError> {
+    fn test_access_with_brackets() -> Result<(), ContextualParserError> {
         let source = "content()";
         let expr = parse_expression(source)?;
 
@@ -87,7 +156,7 @@ mod tests {
     }
 
     #[test]
-    fn test_access_with_bracket_non_close() -> Result<(), ParserError> {
+    fn test_access_with_bracket_non_close() -> Result<(), ContextualParserError> {
         let source = "content( ";
         let err = parse_expression(source).err();
 
@@ -97,7 +166,7 @@ mod tests {
     }
 
     #[test]
-    fn test_access_with_bracket_unexpected() -> Result<(), ParserError> {
+    fn test_access_with_bracket_unexpected() -> Result<(), ContextualParserError> {
         let source = "content(12)";
         let err = parse_expression(source).err();
 
@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq, Clone, Copy)]
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum Access {
     Parent,
     Name,
@@ -8,10 +10,14 @@ pub(crate) enum Access {
     Me,
     Extension,
     Content,
+    ContentBytes,
     Depth,
     Size,
     Count,
+    ChildCount,
+    TotalSize,
     Created,
+    Age,
     Modified,
     Exists,
     IsDir,
@@ -20,8 +26,26 @@ pub(crate) enum Access {
     IsNotDir,
     IsNotFile,
     IsNotLink,
+    IsAbsolute,
+    IsRelative,
+    IsEmptyDir,
+    Hidden,
     Owner,
     Group,
     Permissions,
+    OwnerRead,
+    OwnerWrite,
+    OwnerExecute,
+    GroupRead,
+    GroupWrite,
+    GroupExecute,
+    OthersRead,
+    OthersWrite,
+    OthersExecute,
+    Setuid,
+    Setgid,
+    Sticky,
     Files,
+    FileList,
+    Dirs,
 }
@@ -1,16 +1,20 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct As {
     pub(crate) expression: Box<Expression>,
     pub(crate) cast_type: CastType,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum CastType {
     Bool,
     String,
     Number,
     Date,
     Path,
+    List,
+    Duration,
 }
@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Between {
     pub(crate) reference: Box<Expression>,
     pub(crate) lower_limit: Box<Expression>,
@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::{ast::expression::Expression, ast::operator::BinaryOperator};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct BinaryExpression {
     pub(crate) left: Box<Expression>,
     pub(crate) operator: BinaryOperator,
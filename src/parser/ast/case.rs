@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct CaseBranch {
     pub(crate) condition: Box<Expression>,
     pub(crate) outcome: Box<Expression>,
@@ -14,7 +16,7 @@ impl CaseBranch {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Case {
     pub(crate) branches: Vec<CaseBranch>,
     pub(crate) default_outcome: Option<Box<Expression>>,
@@ -1,17 +1,19 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Field {
     pub(crate) name: String,
     pub(crate) value: Expression,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct ClassDefinition {
     pub(crate) fields: Vec<Field>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct ClassAccess {
     pub(crate) target: Box<Expression>,
     pub(crate) field: String,
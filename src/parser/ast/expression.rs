@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::{
     parser::ast::{
         access::Access,
@@ -11,6 +13,7 @@ use crate::{
         format::Format,
         function::Function,
         if_expression::If,
+        include::Include,
         is_check::IsCheck,
         list::List,
         methods::MethodInvocation,
@@ -23,7 +26,7 @@ use crate::{
     value::Value,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) enum Expression {
     Literal(Value),
     Binary(BinaryExpression),
@@ -47,4 +50,5 @@ pub(crate) enum Expression {
     MethodInvocation(MethodInvocation),
     ClassDefinition(ClassDefinition),
     ClassAccess(ClassAccess),
+    Include(Include),
 }
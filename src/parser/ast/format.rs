@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Format {
     pub(crate) timestamp: Box<Expression>,
     pub(crate) format: Box<Expression>,
@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::{ast::expression::Expression, ast::function_name::FunctionName};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Function {
     pub(crate) name: FunctionName,
     pub(crate) args: Vec<Expression>,
@@ -1,17 +1,38 @@
-#[derive(Debug, PartialEq, Clone, Copy)]
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum EnvFunctionName {
     Rand,
     Env,
     Coalesce,
+    CoalesceList,
+    NullIf,
     ExecOut,
+    ExecCapture,
+    GitRoot,
+    GitBranch,
+    HomeDir,
+    TempDir,
+    Uuid,
 }
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum TimeFunctionName {
     Now,
+    DayOfYear,
+    IsLeapYear,
+    Duration,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+pub(crate) enum NumberFunctionName {
+    FormatNumber,
+    IsPrime,
+    Fibonacci,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum FunctionName {
     Env(EnvFunctionName),
     Time(TimeFunctionName),
+    Number(NumberFunctionName),
 }
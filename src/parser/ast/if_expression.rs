@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct If {
     pub(crate) condition: Box<Expression>,
     pub(crate) then_branch: Box<Expression>,
@@ -0,0 +1,12 @@
+use std::{path::PathBuf, sync::Arc};
+
+use serde::Serialize;
+
+use crate::parser::ast::expression::Expression;
+
+/// An expression that was read from another file using `INCLUDE "path"`.
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct Include {
+    pub(crate) path: PathBuf,
+    pub(crate) expression: Arc<Expression>,
+}
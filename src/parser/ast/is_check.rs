@@ -1,13 +1,15 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct IsCheck {
     pub(crate) expression: Box<Expression>,
     pub(crate) check_type: IsType,
     pub(crate) negate: bool,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 pub(crate) enum IsType {
     True,
     False,
@@ -1,12 +1,21 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct LambdaFunction {
     pub(crate) parameter: String,
     pub(crate) body: Box<Expression>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct BinaryLambdaFunction {
+    pub(crate) first_parameter: String,
+    pub(crate) second_parameter: String,
+    pub(crate) body: Box<Expression>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) enum Method {
     Length,
     ToUpper,
@@ -19,14 +28,23 @@ pub(crate) enum Method {
     Filter(LambdaFunction),
     Sum,
     Max,
+    Mode,
+    Percentile(Box<Expression>),
+    Variance,
+    StdDev,
     Min,
     Avg,
     Sort,
-    SortBy(LambdaFunction),
+    SortBy(Vec<LambdaFunction>),
     Distinct,
     DistinctBy(LambdaFunction),
+    DistinctCount,
+    DistinctCountBy(LambdaFunction),
     Skip(Box<Expression>),
     Take(Box<Expression>),
+    SkipLast(Box<Expression>),
+    TakeLast(Box<Expression>),
+    SplitAt(Box<Expression>),
     Join(Option<Box<Expression>>),
     Split(Box<Expression>),
     HasPrefix(Box<Expression>),
@@ -35,20 +53,74 @@ pub(crate) enum Method {
     RemoveSuffix(Box<Expression>),
     Lines,
     Words,
+    ShellSplit,
     First,
     Last,
     Contains(Box<Expression>),
+    ContainsMatching(LambdaFunction),
     IndexOf(Box<Expression>),
+    IndicesOf(Box<Expression>),
     FlatMap(LambdaFunction),
     All(LambdaFunction),
     Any(LambdaFunction),
     GroupBy(LambdaFunction),
     Enumerate,
-    Walk,
+    Walk(Option<Box<Expression>>),
+    WalkFiles(Option<Box<Expression>>),
+    WalkDirs(Option<Box<Expression>>),
     Debug(LambdaFunction),
+    IsPalindrome,
+    IsAnagram(Box<Expression>),
+    ZipLongest(Box<Expression>, Box<Expression>),
+    Keys,
+    Values,
+    Entries,
+    HasField(Box<Expression>),
+    Merge(Box<Expression>),
+    MapKeys(LambdaFunction),
+    MapValues(LambdaFunction),
+    Tee(LambdaFunction),
+    SortedDistinct,
+    FormatSize(Option<Box<Expression>>),
+    Age,
+    FormatDate(Box<Expression>),
+    ParseDate(Box<Expression>),
+    AggregateBy(LambdaFunction, LambdaFunction),
+    WithExtension(Box<Expression>),
+    AddExtension(Box<Expression>),
+    Extension,
+    TopN(Box<Expression>, LambdaFunction),
+    Ancestor(Box<Expression>),
+    Xattr(Box<Expression>),
+    Entropy,
+    HeadLines(Box<Expression>),
+    TailLines(Box<Expression>),
+    NthLine(Box<Expression>),
+    FirstLine,
+    Shebang,
+    Grep(Box<Expression>),
+    CountMatches(Box<Expression>),
+    Highlight(Box<Expression>),
+    RegexReplaceAll(Box<Expression>, Box<Expression>),
+    Indent(Box<Expression>),
+    Wrap(Box<Expression>),
+    Dedent,
+    EncodeBase64,
+    DecodeBase64,
+    UrlEncode,
+    UrlDecode,
+    EncodeHex,
+    DecodeHex,
+    JsonGet(Box<Expression>),
+    DepthIn(Box<Expression>),
+    FilterMap(LambdaFunction),
+    Scan(Box<Expression>, BinaryLambdaFunction),
+    ZipWith(Box<Expression>, BinaryLambdaFunction),
+    IfEmpty(Box<Expression>),
+    IfPresent(Box<Expression>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct MethodInvocation {
     pub(crate) target: Option<Box<Expression>>,
     pub(crate) method: Method,
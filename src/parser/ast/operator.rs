@@ -1,20 +1,23 @@
-#[derive(Debug, PartialEq, Clone, Copy)]
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum ArithmeticOperator {
     Plus,
     Minus,
     Multiply,
     Divide,
+    IntegerDivide,
     Module,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum LogicalOperator {
     And,
     Or,
     Xor,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum ComparisonOperator {
     Eq,
     Neq,
@@ -23,20 +26,22 @@ pub(crate) enum ComparisonOperator {
     SmallerThen,
     SmallerThenEq,
 }
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum BitwiseOperator {
     And,
     Or,
     Xor,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
 pub(crate) enum BinaryOperator {
     Arithmetic(ArithmeticOperator),
     Logical(LogicalOperator),
     Comparison(ComparisonOperator),
     BitwiseOperator(BitwiseOperator),
     Matches,
+    Glob,
+    In,
     Of,
     Dot,
 }
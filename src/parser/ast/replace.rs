@@ -1,11 +1,13 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) enum ReplaceWhat {
     Pattern(Box<Expression>),
     String(Box<Expression>),
 }
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct Replace {
     pub(crate) source: Box<Expression>,
     pub(crate) what: ReplaceWhat,
@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::parser::ast::expression::Expression;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub(crate) struct With {
     pub(crate) names: Vec<(String, Box<Expression>)>,
     pub(crate) action: Box<Expression>,
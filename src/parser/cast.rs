@@ -3,6 +3,7 @@ use crate::parser::{
         access::Access,
         as_cast::{As, CastType},
         expression::Expression,
+        function_name::{FunctionName, TimeFunctionName},
     },
     lexer::LexerItem,
     parser_error::ParserError,
@@ -27,6 +28,11 @@ impl TryFrom<LexerItem> for CastType {
             Token::Number => Ok(CastType::Number),
             Token::Date => Ok(CastType::Date),
             Token::String => Ok(CastType::String),
+            Token::List => Ok(CastType::List),
+            // `DURATION` is also the name of a function.
+            Token::FunctionName(FunctionName::Time(TimeFunctionName::Duration)) => {
+                Ok(CastType::Duration)
+            }
             _ => Err(ParserError::UnexpectedToken(value.span)),
         }
     }
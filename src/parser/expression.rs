@@ -22,9 +22,10 @@ use crate::parser::{
     format::build_format,
     function::build_function,
     if_expression::build_if,
+    include::build_include,
     lexer::LexerItem,
     literal_list::build_literal_list,
-    method::build_method,
+    method::{MethodName, build_method},
     parse_date::build_parse_date,
     parser_error::ParserError,
     replace::build_replace,
@@ -44,7 +45,7 @@ pub(super) fn build_expression_with_priority(
     minimum_priority: u8,
     end_condition: fn(Option<&Token>) -> bool,
 ) -> Result<Expression, ParserError> {
-    let mut left = match lex.next() {
+    let left = match lex.next() {
         None => return Err(ParserError::UnexpectedEof),
         Some(item) => match item.token {
             Token::Value(value) => Expression::Literal(value),
@@ -56,6 +57,7 @@ pub(super) fn build_expression_with_priority(
             Token::Format => build_format(lex)?,
             Token::Replace => build_replace(lex)?,
             Token::With => build_with(lex)?,
+            Token::Include => build_include(lex, &item.span)?,
             Token::FunctionName(name) => build_function(name, lex)?,
             Token::ListStart => build_literal_list(lex)?,
             Token::ClassStarts => build_class_definition(lex)?,
@@ -85,6 +87,15 @@ pub(super) fn build_expression_with_priority(
         },
     };
 
+    continue_expression_with_priority(left, lex, minimum_priority, end_condition)
+}
+
+pub(super) fn continue_expression_with_priority(
+    mut left: Expression,
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+    minimum_priority: u8,
+    end_condition: fn(Option<&Token>) -> bool,
+) -> Result<Expression, ParserError> {
     loop {
         let next = lex.peek();
         if end_condition(next.map(|f| &f.token)) {
@@ -93,13 +104,9 @@ pub(super) fn build_expression_with_priority(
 
         let operator = match next {
             None => return Err(ParserError::UnexpectedEof),
-            Some(item) => match &item.token {
-                Token::BinaryOperator(operator) => Operator::Binary(*operator),
-                Token::Is => Operator::PostIs,
-                Token::As => Operator::As,
-                Token::Between => Operator::Between,
-                Token::ClassFieldAccess(name) => Operator::ClassAccess(name.clone()),
-                _ => return Err(ParserError::UnexpectedToken(item.span)),
+            Some(item) => match Operator::from_token(&item.token) {
+                Some(operator) => operator,
+                None => return Err(ParserError::UnexpectedToken(item.span)),
             },
         };
         let priority = operator.priority();
@@ -109,7 +116,11 @@ pub(super) fn build_expression_with_priority(
         match operator {
             Operator::Binary(operator) => {
                 lex.next();
-                let right = build_expression_with_priority(lex, priority, end_condition)?;
+                let right = if operator == BinaryOperator::Dot {
+                    build_dot_right_side(lex, priority, end_condition)?
+                } else {
+                    build_expression_with_priority(lex, priority, end_condition)?
+                };
                 if operator == BinaryOperator::Dot
                     && let Expression::MethodInvocation(m) = right
                 {
@@ -142,6 +153,44 @@ pub(super) fn build_expression_with_priority(
     Ok(left)
 }
 
+/// Builds the right side of a dot. A property that has a method with the same name (like `age`)
+/// is the method when it is called with brackets, so `modified.age()` is the age of the date,
+/// while `age` and `age()` are still the property of the file.
+fn build_dot_right_side(
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+    priority: u8,
+    end_condition: fn(Option<&Token>) -> bool,
+) -> Result<Expression, ParserError> {
+    let Some(LexerItem {
+        token: Token::SimpleAccess(access),
+        ..
+    }) = lex.peek()
+    else {
+        return build_expression_with_priority(lex, priority, end_condition);
+    };
+    let Some(name) = MethodName::of_access(access) else {
+        return build_expression_with_priority(lex, priority, end_condition);
+    };
+    let access = *access;
+    lex.next();
+    let left = if lex
+        .peek()
+        .is_some_and(|next| next.token == Token::OpenBrackets)
+    {
+        Expression::MethodInvocation(MethodInvocation {
+            target: None,
+            method: build_method(&name, lex)?,
+        })
+    } else {
+        Expression::Access(access)
+    };
+    continue_expression_with_priority(left, lex, priority, end_condition)
+}
+
+pub(super) fn is_operator(token: &Token) -> bool {
+    Operator::from_token(token).is_some()
+}
+
 #[derive(Debug)]
 enum Operator {
     Binary(BinaryOperator),
@@ -152,6 +201,16 @@ enum Operator {
 }
 
 impl Operator {
+    fn from_token(token: &Token) -> Option<Self> {
+        match token {
+            Token::BinaryOperator(operator) => Some(Operator::Binary(*operator)),
+            Token::Is => Some(Operator::PostIs),
+            Token::As => Some(Operator::As),
+            Token::Between => Some(Operator::Between),
+            Token::ClassFieldAccess(name) => Some(Operator::ClassAccess(name.clone())),
+            _ => None,
+        }
+    }
     fn priority(&self) -> u8 {
         match self {
             Operator::Binary(BinaryOperator::Of) => 5,
@@ -161,6 +220,8 @@ impl Operator {
             Operator::Binary(BinaryOperator::Logical(LogicalOperator::And)) => 20,
             Operator::Binary(BinaryOperator::Comparison(_)) => 40,
             Operator::Binary(BinaryOperator::Matches) => 40,
+            Operator::Binary(BinaryOperator::Glob) => 40,
+            Operator::Binary(BinaryOperator::In) => 40,
             Operator::As => 40,
             Operator::PostIs => 40,
             Operator::Binary(BinaryOperator::Arithmetic(ArithmeticOperator::Plus)) => 50,
@@ -168,6 +229,7 @@ impl Operator {
             Operator::Binary(BinaryOperator::BitwiseOperator(_)) => 50,
             Operator::Binary(BinaryOperator::Arithmetic(ArithmeticOperator::Multiply)) => 80,
             Operator::Binary(BinaryOperator::Arithmetic(ArithmeticOperator::Divide)) => 80,
+            Operator::Binary(BinaryOperator::Arithmetic(ArithmeticOperator::IntegerDivide)) => 80,
             Operator::Binary(BinaryOperator::Arithmetic(ArithmeticOperator::Module)) => 80,
             Operator::ClassAccess(_) => 100,
             Operator::Binary(BinaryOperator::Dot) => 110,
@@ -264,9 +326,11 @@ mod tests {
                 function::Function,
                 function_name::{EnvFunctionName, FunctionName},
                 if_expression::If,
+                methods::Method,
                 operator::ComparisonOperator,
             },
             parse_expression,
+            parser_error::ContextualParserError,
         },
         value::Value,
     };
@@ -339,7 +403,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_simple_literal() -> Result<(), ParserError> {
+    fn parse_simple_literal() -> Result<(), ContextualParserError> {
         let str = "23004";
         let exp = parse_expression(str)?;
 
@@ -348,7 +412,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_simple_with_white_spaces() -> Result<(), ParserError> {
+    fn parse_simple_with_white_spaces() -> Result<(), ContextualParserError> {
         let str = "  false  ";
         let exp = parse_expression(str)?;
 
@@ -357,7 +421,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_two_binary_operators_with_the_same_priority() -> Result<(), ParserError> {
+    fn parse_two_binary_operators_with_the_same_priority() -> Result<(), ContextualParserError> {
         let str = "1+3+4-10";
         let exp = parse_expression(str)?;
 
@@ -382,7 +446,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_two_binary_operators_with_different_priority() -> Result<(), ParserError> {
+    fn parse_two_binary_operators_with_different_priority() -> Result<(), ContextualParserError> {
         let str = "1+3*4-10";
         let exp = parse_expression(str)?;
 
@@ -431,7 +495,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_brackets_return_the_correct_order() -> Result<(), ParserError> {
+    fn parse_brackets_return_the_correct_order() -> Result<(), ContextualParserError> {
         let str = "(1+3)*(4-10)";
         let exp = parse_expression(str)?;
 
@@ -456,7 +520,7 @@ mod tests {
     }
 
     #[test]
-    fn parse_logical_expression() -> Result<(), ParserError> {
+    fn parse_logical_expression() -> Result<(), ContextualParserError> {
         let str = "10 > 4 OR 12 < 6 XOR NOT 20 = 6 AND true";
         let exp = parse_expression(str)?;
 
@@ -492,7 +556,7 @@ mod tests {
     }
 
     #[test]
-    fn access_with_of_and_is_some() -> Result<(), ParserError> {
+    fn access_with_of_and_is_some() -> Result<(), ContextualParserError> {
         let str = "content of parent is not some";
         let exp = parse_expression(str)?;
 
@@ -509,7 +573,7 @@ mod tests {
     }
 
     #[test]
-    fn if_with_else() -> Result<(), ParserError> {
+    fn if_with_else() -> Result<(), ContextualParserError> {
         let str = "if 10>=20 Then 30 else 40 end";
         let exp = parse_expression(str)?;
 
@@ -530,7 +594,7 @@ mod tests {
     }
 
     #[test]
-    fn if_without_else() -> Result<(), ParserError> {
+    fn if_without_else() -> Result<(), ContextualParserError> {
         let str = "if 10>=20 Then 30 end";
         let exp = parse_expression(str)?;
 
@@ -550,7 +614,7 @@ mod tests {
     }
 
     #[test]
-    fn case_with_else() -> Result<(), ParserError> {
+    fn case_with_else() -> Result<(), ContextualParserError> {
         let str = "case when 10 == 10 then 1 when 20 != 20 then 2 when 30 <> 30 then 3 else 4 end";
         let exp = parse_expression(str)?;
 
@@ -591,7 +655,7 @@ mod tests {
     }
 
     #[test]
-    fn case_without_else() -> Result<(), ParserError> {
+    fn case_without_else() -> Result<(), ContextualParserError> {
         let str = "case when 10 == 10 then 1 when 20 != 20 then 2 when 30 <> 30 then 3 end";
         let exp = parse_expression(str)?;
 
@@ -645,7 +709,7 @@ mod tests {
     }
 
     #[test]
-    fn is_file() -> Result<(), ParserError> {
+    fn is_file() -> Result<(), ContextualParserError> {
         let str = "IS FILE";
         let exp = parse_expression(str)?;
 
@@ -655,7 +719,7 @@ mod tests {
     }
 
     #[test]
-    fn is_not_file() -> Result<(), ParserError> {
+    fn is_not_file() -> Result<(), ContextualParserError> {
         let str = "IS NOT file";
         let exp = parse_expression(str)?;
 
@@ -665,7 +729,7 @@ mod tests {
     }
 
     #[test]
-    fn is_dir() -> Result<(), ParserError> {
+    fn is_dir() -> Result<(), ContextualParserError> {
         let str = "IS dir";
         let exp = parse_expression(str)?;
 
@@ -675,7 +739,7 @@ mod tests {
     }
 
     #[test]
-    fn is_not_dir() -> Result<(), ParserError> {
+    fn is_not_dir() -> Result<(), ContextualParserError> {
         let str = "IS NOT DIR";
         let exp = parse_expression(str)?;
 
@@ -685,7 +749,7 @@ mod tests {
     }
 
     #[test]
-    fn is_link() -> Result<(), ParserError> {
+    fn is_link() -> Result<(), ContextualParserError> {
         let str = "IS Link";
         let exp = parse_expression(str)?;
 
@@ -695,7 +759,7 @@ mod tests {
     }
 
     #[test]
-    fn is_not_link() -> Result<(), ParserError> {
+    fn is_not_link() -> Result<(), ContextualParserError> {
         let str = "IS NOT link";
         let exp = parse_expression(str)?;
 
@@ -785,7 +849,7 @@ mod tests {
     }
 
     #[test]
-    fn is_true() -> Result<(), ParserError> {
+    fn is_true() -> Result<(), ContextualParserError> {
         let str = "true is true";
         let exp = parse_expression(str)?;
 
@@ -795,7 +859,7 @@ mod tests {
     }
 
     #[test]
-    fn is_false() -> Result<(), ParserError> {
+    fn is_false() -> Result<(), ContextualParserError> {
         let str = "true is false";
         let exp = parse_expression(str)?;
 
@@ -805,7 +869,7 @@ mod tests {
     }
 
     #[test]
-    fn is_not_true() -> Result<(), ParserError> {
+    fn is_not_true() -> Result<(), ContextualParserError> {
         let str = "true is NOT true";
         let exp = parse_expression(str)?;
 
@@ -815,7 +879,7 @@ mod tests {
     }
 
     #[test]
-    fn is_not_false() -> Result<(), ParserError> {
+    fn is_not_false() -> Result<(), ContextualParserError> {
         let str = "true is not false";
         let exp = parse_expression(str)?;
 
@@ -825,7 +889,7 @@ mod tests {
     }
 
     #[test]
-    fn is_none() -> Result<(), ParserError> {
+    fn is_none() -> Result<(), ContextualParserError> {
         let str = "true is none";
         let exp = parse_expression(str)?;
 
@@ -835,7 +899,7 @@ mod tests {
     }
 
     #[test]
-    fn test_between() -> Result<(), ParserError> {
+    fn test_between() -> Result<(), ContextualParserError> {
         let str = "20 between 10 and 50";
         let exp = parse_expression(str)?;
 
@@ -845,7 +909,7 @@ mod tests {
     }
 
     #[test]
-    fn test_format() -> Result<(), ParserError> {
+    fn test_format() -> Result<(), ContextualParserError> {
         let str = "format(\"str\" as \"string\")";
         let exp = parse_expression(str)?;
 
@@ -855,7 +919,7 @@ mod tests {
     }
 
     #[test]
-    fn test_function_no_args() -> Result<(), ParserError> {
+    fn test_function_no_args() -> Result<(), ContextualParserError> {
         let str = "random()";
         let exp = parse_expression(str)?;
 
@@ -865,7 +929,7 @@ mod tests {
     }
 
     #[test]
-    fn test_function_multiple_arg() -> Result<(), ParserError> {
+    fn test_function_multiple_arg() -> Result<(), ContextualParserError> {
         let str = "COALESCE(1, 2, 3, 4, 5)";
         let exp = parse_expression(str)?;
 
@@ -881,7 +945,7 @@ mod tests {
     }
 
     #[test]
-    fn test_function_multiple_arg_trailing_comma() -> Result<(), ParserError> {
+    fn test_function_multiple_arg_trailing_comma() -> Result<(), ContextualParserError> {
         let str = "COALESCE(1, 2, 3, 4,)";
         let exp = parse_expression(str)?;
 
@@ -897,7 +961,7 @@ mod tests {
     }
 
     #[test]
-    fn test_spawn_into() -> Result<(), ParserError> {
+    fn test_spawn_into() -> Result<(), ContextualParserError> {
         let str = "SPAWN(\"/bin/bash\" INTO \"/dev/null\")";
         let exp = parse_expression(str)?;
 
@@ -910,7 +974,7 @@ mod tests {
     }
 
     #[test]
-    fn test_spawn_multiple_arguments() -> Result<(), ParserError> {
+    fn test_spawn_multiple_arguments() -> Result<(), ContextualParserError> {
         let str = "SPAWN(\"echo\", 1, 2, 3)";
         let exp = parse_expression(str)?;
 
@@ -927,7 +991,7 @@ mod tests {
     }
 
     #[test]
-    fn test_exec_single_arg() -> Result<(), ParserError> {
+    fn test_exec_single_arg() -> Result<(), ContextualParserError> {
         let str = "execute(\"echo\")";
         let exp = parse_expression(str)?;
 
@@ -937,7 +1001,7 @@ mod tests {
     }
 
     #[test]
-    fn test_exec_multiple_arguments_trailing_comma() -> Result<(), ParserError> {
+    fn test_exec_multiple_arguments_trailing_comma() -> Result<(), ContextualParserError> {
         let str = "exec(\"echo\", 1, 2)";
         let exp = parse_expression(str)?;
 
@@ -947,7 +1011,7 @@ mod tests {
     }
 
     #[test]
-    fn test_exec_multiple_arguments_trailing_comma_into() -> Result<(), ParserError> {
+    fn test_exec_multiple_arguments_trailing_comma_into() -> Result<(), ContextualParserError> {
         let str = "exec(\"echo\", 1, 2, into \"test\")";
         let exp = parse_expression(str)?;
 
@@ -964,7 +1028,7 @@ mod tests {
     }
 
     #[test]
-    fn test_spawn_multiple_arguments_into() -> Result<(), ParserError> {
+    fn test_spawn_multiple_arguments_into() -> Result<(), ContextualParserError> {
         let str = "spawn(\"echo\", 1, 2, 4 into \"test\")";
         let exp = parse_expression(str)?;
 
@@ -979,4 +1043,60 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_called_property_after_dot_is_a_method() -> Result<(), ContextualParserError> {
+        for str in ["\"a.txt\".extension()", "\"a.txt\".extension ()"] {
+            let exp = parse_expression(str)?;
+
+            assert_eq!(
+                exp,
+                Expression::MethodInvocation(MethodInvocation {
+                    target: Some(Box::new(lit_s("a.txt"))),
+                    method: Method::Extension,
+                })
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_property_with_brackets_is_a_property() -> Result<(), ContextualParserError> {
+        for (str, access) in [
+            ("extension()", Access::Extension),
+            ("extension ()", Access::Extension),
+            ("age()", Access::Age),
+            ("size()", Access::Size),
+            ("count()", Access::Count),
+        ] {
+            let exp = parse_expression(str)?;
+
+            assert_eq!(exp, Expression::Access(access));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_property_after_dot_without_brackets_is_a_property() -> Result<(), ContextualParserError>
+    {
+        for (str, access) in [
+            ("parent.extension", Access::Extension),
+            ("parent.size()", Access::Size),
+        ] {
+            let exp = parse_expression(str)?;
+
+            assert_eq!(
+                exp,
+                bin_e(
+                    Expression::Access(Access::Parent),
+                    BinaryOperator::Dot,
+                    Expression::Access(access)
+                )
+            );
+        }
+
+        Ok(())
+    }
 }
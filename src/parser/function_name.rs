@@ -1,16 +1,102 @@
-use crate::parser::ast::function_name::{EnvFunctionName, FunctionName, TimeFunctionName};
+use crate::parser::ast::function_name::{
+    EnvFunctionName, FunctionName, NumberFunctionName, TimeFunctionName,
+};
+
+/// All the names of the functions (with their aliases), used both to parse a function and to
+/// suggest a name for an unknown word.
+const FUNCTIONS: &[(&str, FunctionName)] = &[
+    ("RAND", FunctionName::Env(EnvFunctionName::Rand)),
+    ("RANDOM", FunctionName::Env(EnvFunctionName::Rand)),
+    ("ENVIRONMENT", FunctionName::Env(EnvFunctionName::Env)),
+    ("ENV", FunctionName::Env(EnvFunctionName::Env)),
+    ("UUID", FunctionName::Env(EnvFunctionName::Uuid)),
+    ("NEW_UUID", FunctionName::Env(EnvFunctionName::Uuid)),
+    ("NEWUUID", FunctionName::Env(EnvFunctionName::Uuid)),
+    ("COALESCE", FunctionName::Env(EnvFunctionName::Coalesce)),
+    (
+        "COALESCE_LIST",
+        FunctionName::Env(EnvFunctionName::CoalesceList),
+    ),
+    (
+        "COALESCELIST",
+        FunctionName::Env(EnvFunctionName::CoalesceList),
+    ),
+    ("NULLIF", FunctionName::Env(EnvFunctionName::NullIf)),
+    ("NULL_IF", FunctionName::Env(EnvFunctionName::NullIf)),
+    (
+        "EXECUTE_OUTPUT",
+        FunctionName::Env(EnvFunctionName::ExecOut),
+    ),
+    ("EXECUTEOUTPUT", FunctionName::Env(EnvFunctionName::ExecOut)),
+    ("EXECOUT", FunctionName::Env(EnvFunctionName::ExecOut)),
+    ("EXEC_OUT", FunctionName::Env(EnvFunctionName::ExecOut)),
+    (
+        "EXEC_CAPTURE",
+        FunctionName::Env(EnvFunctionName::ExecCapture),
+    ),
+    (
+        "EXECCAPTURE",
+        FunctionName::Env(EnvFunctionName::ExecCapture),
+    ),
+    ("CAPTURE", FunctionName::Env(EnvFunctionName::ExecCapture)),
+    ("GIT_ROOT", FunctionName::Env(EnvFunctionName::GitRoot)),
+    ("GITROOT", FunctionName::Env(EnvFunctionName::GitRoot)),
+    ("GIT_BRANCH", FunctionName::Env(EnvFunctionName::GitBranch)),
+    ("GITBRANCH", FunctionName::Env(EnvFunctionName::GitBranch)),
+    ("HOME_DIR", FunctionName::Env(EnvFunctionName::HomeDir)),
+    ("HOMEDIR", FunctionName::Env(EnvFunctionName::HomeDir)),
+    ("HOME", FunctionName::Env(EnvFunctionName::HomeDir)),
+    ("TEMP_DIR", FunctionName::Env(EnvFunctionName::TempDir)),
+    ("TEMPDIR", FunctionName::Env(EnvFunctionName::TempDir)),
+    ("TMPDIR", FunctionName::Env(EnvFunctionName::TempDir)),
+    ("TMP_DIR", FunctionName::Env(EnvFunctionName::TempDir)),
+    ("NOW", FunctionName::Time(TimeFunctionName::Now)),
+    (
+        "DAY_OF_YEAR",
+        FunctionName::Time(TimeFunctionName::DayOfYear),
+    ),
+    ("DAYOFYEAR", FunctionName::Time(TimeFunctionName::DayOfYear)),
+    ("YEARDAY", FunctionName::Time(TimeFunctionName::DayOfYear)),
+    ("YEAR_DAY", FunctionName::Time(TimeFunctionName::DayOfYear)),
+    (
+        "IS_LEAP_YEAR",
+        FunctionName::Time(TimeFunctionName::IsLeapYear),
+    ),
+    (
+        "ISLEAPYEAR",
+        FunctionName::Time(TimeFunctionName::IsLeapYear),
+    ),
+    ("DURATION", FunctionName::Time(TimeFunctionName::Duration)),
+    (
+        "FORMAT_NUMBER",
+        FunctionName::Number(NumberFunctionName::FormatNumber),
+    ),
+    (
+        "FORMATNUMBER",
+        FunctionName::Number(NumberFunctionName::FormatNumber),
+    ),
+    (
+        "IS_PRIME",
+        FunctionName::Number(NumberFunctionName::IsPrime),
+    ),
+    ("ISPRIME", FunctionName::Number(NumberFunctionName::IsPrime)),
+    (
+        "FIBONACCI",
+        FunctionName::Number(NumberFunctionName::Fibonacci),
+    ),
+    ("FIB", FunctionName::Number(NumberFunctionName::Fibonacci)),
+];
 
 impl FunctionName {
     pub(super) fn from_str(name: &str) -> Option<Self> {
-        match name {
-            "RAND" | "RANDOM" => Some(FunctionName::Env(EnvFunctionName::Rand)),
-            "ENVIRONMENT" | "ENV" => Some(FunctionName::Env(EnvFunctionName::Env)),
-            "COALESCE" => Some(FunctionName::Env(EnvFunctionName::Coalesce)),
-            "EXECUTE_OUTPUT" | "EXECUTEOUTPUT" | "EXECOUT" | "EXEC_OUT" => {
-                Some(FunctionName::Env(EnvFunctionName::ExecOut))
-            }
-            "NOW" => Some(FunctionName::Time(TimeFunctionName::Now)),
-            _ => None,
-        }
+        FUNCTIONS
+            .iter()
+            .find(|(function_name, _)| *function_name == name)
+            .map(|(_, function)| *function)
     }
 }
+
+/// All the names of the functions, used to suggest a name for an unknown word.
+pub(super) fn function_names() -> impl Iterator<Item = &'static &'static str> {
+    FUNCTIONS.iter().map(|(name, _)| name)
+}
@@ -0,0 +1,273 @@
+use std::{
+    cell::{Cell, RefCell},
+    fs,
+    iter::Peekable,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    cache::expression_cache,
+    parser::{
+        ast::{expression::Expression, include::Include},
+        lexer::LexerItem,
+        parser_error::ParserError,
+        span::Span,
+        tokens::Token,
+    },
+    value::Value,
+};
+
+/// How deep can files include other files.
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+thread_local! {
+    /// The files that are parsed right now, the last one is the innermost file.
+    static INCLUDED_FILES: RefCell<Vec<PathBuf>> = const { RefCell::new(vec![]) };
+    /// The number of files that were included so far.
+    static INCLUDES_READ: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The number of files that were included so far (by this thread). An expression that included
+/// files depends on their content (and on the file it was read from), so it can not be cached by
+/// its source.
+pub(crate) fn includes_read() -> usize {
+    INCLUDES_READ.get()
+}
+
+/// Run the parsing of an expression that was read from a file, so relative paths in `INCLUDE`
+/// will be resolved relative to that file.
+pub(crate) fn with_expression_file<T>(path: &Path, parse: impl FnOnce() -> T) -> T {
+    INCLUDED_FILES.with_borrow_mut(|files| files.push(path.to_path_buf()));
+    let result = parse();
+    INCLUDED_FILES.with_borrow_mut(|files| files.pop());
+    result
+}
+
+fn resolve(path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    INCLUDED_FILES
+        .with_borrow(|files| {
+            files
+                .last()
+                .and_then(|file| file.parent())
+                .map(Path::to_path_buf)
+        })
+        .map_or_else(|| path.to_path_buf(), |parent| parent.join(path))
+}
+
+fn include(path: &Path) -> Result<Include, String> {
+    INCLUDES_READ.set(INCLUDES_READ.get() + 1);
+    let path = resolve(path);
+    let canonical = fs::canonicalize(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let (depth, cycle) = INCLUDED_FILES.with_borrow(|files| {
+        let cycle = files
+            .iter()
+            .any(|file| fs::canonicalize(file).is_ok_and(|file| file == canonical));
+        (files.len(), cycle)
+    });
+    if cycle {
+        return Err(format!("{}: the file includes itself", path.display()));
+    }
+    if depth >= MAX_INCLUDE_DEPTH {
+        return Err(format!(
+            "{}: more than {MAX_INCLUDE_DEPTH} nested includes",
+            path.display()
+        ));
+    }
+    let source = fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let expression = with_expression_file(&path, || expression_cache().get_or_parse(&source))
+        .map_err(|err| match err.into_error() {
+            // The nested include already explains what failed.
+            ParserError::IncludeFailed(_, message) => message,
+            err => format!("{}: {err}", path.display()),
+        })?;
+    Ok(Include { path, expression })
+}
+
+pub(super) fn build_include(
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+    span: &Span,
+) -> Result<Expression, ParserError> {
+    let Some(item) = lex.next() else {
+        return Err(ParserError::UnexpectedEof);
+    };
+    let path = match item.token {
+        Token::Value(Value::String(path)) => PathBuf::from(path),
+        Token::Value(Value::Path(path)) => path,
+        _ => return Err(ParserError::UnexpectedToken(item.span)),
+    };
+    let span = Span {
+        start: span.start,
+        end: item.span.end,
+    };
+    include(&path)
+        .map(Expression::Include)
+        .map_err(|err| ParserError::IncludeFailed(span, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        errors::FindItError,
+        evaluators::expr::read_expr,
+        file_wrapper::FileWrapper,
+        parser::{include::with_expression_file, parse_expression},
+        value::Value,
+    };
+
+    fn eval(expr: &str) -> Result<Value, FindItError> {
+        let file = &FileWrapper::new(PathBuf::from("/no/such/file.rs"), 1);
+        Ok(read_expr(expr)?.eval(file))
+    }
+
+    #[test]
+    fn include_absolute_path() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("rust.expr");
+        fs::write(&path, "extension = \"rs\" # Only rust files")?;
+
+        assert_eq!(
+            eval(&format!("INCLUDE \"{}\" AND true", path.display()))?,
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval(&format!("NOT include @{}", path.display()))?,
+            Value::Bool(false)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn include_relative_to_the_including_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::create_dir(dir.path().join("lib"))?;
+        fs::write(dir.path().join("lib/ten.expr"), "10")?;
+        fs::write(
+            dir.path().join("lib/twenty.expr"),
+            "INCLUDE \"ten.expr\" * 2",
+        )?;
+        let main = dir.path().join("main.expr");
+        let source = "INCLUDE \"lib/twenty.expr\" + 1";
+
+        let expr = with_expression_file(&main, || read_expr(source))?;
+
+        let file = &FileWrapper::new(PathBuf::from("/no/such/file.rs"), 1);
+        assert_eq!(expr.eval(file), Value::Number(21));
+        Ok(())
+    }
+
+    #[test]
+    fn include_same_source_from_different_directories() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        for (sub_dir, name) in [("a", "x.expr"), ("b", "y.expr")] {
+            fs::create_dir(dir.path().join(sub_dir))?;
+            fs::write(
+                dir.path().join(sub_dir).join("common.expr"),
+                format!("\"from-{sub_dir}\""),
+            )?;
+            fs::write(
+                dir.path().join(sub_dir).join(name),
+                "INCLUDE \"common.expr\"",
+            )?;
+        }
+        let main = dir.path().join("main.expr");
+        let source = "INCLUDE \"a/x.expr\" + \" \" + INCLUDE \"b/y.expr\"";
+
+        let expr = with_expression_file(&main, || read_expr(source))?;
+
+        let file = &FileWrapper::new(PathBuf::from("/no/such/file.rs"), 1);
+        assert_eq!(expr.eval(file), Value::String("from-a from-b".into()));
+        Ok(())
+    }
+
+    #[test]
+    fn include_read_the_file_again() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("number.expr");
+        let source = format!("INCLUDE \"{}\"", path.display());
+
+        fs::write(&path, "1")?;
+        assert_eq!(eval(&source)?, Value::Number(1));
+        fs::write(&path, "2")?;
+        assert_eq!(eval(&source)?, Value::Number(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn include_itself_report_the_failure_once() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("self.expr");
+        fs::write(&path, "INCLUDE \"self.expr\"")?;
+
+        let err = parse_expression(&format!("INCLUDE \"{}\"", path.display()))
+            .err()
+            .unwrap()
+            .to_string();
+
+        assert_eq!(err.matches("Can not include").count(), 1, "{err}");
+        assert_eq!(
+            err.lines().filter(|line| line.contains('^')).count(),
+            1,
+            "{err}"
+        );
+        assert!(err.contains("the file includes itself"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn include_cycle_fails() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("one.expr"), "INCLUDE \"two.expr\"")?;
+        fs::write(dir.path().join("two.expr"), "INCLUDE \"one.expr\"")?;
+        let source = format!("INCLUDE \"{}\"", dir.path().join("one.expr").display());
+
+        let err = parse_expression(&source).err().unwrap().to_string();
+
+        assert!(err.contains("the file includes itself"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn include_missing_file_fails() {
+        let err = parse_expression("size > 2 AND INCLUDE \"/no/such/file.expr\"")
+            .err()
+            .unwrap()
+            .to_string();
+
+        assert!(
+            err.starts_with("Can not include /no/such/file.expr"),
+            "{err}"
+        );
+        assert!(
+            err.ends_with("  size > 2 AND INCLUDE \"/no/such/file.expr\"\n               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn include_bad_expression_fails() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("bad.expr");
+        fs::write(&path, "size > > 3")?;
+
+        let err = parse_expression(&format!("INCLUDE \"{}\"", path.display()))
+            .err()
+            .unwrap()
+            .to_string();
+
+        assert!(err.contains("bad.expr: Unexpected token at"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn include_without_a_path_fails() {
+        assert!(parse_expression("INCLUDE").is_err());
+        assert!(parse_expression("INCLUDE size").is_err());
+    }
+}
@@ -28,7 +28,7 @@ impl TryFrom<LexerItem> for IsType {
             Token::Value(Value::Bool(true)) => Ok(IsType::True),
             Token::Value(Value::Bool(false)) => Ok(IsType::False),
             Token::Some => Ok(IsType::Some),
-            Token::None => Ok(IsType::None),
+            Token::None | Token::Value(Value::Empty) => Ok(IsType::None),
             _ => Err(ParserError::UnexpectedToken(value.span)),
         }
     }
@@ -13,6 +13,11 @@ impl Display for LexerError {
     }
 }
 impl Error for LexerError {}
+impl LexerError {
+    pub(crate) fn span(&self) -> Span {
+        self.span
+    }
+}
 
 #[derive(Debug, PartialEq, Clone)]
 pub(super) struct LexerItem {
@@ -1,9 +1,11 @@
 use crate::parser::{
     ast::{
+        access::Access,
+        binding::Binding,
         expression::Expression,
-        methods::{LambdaFunction, Method},
+        methods::{BinaryLambdaFunction, LambdaFunction, Method},
     },
-    expression::build_expression_with_priority,
+    expression::{build_expression_with_priority, continue_expression_with_priority, is_operator},
     lexer::LexerItem,
     parser_error::ParserError,
     tokens::Token,
@@ -23,81 +25,344 @@ pub(super) enum MethodName {
     Filter,
     Sum,
     Max,
+    Mode,
+    Percentile,
+    Variance,
+    StdDev,
     Min,
     Avg,
     Sort,
     SortBy,
     Distinct,
     DistinctBy,
+    DistinctCount,
+    DistinctCountBy,
     Skip,
     Take,
+    SkipLast,
+    TakeLast,
+    SplitAt,
     Join,
     Split,
     Lines,
     Words,
+    ShellSplit,
     First,
     Last,
     Contains,
     IndexOf,
+    IndicesOf,
     FlatMap,
     All,
     Any,
     GroupBy,
     Enumerate,
     Walk,
+    WalkFiles,
+    WalkDirs,
     HasPrefix,
     HasSuffix,
     RemovePrefix,
     RemoveSuffix,
     Debug,
+    IsPalindrome,
+    IsAnagram,
+    ZipLongest,
+    Keys,
+    Values,
+    Entries,
+    HasField,
+    Merge,
+    MapKeys,
+    MapValues,
+    Tee,
+    SortedDistinct,
+    FormatSize,
+    Age,
+    FormatDate,
+    ParseDate,
+    AggregateBy,
+    WithExtension,
+    AddExtension,
+    Extension,
+    TopN,
+    Ancestor,
+    Xattr,
+    Entropy,
+    HeadLines,
+    TailLines,
+    NthLine,
+    FirstLine,
+    Shebang,
+    Grep,
+    CountMatches,
+    Highlight,
+    RegexReplaceAll,
+    Indent,
+    Wrap,
+    Dedent,
+    EncodeBase64,
+    DecodeBase64,
+    UrlEncode,
+    UrlDecode,
+    EncodeHex,
+    DecodeHex,
+    JsonGet,
+    DepthIn,
+    FilterMap,
+    Scan,
+    ZipWith,
+    IfEmpty,
+    IfPresent,
+}
+
+/// All the names of the methods (with their aliases), used both to parse a method and to suggest a
+/// name for an unknown word.
+const METHODS: &[(&str, MethodName)] = &[
+    ("LENGTH", MethodName::Length),
+    ("LEN", MethodName::Length),
+    ("COUNT", MethodName::Length),
+    ("SIZE", MethodName::Length),
+    ("TO_UPPER", MethodName::ToUpper),
+    ("UPPER", MethodName::ToUpper),
+    ("UPPERCASE", MethodName::ToUpper),
+    ("TOUPPER", MethodName::ToUpper),
+    ("TO_LOWER", MethodName::ToLower),
+    ("LOWER", MethodName::ToLower),
+    ("LOWERCASE", MethodName::ToLower),
+    ("TOLOWER", MethodName::ToLower),
+    ("TRIM", MethodName::Trim),
+    ("TRIM_HEAD", MethodName::TrimHead),
+    ("TRIMHEAD", MethodName::TrimHead),
+    ("TRIM_TAIL", MethodName::TrimTail),
+    ("TRIMTAIL", MethodName::TrimTail),
+    ("REVERSE", MethodName::Reverse),
+    ("MAP", MethodName::Map),
+    ("FILTER", MethodName::Filter),
+    ("SUM", MethodName::Sum),
+    ("MAX", MethodName::Max),
+    ("MAXIMUM", MethodName::Max),
+    ("MODE", MethodName::Mode),
+    ("MOST_COMMON", MethodName::Mode),
+    ("MOSTCOMMON", MethodName::Mode),
+    ("PERCENTILE", MethodName::Percentile),
+    ("VARIANCE", MethodName::Variance),
+    ("VAR", MethodName::Variance),
+    ("STDDEV", MethodName::StdDev),
+    ("STD_DEV", MethodName::StdDev),
+    ("STANDARD_DEVIATION", MethodName::StdDev),
+    ("STANDARDDEVIATION", MethodName::StdDev),
+    ("MIN", MethodName::Min),
+    ("MINIMUM", MethodName::Min),
+    ("AVG", MethodName::Avg),
+    ("AVERAGE", MethodName::Avg),
+    ("SORT", MethodName::Sort),
+    ("ORDER", MethodName::Sort),
+    ("SORT_BY", MethodName::SortBy),
+    ("ORDER_BY", MethodName::SortBy),
+    ("SORTBY", MethodName::SortBy),
+    ("ORDERBY", MethodName::SortBy),
+    ("SKIP", MethodName::Skip),
+    ("TAKE", MethodName::Take),
+    ("SKIP_LAST", MethodName::SkipLast),
+    ("SKIPLAST", MethodName::SkipLast),
+    ("TAKE_LAST", MethodName::TakeLast),
+    ("TAKELAST", MethodName::TakeLast),
+    ("SPLIT_AT", MethodName::SplitAt),
+    ("SPLITAT", MethodName::SplitAt),
+    ("JOIN", MethodName::Join),
+    ("SPLIT", MethodName::Split),
+    ("LINES", MethodName::Lines),
+    ("WORDS", MethodName::Words),
+    ("TO_ARGS", MethodName::ShellSplit),
+    ("TOARGS", MethodName::ShellSplit),
+    ("SHELL_SPLIT", MethodName::ShellSplit),
+    ("SHELLSPLIT", MethodName::ShellSplit),
+    ("FIRST", MethodName::First),
+    ("LAST", MethodName::Last),
+    ("CONTAINS", MethodName::Contains),
+    ("INDEXOF", MethodName::IndexOf),
+    ("INDEX_OF", MethodName::IndexOf),
+    ("INDICES_OF", MethodName::IndicesOf),
+    ("INDICESOF", MethodName::IndicesOf),
+    ("ALL_INDICES_OF", MethodName::IndicesOf),
+    ("ALLINDICESOF", MethodName::IndicesOf),
+    ("FLATMAP", MethodName::FlatMap),
+    ("FLAT_MAP", MethodName::FlatMap),
+    ("ALL", MethodName::All),
+    ("ANY", MethodName::Any),
+    ("DISTINCT", MethodName::Distinct),
+    ("UNIQUE", MethodName::Distinct),
+    ("DISTINCT_BY", MethodName::DistinctBy),
+    ("DISTINCTBY", MethodName::DistinctBy),
+    ("UNIQUE_BY", MethodName::DistinctBy),
+    ("UNIQUEBY", MethodName::DistinctBy),
+    ("DISTINCT_COUNT", MethodName::DistinctCount),
+    ("DISTINCTCOUNT", MethodName::DistinctCount),
+    ("UNIQUE_COUNT", MethodName::DistinctCount),
+    ("UNIQUECOUNT", MethodName::DistinctCount),
+    ("DISTINCT_COUNT_BY", MethodName::DistinctCountBy),
+    ("DISTINCTCOUNTBY", MethodName::DistinctCountBy),
+    ("UNIQUE_COUNT_BY", MethodName::DistinctCountBy),
+    ("UNIQUECOUNTBY", MethodName::DistinctCountBy),
+    ("GROUPBY", MethodName::GroupBy),
+    ("GROUP_BY", MethodName::GroupBy),
+    ("ENUMERATE", MethodName::Enumerate),
+    ("WALK", MethodName::Walk),
+    ("WALK_FILES", MethodName::WalkFiles),
+    ("WALKFILES", MethodName::WalkFiles),
+    ("DESCENDANTS_FILES", MethodName::WalkFiles),
+    ("DESCENDANTSFILES", MethodName::WalkFiles),
+    ("WALK_DIRS", MethodName::WalkDirs),
+    ("WALKDIRS", MethodName::WalkDirs),
+    ("DESCENDANTS_DIRS", MethodName::WalkDirs),
+    ("DESCENDANTSDIRS", MethodName::WalkDirs),
+    ("HAS_PREFIX", MethodName::HasPrefix),
+    ("HASPREFIX", MethodName::HasPrefix),
+    ("STARTS_WITH", MethodName::HasPrefix),
+    ("STARTSWITH", MethodName::HasPrefix),
+    ("HAS_SUFFIX", MethodName::HasSuffix),
+    ("HASSUFFIX", MethodName::HasSuffix),
+    ("ENDS_WITH", MethodName::HasSuffix),
+    ("ENDSWITH", MethodName::HasSuffix),
+    ("REMOVE_PREFIX", MethodName::RemovePrefix),
+    ("REMOVEPREFIX", MethodName::RemovePrefix),
+    ("REMOVE_SUFFIX", MethodName::RemoveSuffix),
+    ("REMOVESUFFIX", MethodName::RemoveSuffix),
+    ("DEBUG", MethodName::Debug),
+    ("DBG", MethodName::Debug),
+    ("IS_PALINDROME", MethodName::IsPalindrome),
+    ("ISPALINDROME", MethodName::IsPalindrome),
+    ("IS_ANAGRAM", MethodName::IsAnagram),
+    ("ISANAGRAM", MethodName::IsAnagram),
+    ("ZIP_LONGEST", MethodName::ZipLongest),
+    ("ZIPLONGEST", MethodName::ZipLongest),
+    ("KEYS", MethodName::Keys),
+    ("VALUES", MethodName::Values),
+    ("ENTRIES", MethodName::Entries),
+    ("HAS_FIELD", MethodName::HasField),
+    ("HASFIELD", MethodName::HasField),
+    ("MERGE", MethodName::Merge),
+    ("MAP_KEYS", MethodName::MapKeys),
+    ("MAPKEYS", MethodName::MapKeys),
+    ("MAP_VALUES", MethodName::MapValues),
+    ("MAPVALUES", MethodName::MapValues),
+    ("TEE", MethodName::Tee),
+    ("SORTED_DISTINCT", MethodName::SortedDistinct),
+    ("SORTEDDISTINCT", MethodName::SortedDistinct),
+    ("FORMAT_SIZE", MethodName::FormatSize),
+    ("FORMATSIZE", MethodName::FormatSize),
+    ("HUMAN_SIZE", MethodName::FormatSize),
+    ("HUMANSIZE", MethodName::FormatSize),
+    ("AGE", MethodName::Age),
+    // `FORMATDATE` is a reserved word for the `FORMAT` expression.
+    ("FORMAT_DATE", MethodName::FormatDate),
+    ("STRFTIME", MethodName::FormatDate),
+    // `PARSEDATE` is a reserved word for the `PARSE` expression.
+    ("PARSE_DATE", MethodName::ParseDate),
+    ("STRPTIME", MethodName::ParseDate),
+    ("AGGREGATE_BY", MethodName::AggregateBy),
+    ("AGGREGATEBY", MethodName::AggregateBy),
+    ("WITH_EXTENSION", MethodName::WithExtension),
+    ("WITHEXTENSION", MethodName::WithExtension),
+    ("ADD_EXTENSION", MethodName::AddExtension),
+    ("ADDEXTENSION", MethodName::AddExtension),
+    ("EXTENSION", MethodName::Extension),
+    ("TOP_N", MethodName::TopN),
+    ("TOPN", MethodName::TopN),
+    ("ANCESTOR", MethodName::Ancestor),
+    ("PARENT_AT", MethodName::Ancestor),
+    ("PARENTAT", MethodName::Ancestor),
+    ("XATTR", MethodName::Xattr),
+    ("EXTENDED_ATTR", MethodName::Xattr),
+    ("EXTENDEDATTR", MethodName::Xattr),
+    ("ENTROPY", MethodName::Entropy),
+    ("HEAD_LINES", MethodName::HeadLines),
+    ("HEADLINES", MethodName::HeadLines),
+    ("HEAD", MethodName::HeadLines),
+    ("TAIL_LINES", MethodName::TailLines),
+    ("TAILLINES", MethodName::TailLines),
+    ("TAIL", MethodName::TailLines),
+    ("NTH_LINE", MethodName::NthLine),
+    ("NTHLINE", MethodName::NthLine),
+    ("LINE_AT", MethodName::NthLine),
+    ("LINEAT", MethodName::NthLine),
+    ("FIRST_LINE", MethodName::FirstLine),
+    ("FIRSTLINE", MethodName::FirstLine),
+    ("SHEBANG", MethodName::Shebang),
+    ("GREP", MethodName::Grep),
+    ("COUNT_MATCHES", MethodName::CountMatches),
+    ("COUNTMATCHES", MethodName::CountMatches),
+    ("HIGHLIGHT", MethodName::Highlight),
+    ("REGEX_REPLACE_ALL", MethodName::RegexReplaceAll),
+    ("REGEXREPLACEALL", MethodName::RegexReplaceAll),
+    ("INDENT", MethodName::Indent),
+    ("WRAP", MethodName::Wrap),
+    ("DEDENT", MethodName::Dedent),
+    ("ENCODE_BASE64", MethodName::EncodeBase64),
+    ("ENCODEBASE64", MethodName::EncodeBase64),
+    ("TO_BASE64", MethodName::EncodeBase64),
+    ("TOBASE64", MethodName::EncodeBase64),
+    ("DECODE_BASE64", MethodName::DecodeBase64),
+    ("DECODEBASE64", MethodName::DecodeBase64),
+    ("FROM_BASE64", MethodName::DecodeBase64),
+    ("FROMBASE64", MethodName::DecodeBase64),
+    ("URL_ENCODE", MethodName::UrlEncode),
+    ("URLENCODE", MethodName::UrlEncode),
+    ("PERCENT_ENCODE", MethodName::UrlEncode),
+    ("PERCENTENCODE", MethodName::UrlEncode),
+    ("URL_DECODE", MethodName::UrlDecode),
+    ("URLDECODE", MethodName::UrlDecode),
+    ("PERCENT_DECODE", MethodName::UrlDecode),
+    ("PERCENTDECODE", MethodName::UrlDecode),
+    ("ENCODE_HEX", MethodName::EncodeHex),
+    ("ENCODEHEX", MethodName::EncodeHex),
+    ("HEX", MethodName::EncodeHex),
+    ("TO_HEX", MethodName::EncodeHex),
+    ("TOHEX", MethodName::EncodeHex),
+    ("DECODE_HEX", MethodName::DecodeHex),
+    ("DECODEHEX", MethodName::DecodeHex),
+    ("FROM_HEX", MethodName::DecodeHex),
+    ("FROMHEX", MethodName::DecodeHex),
+    ("JSON_GET", MethodName::JsonGet),
+    ("JSONGET", MethodName::JsonGet),
+    ("JSON_PATH", MethodName::JsonGet),
+    ("JSONPATH", MethodName::JsonGet),
+    ("DEPTH_IN", MethodName::DepthIn),
+    ("DEPTHIN", MethodName::DepthIn),
+    ("FILTER_MAP", MethodName::FilterMap),
+    ("FILTERMAP", MethodName::FilterMap),
+    ("SCAN", MethodName::Scan),
+    ("ZIP_WITH", MethodName::ZipWith),
+    ("ZIPWITH", MethodName::ZipWith),
+    ("IF_EMPTY", MethodName::IfEmpty),
+    ("IFEMPTY", MethodName::IfEmpty),
+    ("IF_PRESENT", MethodName::IfPresent),
+    ("IFPRESENT", MethodName::IfPresent),
+];
+
+/// All the names of the methods, used to suggest a name for an unknown word.
+pub(super) fn method_names() -> impl Iterator<Item = &'static &'static str> {
+    METHODS.iter().map(|(name, _)| name)
 }
 impl MethodName {
-    pub(super) fn from_str(name: &str) -> Option<Self> {
-        match name {
-            "LENGTH" | "LEN" | "COUNT" | "SIZE" => Some(MethodName::Length),
-            "TO_UPPER" | "UPPER" | "UPPERCASE" | "TOUPPER" => Some(MethodName::ToUpper),
-            "TO_LOWER" | "LOWER" | "LOWERCASE" | "TOLOWER" => Some(MethodName::ToLower),
-            "TRIM" => Some(MethodName::Trim),
-            "TRIM_HEAD" | "TRIMHEAD" => Some(MethodName::TrimHead),
-            "TRIM_TAIL" | "TRIMTAIL" => Some(MethodName::TrimTail),
-            "REVERSE" => Some(MethodName::Reverse),
-            "MAP" => Some(MethodName::Map),
-            "FILTER" => Some(MethodName::Filter),
-            "SUM" => Some(MethodName::Sum),
-            "MAX" | "MAXIMUM" => Some(MethodName::Max),
-            "MIN" | "MINIMUM" => Some(MethodName::Min),
-            "AVG" | "AVERAGE" => Some(MethodName::Avg),
-            "SORT" | "ORDER" => Some(MethodName::Sort),
-            "SORT_BY" | "ORDER_BY" | "SORTBY" | "ORDERBY" => Some(MethodName::SortBy),
-            "SKIP" => Some(MethodName::Skip),
-            "TAKE" => Some(MethodName::Take),
-            "JOIN" => Some(MethodName::Join),
-            "SPLIT" => Some(MethodName::Split),
-            "LINES" => Some(MethodName::Lines),
-            "WORDS" => Some(MethodName::Words),
-            "FIRST" => Some(MethodName::First),
-            "LAST" => Some(MethodName::Last),
-            "CONTAINS" => Some(MethodName::Contains),
-            "INDEXOF" | "INDEX_OF" => Some(MethodName::IndexOf),
-            "FLATMAP" | "FLAT_MAP" => Some(MethodName::FlatMap),
-            "ALL" => Some(MethodName::All),
-            "ANY" => Some(MethodName::Any),
-            "DISTINCT" | "UNIQUE" => Some(MethodName::Distinct),
-            "DISTINCT_BY" | "DISTINCTBY" | "UNIQUE_BY" | "UNIQUEBY" => Some(MethodName::DistinctBy),
-            "GROUPBY" | "GROUP_BY" => Some(MethodName::GroupBy),
-            "ENUMERATE" => Some(MethodName::Enumerate),
-            "WALK" => Some(MethodName::Walk),
-            "HAS_PREFIX" | "HASPREFIX" | "STARTS_WITH" | "STARTSWITH" => {
-                Some(MethodName::HasPrefix)
-            }
-            "HAS_SUFFIX" | "HASSUFFIX" | "ENDS_WITH" | "ENDSWITH" => Some(MethodName::HasSuffix),
-            "REMOVE_PREFIX" | "REMOVEPREFIX" => Some(MethodName::RemovePrefix),
-            "REMOVE_SUFFIX" | "REMOVESUFFIX" => Some(MethodName::RemoveSuffix),
-            "DEBUG" | "DBG" => Some(MethodName::Debug),
+    /// The method with the same name as a property. The tokens of such a name are always the
+    /// property, and the parser uses the method when it is called on a target (like `x.age()`).
+    pub(super) fn of_access(access: &Access) -> Option<Self> {
+        match access {
+            Access::Age => Some(MethodName::Age),
+            Access::Extension => Some(MethodName::Extension),
             _ => None,
         }
     }
 
+    pub(super) fn from_str(name: &str) -> Option<Self> {
+        METHODS
+            .iter()
+            .find(|(method_name, _)| *method_name == name)
+            .map(|(_, method)| *method)
+    }
+
     fn must_have_arguments(&self) -> bool {
         match self {
             MethodName::Length => false,
@@ -111,14 +376,23 @@ impl MethodName {
             MethodName::Filter => true,
             MethodName::Sum => false,
             MethodName::Max => false,
+            MethodName::Mode => false,
+            MethodName::Percentile => true,
+            MethodName::Variance => false,
+            MethodName::StdDev => false,
             MethodName::Min => false,
             MethodName::Avg => false,
             MethodName::Sort => false,
             MethodName::SortBy => true,
             MethodName::Distinct => false,
             MethodName::DistinctBy => true,
+            MethodName::DistinctCount => false,
+            MethodName::DistinctCountBy => true,
             MethodName::Skip => true,
             MethodName::Take => true,
+            MethodName::SkipLast => true,
+            MethodName::TakeLast => true,
+            MethodName::SplitAt => true,
             MethodName::Join => true,
             MethodName::Split => true,
             MethodName::HasPrefix => true,
@@ -127,17 +401,70 @@ impl MethodName {
             MethodName::RemoveSuffix => true,
             MethodName::Lines => false,
             MethodName::Words => false,
+            MethodName::ShellSplit => false,
             MethodName::First => false,
             MethodName::Last => false,
             MethodName::Contains => true,
             MethodName::IndexOf => true,
+            MethodName::IndicesOf => true,
             MethodName::FlatMap => true,
             MethodName::All => true,
             MethodName::Any => true,
             MethodName::GroupBy => true,
             MethodName::Enumerate => false,
             MethodName::Walk => false,
+            MethodName::WalkFiles => false,
+            MethodName::WalkDirs => false,
             MethodName::Debug => true,
+            MethodName::IsPalindrome => false,
+            MethodName::IsAnagram => true,
+            MethodName::ZipLongest => true,
+            MethodName::Keys => false,
+            MethodName::Values => false,
+            MethodName::Entries => false,
+            MethodName::HasField => true,
+            MethodName::Merge => true,
+            MethodName::MapKeys => true,
+            MethodName::MapValues => true,
+            MethodName::Tee => true,
+            MethodName::SortedDistinct => false,
+            MethodName::FormatSize => false,
+            MethodName::Age => false,
+            MethodName::FormatDate => true,
+            MethodName::ParseDate => true,
+            MethodName::AggregateBy => true,
+            MethodName::WithExtension => true,
+            MethodName::AddExtension => true,
+            MethodName::Extension => false,
+            MethodName::TopN => true,
+            MethodName::Ancestor => true,
+            MethodName::Xattr => true,
+            MethodName::Entropy => false,
+            MethodName::HeadLines => true,
+            MethodName::TailLines => true,
+            MethodName::NthLine => true,
+            MethodName::FirstLine => false,
+            MethodName::Shebang => false,
+            MethodName::Grep => true,
+            MethodName::CountMatches => true,
+            MethodName::Highlight => true,
+            MethodName::RegexReplaceAll => true,
+            MethodName::Indent => true,
+            MethodName::Wrap => true,
+            MethodName::Dedent => false,
+            MethodName::EncodeBase64 => false,
+            MethodName::DecodeBase64 => false,
+            MethodName::UrlEncode => false,
+            MethodName::UrlDecode => false,
+            MethodName::EncodeHex => false,
+            MethodName::DecodeHex => false,
+            MethodName::JsonGet => true,
+            MethodName::DepthIn => true,
+            MethodName::FilterMap => true,
+            MethodName::Scan => true,
+            MethodName::ZipWith => true,
+            MethodName::IfEmpty => true,
+            MethodName::IfPresent => true,
         }
     }
 }
@@ -153,14 +480,69 @@ impl LambdaFunction {
 pub(super) fn build_lambda(
     lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
 ) -> Result<LambdaFunction, ParserError> {
+    build_lambda_until(lex, |f| f == Some(&Token::CloseBrackets))
+}
+
+fn build_lambda_until(
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+    end_condition: fn(Option<&Token>) -> bool,
+) -> Result<LambdaFunction, ParserError> {
+    let name = read_parameter(lex)?;
+    let body = build_expression_with_priority(lex, 0, end_condition)?;
+    Ok(LambdaFunction::new(name, body))
+}
+
+fn read_parameter(
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+) -> Result<String, ParserError> {
     let Some(param) = lex.next() else {
         return Err(ParserError::UnexpectedEof);
     };
     let Token::BindingName(name) = param.token else {
         return Err(ParserError::UnexpectedToken(param.span));
     };
+    Ok(name)
+}
+
+fn build_binary_lambda(
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+) -> Result<BinaryLambdaFunction, ParserError> {
+    let first_parameter = read_parameter(lex)?;
+    let second_parameter = read_parameter(lex)?;
     let body = build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
-    Ok(LambdaFunction::new(name, body))
+    Ok(BinaryLambdaFunction {
+        first_parameter,
+        second_parameter,
+        body: Box::new(body),
+    })
+}
+
+fn build_contains(
+    lex: &mut Peekable<impl Iterator<Item = LexerItem>>,
+) -> Result<Method, ParserError> {
+    let Some(LexerItem {
+        token: Token::BindingName(name),
+        ..
+    }) = lex.peek().cloned()
+    else {
+        let expr = build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+        return Ok(Method::Contains(Box::new(expr)));
+    };
+    lex.next();
+    match lex.peek() {
+        Some(next) if next.token != Token::CloseBrackets && !is_operator(&next.token) => {
+            let body =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::ContainsMatching(LambdaFunction::new(name, body)))
+        }
+        _ => {
+            let left = Expression::BindingReplacement(Binding { name });
+            let expr = continue_expression_with_priority(left, lex, 0, |f| {
+                f == Some(&Token::CloseBrackets)
+            })?;
+            Ok(Method::Contains(Box::new(expr)))
+        }
+    }
 }
 
 pub(super) fn build_method(
@@ -189,6 +571,7 @@ pub(super) fn build_method(
         MethodName::ToUpper => Ok(Method::ToUpper),
         MethodName::ToLower => Ok(Method::ToLower),
         MethodName::Trim => Ok(Method::Trim),
+        MethodName::Dedent => Ok(Method::Dedent),
         MethodName::TrimHead => Ok(Method::TrimHead),
         MethodName::TrimTail => Ok(Method::TrimTail),
         MethodName::Reverse => Ok(Method::Reverse),
@@ -202,18 +585,38 @@ pub(super) fn build_method(
         }
         MethodName::Sum => Ok(Method::Sum),
         MethodName::Max => Ok(Method::Max),
+        MethodName::Mode => Ok(Method::Mode),
+        MethodName::Percentile => {
+            let percent =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Percentile(Box::new(percent)))
+        }
+        MethodName::Variance => Ok(Method::Variance),
+        MethodName::StdDev => Ok(Method::StdDev),
         MethodName::Min => Ok(Method::Min),
         MethodName::Avg => Ok(Method::Avg),
         MethodName::Sort => Ok(Method::Sort),
         MethodName::SortBy => {
-            let lambda = build_lambda(lex)?;
-            Ok(Method::SortBy(lambda))
+            let mut keys = vec![build_lambda_until(lex, |f| {
+                f == Some(&Token::Comma) || f == Some(&Token::CloseBrackets)
+            })?];
+            while lex.next_if(|f| f.token == Token::Comma).is_some() {
+                keys.push(build_lambda_until(lex, |f| {
+                    f == Some(&Token::Comma) || f == Some(&Token::CloseBrackets)
+                })?);
+            }
+            Ok(Method::SortBy(keys))
         }
         MethodName::Distinct => Ok(Method::Distinct),
         MethodName::DistinctBy => {
             let lambda = build_lambda(lex)?;
             Ok(Method::DistinctBy(lambda))
         }
+        MethodName::DistinctCount => Ok(Method::DistinctCount),
+        MethodName::DistinctCountBy => {
+            let lambda = build_lambda(lex)?;
+            Ok(Method::DistinctCountBy(lambda))
+        }
         MethodName::Skip => {
             let expr =
                 build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
@@ -224,6 +627,21 @@ pub(super) fn build_method(
                 build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
             Ok(Method::Take(Box::new(expr)))
         }
+        MethodName::SkipLast => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::SkipLast(Box::new(expr)))
+        }
+        MethodName::TakeLast => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::TakeLast(Box::new(expr)))
+        }
+        MethodName::SplitAt => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::SplitAt(Box::new(expr)))
+        }
         MethodName::Join => {
             let next = lex.peek();
             if let Some(LexerItem {
@@ -264,18 +682,26 @@ pub(super) fn build_method(
             Ok(Method::RemoveSuffix(Box::new(expr)))
         }
         MethodName::Lines => Ok(Method::Lines),
+        MethodName::EncodeBase64 => Ok(Method::EncodeBase64),
+        MethodName::DecodeBase64 => Ok(Method::DecodeBase64),
+        MethodName::UrlEncode => Ok(Method::UrlEncode),
+        MethodName::UrlDecode => Ok(Method::UrlDecode),
+        MethodName::EncodeHex => Ok(Method::EncodeHex),
+        MethodName::DecodeHex => Ok(Method::DecodeHex),
         MethodName::Words => Ok(Method::Words),
+        MethodName::ShellSplit => Ok(Method::ShellSplit),
         MethodName::First => Ok(Method::First),
         MethodName::Last => Ok(Method::Last),
-        MethodName::Contains => {
+        MethodName::Contains => build_contains(lex),
+        MethodName::IndexOf => {
             let expr =
                 build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
-            Ok(Method::Contains(Box::new(expr)))
+            Ok(Method::IndexOf(Box::new(expr)))
         }
-        MethodName::IndexOf => {
+        MethodName::IndicesOf => {
             let expr =
                 build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
-            Ok(Method::IndexOf(Box::new(expr)))
+            Ok(Method::IndicesOf(Box::new(expr)))
         }
         MethodName::FlatMap => {
             let lambda = build_lambda(lex)?;
@@ -294,11 +720,211 @@ pub(super) fn build_method(
             Ok(Method::GroupBy(lambda))
         }
         MethodName::Enumerate => Ok(Method::Enumerate),
-        MethodName::Walk => Ok(Method::Walk),
+        MethodName::Walk | MethodName::WalkFiles | MethodName::WalkDirs => {
+            let max_depth = if !open || lex.peek().map(|f| &f.token) == Some(&Token::CloseBrackets)
+            {
+                None
+            } else {
+                let expr =
+                    build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+                Some(Box::new(expr))
+            };
+            Ok(match name {
+                MethodName::WalkFiles => Method::WalkFiles(max_depth),
+                MethodName::WalkDirs => Method::WalkDirs(max_depth),
+                _ => Method::Walk(max_depth),
+            })
+        }
         MethodName::Debug => {
             let lambda = build_lambda(lex)?;
             Ok(Method::Debug(lambda))
         }
+        MethodName::IsPalindrome => Ok(Method::IsPalindrome),
+        MethodName::IsAnagram => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::IsAnagram(Box::new(expr)))
+        }
+        MethodName::ZipLongest => {
+            let other = build_expression_with_priority(lex, 0, |f| f == Some(&Token::Comma))?;
+            let Some(comma) = lex.next() else {
+                return Err(ParserError::UnexpectedEof);
+            };
+            if comma.token != Token::Comma {
+                return Err(ParserError::UnexpectedToken(comma.span));
+            }
+            let default =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::ZipLongest(Box::new(other), Box::new(default)))
+        }
+        MethodName::Keys => Ok(Method::Keys),
+        MethodName::Values => Ok(Method::Values),
+        MethodName::Entries => Ok(Method::Entries),
+        MethodName::HasField => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::HasField(Box::new(expr)))
+        }
+        MethodName::Merge => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Merge(Box::new(expr)))
+        }
+        MethodName::MapKeys => {
+            let lambda = build_lambda(lex)?;
+            Ok(Method::MapKeys(lambda))
+        }
+        MethodName::MapValues => {
+            let lambda = build_lambda(lex)?;
+            Ok(Method::MapValues(lambda))
+        }
+        MethodName::Tee => {
+            let lambda = build_lambda(lex)?;
+            Ok(Method::Tee(lambda))
+        }
+        MethodName::SortedDistinct => Ok(Method::SortedDistinct),
+        MethodName::Age => Ok(Method::Age),
+        MethodName::FormatDate => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::FormatDate(Box::new(expr)))
+        }
+        MethodName::ParseDate => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::ParseDate(Box::new(expr)))
+        }
+        MethodName::FormatSize => {
+            if !open || lex.peek().map(|f| &f.token) == Some(&Token::CloseBrackets) {
+                Ok(Method::FormatSize(None))
+            } else {
+                let expr =
+                    build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+                Ok(Method::FormatSize(Some(Box::new(expr))))
+            }
+        }
+        MethodName::AggregateBy => {
+            let key = build_lambda_until(lex, |f| f == Some(&Token::Comma))?;
+            lex.next();
+            let aggregation = build_lambda(lex)?;
+            Ok(Method::AggregateBy(key, aggregation))
+        }
+        MethodName::Extension => Ok(Method::Extension),
+        MethodName::WithExtension => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::WithExtension(Box::new(expr)))
+        }
+        MethodName::AddExtension => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::AddExtension(Box::new(expr)))
+        }
+        MethodName::TopN => {
+            let count = build_expression_with_priority(lex, 0, |f| f == Some(&Token::Comma))?;
+            lex.next();
+            let lambda = build_lambda(lex)?;
+            Ok(Method::TopN(Box::new(count), lambda))
+        }
+        MethodName::Ancestor => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Ancestor(Box::new(expr)))
+        }
+        MethodName::Entropy => Ok(Method::Entropy),
+        MethodName::Xattr => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Xattr(Box::new(expr)))
+        }
+        MethodName::HeadLines => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::HeadLines(Box::new(expr)))
+        }
+        MethodName::TailLines => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::TailLines(Box::new(expr)))
+        }
+        MethodName::FirstLine => Ok(Method::FirstLine),
+        MethodName::Shebang => Ok(Method::Shebang),
+        MethodName::NthLine => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::NthLine(Box::new(expr)))
+        }
+        MethodName::Grep => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Grep(Box::new(expr)))
+        }
+        MethodName::CountMatches => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::CountMatches(Box::new(expr)))
+        }
+        MethodName::RegexReplaceAll => {
+            let pattern = build_expression_with_priority(lex, 0, |f| f == Some(&Token::Comma))?;
+            lex.next();
+            let replacement =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::RegexReplaceAll(
+                Box::new(pattern),
+                Box::new(replacement),
+            ))
+        }
+        MethodName::Highlight => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Highlight(Box::new(expr)))
+        }
+        MethodName::Indent => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Indent(Box::new(expr)))
+        }
+        MethodName::Wrap => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::Wrap(Box::new(expr)))
+        }
+        MethodName::IfEmpty => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::IfEmpty(Box::new(expr)))
+        }
+        MethodName::IfPresent => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::IfPresent(Box::new(expr)))
+        }
+        MethodName::JsonGet => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::JsonGet(Box::new(expr)))
+        }
+        MethodName::DepthIn => {
+            let expr =
+                build_expression_with_priority(lex, 0, |f| f == Some(&Token::CloseBrackets))?;
+            Ok(Method::DepthIn(Box::new(expr)))
+        }
+        MethodName::FilterMap => {
+            let lambda = build_lambda(lex)?;
+            Ok(Method::FilterMap(lambda))
+        }
+        MethodName::Scan => {
+            let initial = build_expression_with_priority(lex, 0, |f| f == Some(&Token::Comma))?;
+            lex.next();
+            let lambda = build_binary_lambda(lex)?;
+            Ok(Method::Scan(Box::new(initial), lambda))
+        }
+        MethodName::ZipWith => {
+            let other = build_expression_with_priority(lex, 0, |f| f == Some(&Token::Comma))?;
+            lex.next();
+            let lambda = build_binary_lambda(lex)?;
+            Ok(Method::ZipWith(Box::new(other), lambda))
+        }
     };
     if open {
         let Some(close) = lex.next() else {
@@ -353,4 +979,12 @@ mod tests {
 
         assert!(err.is_some());
     }
+
+    #[test]
+    fn test_zip_longest_no_comma() {
+        let source = "[1].zipLongest([2])";
+        let err = parse_expression(source).err().map(|e| e.to_string());
+
+        assert!(err.is_some_and(|e| e.starts_with("Unexpected token at `At 18 - 19`")));
+    }
 }
@@ -3,7 +3,7 @@ use crate::parser::{
     ast::order_by::{OrderByDirection, OrderByExpression, OrderByItem},
     expression::build_expression_with_priority,
     lexer::lex,
-    parser_error::ParserError,
+    parser_error::{ContextualParserError, ParserError},
     tokens::Token,
 };
 
@@ -20,6 +20,7 @@ mod format;
 mod function;
 mod function_name;
 mod if_expression;
+pub(crate) mod include;
 mod is_check;
 mod lexer;
 mod literal_list;
@@ -34,13 +35,21 @@ mod span;
 mod tokens;
 mod with;
 
-pub(crate) fn parse_expression(source: &str) -> Result<Expression, ParserError> {
+pub(crate) fn parse_expression(source: &str) -> Result<Expression, ContextualParserError> {
+    build_expression(source).map_err(|err| ContextualParserError::new(source, err))
+}
+
+pub(crate) fn parse_order_by(source: &str) -> Result<OrderByExpression, ContextualParserError> {
+    build_order_by(source).map_err(|err| ContextualParserError::new(source, err))
+}
+
+fn build_expression(source: &str) -> Result<Expression, ParserError> {
     let mut lexer = lex(source)?;
 
     build_expression_with_priority(&mut lexer, 0, |f| f.is_none())
 }
 
-pub(crate) fn parse_order_by(source: &str) -> Result<OrderByExpression, ParserError> {
+fn build_order_by(source: &str) -> Result<OrderByExpression, ParserError> {
     let mut lexer = lex(source)?;
 
     let mut items = vec![];
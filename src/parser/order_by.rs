@@ -6,13 +6,13 @@ mod tests {
             ast::order_by::{OrderByDirection, OrderByExpression, OrderByItem},
             ast::{access::Access, expression::Expression},
             parse_order_by,
-            parser_error::ParserError,
+            parser_error::ContextualParserError,
         },
         value::Value,
     };
 
     #[test]
-    fn test_order_by_single_item() -> Result<(), ParserError> {
+    fn test_order_by_single_item() -> Result<(), ContextualParserError> {
         let source = "1";
         let ast = parse_order_by(source)?;
 
@@ -30,7 +30,7 @@ mod tests {
     }
 
     #[test]
-    fn test_order_by_three_items() -> Result<(), ParserError> {
+    fn test_order_by_three_items() -> Result<(), ContextualParserError> {
         let source = "1, false, name";
         let ast = parse_order_by(source)?;
 
@@ -58,7 +58,7 @@ mod tests {
     }
 
     #[test]
-    fn test_order_by_three_items_asc_and_desc() -> Result<(), ParserError> {
+    fn test_order_by_three_items_asc_and_desc() -> Result<(), ContextualParserError> {
         let source = "1 asc, false, name desc";
         let ast = parse_order_by(source)?;
 
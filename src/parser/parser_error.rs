@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use thiserror::Error;
 
 use crate::parser::{lexer::LexerError, span::Span};
@@ -12,4 +14,140 @@ pub enum ParserError {
     UnexpectedToken(Span),
     #[error("Case without any branches `{0}`")]
     NoBranches(Span),
+    #[error("Can not include {1}")]
+    IncludeFailed(Span, String),
+}
+impl ParserError {
+    fn span(&self) -> Option<Span> {
+        match self {
+            ParserError::LexerError(err) => Some(err.span()),
+            ParserError::UnexpectedEof => None,
+            ParserError::UnexpectedToken(span) => Some(*span),
+            ParserError::NoBranches(span) => Some(*span),
+            ParserError::IncludeFailed(span, _) => Some(*span),
+        }
+    }
+}
+
+/// A parser error, with the source that failed to parse, so it can point at the bad token.
+#[derive(Error, Debug)]
+pub struct ContextualParserError {
+    expression: String,
+    #[source]
+    error: ParserError,
+}
+impl ContextualParserError {
+    pub(crate) fn new(source: &str, error: ParserError) -> Self {
+        Self {
+            expression: source.to_string(),
+            error,
+        }
+    }
+
+    pub(crate) fn into_error(self) -> ParserError {
+        self.error
+    }
+
+    /// The line of the error, and the (zero based) columns of the start and the end of the
+    /// error within that line. An unexpected end points just after the last character.
+    fn location(&self) -> (&str, usize, usize) {
+        let length = self.expression.chars().count();
+        let span = self.error.span().unwrap_or(Span {
+            start: length,
+            end: length + 1,
+        });
+        let end = span.end.min(length).max(span.start + 1);
+        // The span of a token includes the white spaces before it.
+        let start = self
+            .expression
+            .chars()
+            .enumerate()
+            .skip(span.start)
+            .take(end.saturating_sub(span.start))
+            .find(|(_, c)| !c.is_whitespace())
+            .map_or(span.start.min(length), |(index, _)| index);
+        let mut line_start = 0;
+        let mut lines = self.expression.split('\n').peekable();
+        while let Some(line) = lines.next() {
+            let line_end = line_start + line.chars().count();
+            if start <= line_end || lines.peek().is_none() {
+                return (line, start - line_start, end.min(line_end + 1) - line_start);
+            }
+            line_start = line_end + 1;
+        }
+        (&self.expression, start, end)
+    }
+}
+impl Display for ContextualParserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (line, column, end) = self.location();
+        writeln!(f, "{} (column {})", self.error, column + 1)?;
+        writeln!(f, "  {line}")?;
+        write!(f, "  {}{}", " ".repeat(column), "^".repeat(end - column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{parse_expression, parse_order_by};
+
+    fn error_lines(source: &str) -> Vec<String> {
+        parse_expression(source)
+            .err()
+            .unwrap()
+            .to_string()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn caret_points_to_unexpected_token() {
+        let lines = error_lines("size > 10 20");
+
+        assert_eq!(lines[0], "Unexpected token at `At 9 - 12` (column 11)");
+        assert_eq!(lines[1], "  size > 10 20");
+        assert_eq!(lines[2], "            ^^");
+    }
+
+    #[test]
+    fn caret_points_to_the_end_when_expression_is_not_complete() {
+        let lines = error_lines("size >");
+
+        assert_eq!(lines[0], "Unexpected end of expression (column 7)");
+        assert_eq!(lines[1], "  size >");
+        assert_eq!(lines[2], "        ^");
+    }
+
+    #[test]
+    fn caret_points_to_lexer_error() {
+        let lines = error_lines("name = \"abc");
+
+        assert_eq!(lines[1], "  name = \"abc");
+        assert_eq!(lines[2].find('^'), Some(9));
+    }
+
+    #[test]
+    fn caret_points_to_the_correct_line() {
+        let lines = error_lines("size > 10\nAND )");
+
+        assert_eq!(lines[0], "Unexpected token at `At 13 - 15` (column 5)");
+        assert_eq!(lines[1], "  AND )");
+        assert_eq!(lines[2], "      ^");
+    }
+
+    #[test]
+    fn caret_counts_characters_not_bytes() {
+        let lines = error_lines("\"ü\" = 3 3");
+
+        assert_eq!(lines[1], "  \"ü\" = 3 3");
+        assert_eq!(lines[2], "          ^");
+    }
+
+    #[test]
+    fn caret_in_order_by() {
+        let err = parse_order_by("size DESC name").err().unwrap().to_string();
+
+        assert!(err.ends_with("  size DESC name\n            ^^^^"));
+    }
 }
@@ -1,9 +1,12 @@
 use std::{iter::Peekable, path::PathBuf};
 
 use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, offset::LocalResult};
+use regex::Regex;
 
 use crate::{
+    errors::closest_name,
     parser::{
+        access::access_names,
         ast::{
             access::Access,
             function_name::FunctionName,
@@ -12,9 +15,10 @@ use crate::{
                 LogicalOperator,
             },
         },
-        method::MethodName,
+        function_name::function_names,
+        method::{MethodName, method_names},
     },
-    value::Value,
+    value::{RegexValue, Value, duration_of},
 };
 
 #[derive(Debug, PartialEq, Clone)]
@@ -53,6 +57,7 @@ pub(crate) enum Token {
     Boolean,
     String,
     Number,
+    List,
     Replace,
     To,
     Pattern,
@@ -63,6 +68,7 @@ pub(crate) enum Token {
     MethodName(MethodName),
     With,
     Do,
+    Include,
     ClassStarts,
     ClassEnds,
     ClassFieldName(String),
@@ -76,21 +82,26 @@ pub(crate) struct TokenError {
 
 impl Token {
     pub(crate) fn new(
-        chars: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+        chars: &mut Peekable<impl Iterator<Item = (usize, char)> + Clone>,
     ) -> Result<Option<Self>, TokenError> {
         let chr = loop {
-            let Some((_, chr)) = chars.peek() else {
+            let Some(&(_, chr)) = chars.peek() else {
                 chars.next();
                 return Ok(None);
             };
-            if !chr.is_ascii_whitespace() {
+            if chr == '#' {
+                // A comment, up to the end of the line.
+                chars.find(|(_, chr)| *chr == '\n');
+            } else if chr == '/' && chars.clone().nth(1).is_some_and(|(_, chr)| chr == '*') {
+                skip_block_comment(chars)?;
+            } else if !chr.is_ascii_whitespace() {
                 break chr;
             } else {
                 chars.next();
             }
         };
         match chr {
-            '0'..='9' => Ok(Some(Token::Value(Value::Number(read_number(chars))))),
+            '0'..='9' => Ok(Some(Token::Value(read_numeric(chars)))),
             '"' => Ok(Some(Token::Value(Value::String(read_string(chars)?)))),
             '$' => Ok(Some(Token::BindingName(read_binding_name(chars)?))),
             '@' => Ok(Some(read_path_or_file(chars)?)),
@@ -188,10 +199,7 @@ fn read_symbol(
     chars: &mut Peekable<impl Iterator<Item = (usize, char)>>,
 ) -> Result<Token, TokenError> {
     let mut str = String::new();
-    loop {
-        let Some((_, chr)) = chars.peek() else {
-            break;
-        };
+    while let Some((_, chr)) = chars.peek() {
         let chr = *chr;
         match chr {
             '=' | '!' | '<' | '>' => {
@@ -226,83 +234,175 @@ fn read_symbol(
     }
 }
 
+/// All the reserved words that are not accessors, functions or methods (with their aliases), used
+/// both to read a reserved word and to suggest a name for an unknown word.
+const KEYWORDS: &[(&str, Token)] = &[
+    ("FALSE", Token::Value(Value::Bool(false))),
+    ("TRUE", Token::Value(Value::Bool(true))),
+    ("NULL", Token::Value(Value::Empty)),
+    ("EMPTY", Token::Value(Value::Empty)),
+    ("NOT", Token::Not),
+    (
+        "AND",
+        Token::BinaryOperator(BinaryOperator::Logical(LogicalOperator::And)),
+    ),
+    (
+        "OR",
+        Token::BinaryOperator(BinaryOperator::Logical(LogicalOperator::Or)),
+    ),
+    (
+        "XOR",
+        Token::BinaryOperator(BinaryOperator::Logical(LogicalOperator::Xor)),
+    ),
+    ("IS", Token::Is),
+    ("SOME", Token::Some),
+    ("NONE", Token::None),
+    ("FILE", Token::File),
+    ("DIR", Token::Dir),
+    ("LINK", Token::Link),
+    ("OF", Token::BinaryOperator(BinaryOperator::Of)),
+    ("MATCHES", Token::BinaryOperator(BinaryOperator::Matches)),
+    ("GLOB", Token::BinaryOperator(BinaryOperator::Glob)),
+    ("IN", Token::BinaryOperator(BinaryOperator::In)),
+    (
+        "DIV",
+        Token::BinaryOperator(BinaryOperator::Arithmetic(
+            ArithmeticOperator::IntegerDivide,
+        )),
+    ),
+    ("IF", Token::If),
+    ("THEN", Token::Then),
+    ("ELSE", Token::Else),
+    ("CASE", Token::Case),
+    ("WHEN", Token::When),
+    ("END", Token::End),
+    ("BETWEEN", Token::Between),
+    ("FORMAT", Token::Format),
+    ("FORMATDATE", Token::Format),
+    ("FOR", Token::For),
+    ("FROM", Token::From),
+    ("PARSE", Token::Parse),
+    ("PARSEDATE", Token::Parse),
+    ("SPAWN", Token::Spawn),
+    ("FIRE", Token::Spawn),
+    ("EXECUTE", Token::Execute),
+    ("EXEC", Token::Execute),
+    ("INTO", Token::Into),
+    ("ASC", Token::Asc),
+    ("DESC", Token::Desc),
+    ("AS", Token::As),
+    ("WITH", Token::With),
+    ("DO", Token::Do),
+    ("INCLUDE", Token::Include),
+    ("DATE", Token::Date),
+    ("TIME", Token::Date),
+    ("TIMESTAMP", Token::Date),
+    ("BOOL", Token::Boolean),
+    ("BOOLEAN", Token::Boolean),
+    ("STRING", Token::String),
+    ("TEXT", Token::String),
+    ("STR", Token::String),
+    ("REPLACE", Token::Replace),
+    ("TO", Token::To),
+    ("PATTERN", Token::Pattern),
+    ("NUMBER", Token::Number),
+    ("NUM", Token::Number),
+    ("INT", Token::Number),
+    ("INTEGER", Token::Number),
+    ("LIST", Token::List),
+];
+
+/// All the reserved words that are not accessors, functions or methods.
+pub(super) fn keyword_names() -> impl Iterator<Item = &'static &'static str> {
+    KEYWORDS.iter().map(|(name, _)| name)
+}
+
 fn read_reserved_word(
     chars: &mut Peekable<impl Iterator<Item = (usize, char)>>,
 ) -> Result<Token, TokenError> {
     let mut str = String::new();
-    loop {
-        let Some((_, chr)) = chars.peek() else {
-            break;
-        };
+    while let Some((_, chr)) = chars.peek() {
         let chr = *chr;
-        if chr.is_ascii_alphabetic() || chr == '_' {
+        // A reserved word always starts with a letter, so digits can only follow it.
+        if chr.is_ascii_alphabetic() || chr == '_' || (!str.is_empty() && chr.is_ascii_digit()) {
             chars.next();
             str.push(chr.to_ascii_uppercase());
         } else {
             break;
         }
     }
-    match str.as_str() {
-        "FALSE" => Ok(Token::Value(Value::Bool(false))),
-        "TRUE" => Ok(Token::Value(Value::Bool(true))),
-        "NOT" => Ok(Token::Not),
-        "AND" => Ok(Token::BinaryOperator(BinaryOperator::Logical(
-            LogicalOperator::And,
-        ))),
-        "OR" => Ok(Token::BinaryOperator(BinaryOperator::Logical(
-            LogicalOperator::Or,
-        ))),
-        "XOR" => Ok(Token::BinaryOperator(BinaryOperator::Logical(
-            LogicalOperator::Xor,
-        ))),
-        "IS" => Ok(Token::Is),
-        "SOME" => Ok(Token::Some),
-        "NONE" => Ok(Token::None),
-        "FILE" => Ok(Token::File),
-        "DIR" => Ok(Token::Dir),
-        "LINK" => Ok(Token::Link),
-        "OF" => Ok(Token::BinaryOperator(BinaryOperator::Of)),
-        "MATCHES" => Ok(Token::BinaryOperator(BinaryOperator::Matches)),
-        "IF" => Ok(Token::If),
-        "THEN" => Ok(Token::Then),
-        "ELSE" => Ok(Token::Else),
-        "CASE" => Ok(Token::Case),
-        "WHEN" => Ok(Token::When),
-        "END" => Ok(Token::End),
-        "BETWEEN" => Ok(Token::Between),
-        "FORMAT" | "FORMATDATE" => Ok(Token::Format),
-        "FOR" => Ok(Token::For),
-        "FROM" => Ok(Token::From),
-        "PARSE" | "PARSEDATE" => Ok(Token::Parse),
-        "SPAWN" | "FIRE" => Ok(Token::Spawn),
-        "EXECUTE" | "EXEC" => Ok(Token::Execute),
-        "INTO" => Ok(Token::Into),
-        "ASC" => Ok(Token::Asc),
-        "DESC" => Ok(Token::Desc),
-        "AS" => Ok(Token::As),
-        "WITH" => Ok(Token::With),
-        "DO" => Ok(Token::Do),
-        "DATE" | "TIME" | "TIMESTAMP" => Ok(Token::Date),
-        "BOOL" | "BOOLEAN" => Ok(Token::Boolean),
-        "STRING" | "TEXT" | "STR" => Ok(Token::String),
-        "REPLACE" => Ok(Token::Replace),
-        "TO" => Ok(Token::To),
-        "PATTERN" => Ok(Token::Pattern),
-        "NUMBER" | "NUM" | "INT" | "INTEGER" => Ok(Token::Number),
-        _ => {
-            if let Some(access) = Access::from_str(&str) {
-                Ok(Token::SimpleAccess(access))
-            } else if let Some(f) = FunctionName::from_str(&str) {
-                Ok(Token::FunctionName(f))
-            } else if let Some(n) = MethodName::from_str(&str) {
-                Ok(Token::MethodName(n))
-            } else {
-                Err(TokenError {
-                    cause: format!("Unknown reserved word: {str}"),
-                })
+    if str == "RE" && chars.peek().is_some_and(|(_, chr)| *chr == '/') {
+        return read_regex(chars);
+    }
+    if let Some((_, token)) = KEYWORDS.iter().find(|(keyword, _)| *keyword == str) {
+        return Ok(token.clone());
+    }
+    if let Some(access) = Access::from_str(&str) {
+        Ok(Token::SimpleAccess(access))
+    } else if let Some(f) = FunctionName::from_str(&str) {
+        Ok(Token::FunctionName(f))
+    } else if let Some(n) = MethodName::from_str(&str) {
+        Ok(Token::MethodName(n))
+    } else {
+        let known = keyword_names()
+            .chain(access_names())
+            .chain(function_names())
+            .chain(method_names())
+            .copied();
+        let suggestion = closest_name(&str, known)
+            .map(|name| format!(". Did you mean: {}?", name.to_lowercase()))
+            .unwrap_or_default();
+        Err(TokenError {
+            cause: format!("Unknown reserved word: {str}{suggestion}"),
+        })
+    }
+}
+
+/// Read a `re/pattern/flags` literal (after the `re`), the regular expression is compiled once, here.
+fn read_regex(
+    chars: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+) -> Result<Token, TokenError> {
+    // eat the open /
+    chars.next();
+    let mut pattern = String::new();
+    loop {
+        match chars.next() {
+            None => {
+                return Err(TokenError {
+                    cause: "Unended regular expression".into(),
+                });
             }
+            Some((_, '/')) => break,
+            Some((_, '\\')) if chars.peek().is_some_and(|(_, chr)| *chr == '/') => {
+                chars.next();
+                pattern.push('/');
+            }
+            Some((_, chr)) => pattern.push(chr),
         }
     }
+    let mut flags = String::new();
+    while let Some((_, chr)) = chars.peek() {
+        let chr = *chr;
+        if !chr.is_ascii_alphabetic() {
+            break;
+        }
+        if !"imsxU".contains(chr) {
+            return Err(TokenError {
+                cause: format!("Unknown regular expression flag: {chr}"),
+            });
+        }
+        flags.push(chr);
+        chars.next();
+    }
+    if !flags.is_empty() {
+        pattern = format!("(?{flags}){pattern}");
+    }
+    match Regex::new(&pattern) {
+        Ok(regex) => Ok(Token::Value(Value::Regex(RegexValue::new(regex)))),
+        Err(err) => Err(TokenError {
+            cause: format!("Bad regular expression [{pattern}]: {err}"),
+        }),
+    }
 }
 
 fn read_field_access_or_definition(
@@ -523,13 +623,63 @@ fn read_hex_char(chars: &mut impl Iterator<Item = (usize, char)>) -> Result<char
     Ok(chr)
 }
 
+/// Skip a `/* ... */` comment, the iterator should point to the opening `/`.
+fn skip_block_comment(
+    chars: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+) -> Result<(), TokenError> {
+    chars.next();
+    chars.next();
+    let mut last = None;
+    for (_, chr) in chars.by_ref() {
+        if last == Some('*') && chr == '/' {
+            return Ok(());
+        }
+        last = Some(chr);
+    }
+    Err(TokenError {
+        cause: "Unterminated block comment".into(),
+    })
+}
+
+/// A number, or a float if the (decimal) number is followed by a decimal point and digits, or a
+/// duration if the (decimal) number is followed by a unit letter (`s`, `m`, `h`, `d` or `w`).
+fn read_numeric(chars: &mut Peekable<impl Iterator<Item = (usize, char)> + Clone>) -> Value {
+    let mut lookahead = chars.clone();
+    let has_radix = lookahead.next().is_some_and(|(_, c)| c == '0')
+        && lookahead.peek().is_some_and(|(_, c)| "xXoObB".contains(*c));
+    let number = read_number(chars);
+    if has_radix {
+        return Value::Number(number);
+    }
+    let mut lookahead = chars.clone();
+    if let Some((_, unit)) = lookahead.next()
+        && "smhdwSMHDW".contains(unit)
+        && !lookahead
+            .peek()
+            .is_some_and(|(_, c)| c.is_alphanumeric() || *c == '_')
+    {
+        chars.next();
+        return duration_of(number, &unit.to_string()).into();
+    }
+    let mut lookahead = chars.clone();
+    if lookahead.next().map(|(_, c)| c) != Some('.')
+        || !lookahead.peek().is_some_and(|(_, c)| c.is_ascii_digit())
+    {
+        return Value::Number(number);
+    }
+    // eat the decimal point
+    chars.next();
+    let mut float = format!("{number}.");
+    while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+        float.push(c);
+    }
+    float.parse::<f64>().ok().into()
+}
+
 fn read_number(chars: &mut Peekable<impl Iterator<Item = (usize, char)>>) -> u64 {
     let mut number = 0;
     let mut index = 0;
-    loop {
-        let Some((_, char)) = chars.peek() else {
-            break;
-        };
+    while let Some((_, char)) = chars.peek() {
         let Some(digit) = char.to_digit(10) else {
             if index == 1 && number == 0 {
                 let radix = match char.to_ascii_uppercase() {
@@ -557,10 +707,7 @@ fn read_number_with_radix(
     radix: u32,
 ) -> u64 {
     let mut number = 0;
-    loop {
-        let Some((_, char)) = chars.peek() else {
-            break;
-        };
+    while let Some((_, char)) = chars.peek() {
         let Some(digit) = char.to_digit(radix) else {
             break;
         };
@@ -596,7 +743,9 @@ fn read_binding_name(
 
 #[cfg(test)]
 mod tests {
-    use chrono::{FixedOffset, MappedLocalTime, NaiveTime, TimeZone, Utc};
+    use chrono::{FixedOffset, MappedLocalTime, NaiveTime, TimeDelta, TimeZone, Utc};
+
+    use std::collections::HashSet;
 
     use crate::parser::ast::function_name::EnvFunctionName;
 
@@ -626,6 +775,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn read_float_number() -> Result<(), TokenError> {
+        for (str, expected) in [("2.5", 2.5), ("0.125", 0.125), ("10.0", 10.0)] {
+            let mut chars = str.chars().enumerate().peekable();
+
+            let token = Token::new(&mut chars)?;
+
+            assert_eq!(token, Some(Token::Value(expected.into())));
+            assert!(chars.next().is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_number_followed_by_method() -> Result<(), TokenError> {
+        let str = "12.length()";
+        let mut chars = str.chars().enumerate().peekable();
+
+        let token = Token::new(&mut chars)?;
+
+        assert_eq!(token, Some(Token::Value(Value::Number(12))));
+        assert_eq!(chars.next(), Some((2, '.')));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_hex_number_is_not_a_float() -> Result<(), TokenError> {
+        let str = "0x10.5";
+        let mut chars = str.chars().enumerate().peekable();
+
+        let token = Token::new(&mut chars)?;
+
+        assert_eq!(token, Some(Token::Value(Value::Number(16))));
+
+        Ok(())
+    }
+
     #[test]
     fn read_hex_number() -> Result<(), TokenError> {
         let str = "0x11Aa";
@@ -1405,4 +1593,284 @@ mod tests {
 
         Ok(())
     }
+    #[test]
+    fn no_name_is_listed_twice() {
+        for names in [
+            keyword_names().collect::<Vec<_>>(),
+            access_names().collect(),
+            function_names().collect(),
+            method_names().collect(),
+        ] {
+            let mut seen = HashSet::new();
+            for name in names {
+                assert!(seen.insert(name), "{name} is listed twice");
+            }
+        }
+    }
+
+    fn read_name(name: &str) -> Result<Option<Token>, TokenError> {
+        let mut chars = name.chars().enumerate().peekable();
+        Token::new(&mut chars)
+    }
+
+    #[test]
+    fn every_name_reads_its_own_token() -> Result<(), TokenError> {
+        for (name, token) in KEYWORDS {
+            assert_eq!(read_name(name)?.as_ref(), Some(token), "{name}");
+        }
+        for name in function_names() {
+            let function = FunctionName::from_str(name);
+            assert!(
+                matches!(read_name(name)?, Some(Token::FunctionName(f)) if Some(f) == function),
+                "{name} is not read as a function"
+            );
+        }
+        for name in method_names() {
+            let method = MethodName::from_str(name);
+            // A method with the name of a property is read as the property, and the parser
+            // decides which one it is.
+            assert!(
+                match read_name(name)? {
+                    Some(Token::MethodName(m)) => Some(m) == method,
+                    Some(Token::SimpleAccess(_)) => true,
+                    _ => false,
+                },
+                "{name} is not read as a method"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn all_known_names_are_reserved_words() {
+        let known = keyword_names()
+            .chain(access_names())
+            .chain(function_names())
+            .chain(method_names());
+        for name in known {
+            let mut chars = name.chars().enumerate().peekable();
+
+            assert!(
+                Token::new(&mut chars).is_ok(),
+                "{name} is not a reserved word"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_word_suggest_a_name() {
+        for (word, suggestion) in [
+            ("exntension", "extension"),
+            ("lentgh", "length"),
+            ("betwen", "between"),
+            ("toUper", "toupper"),
+        ] {
+            let mut chars = word.chars().enumerate().peekable();
+
+            let err = Token::new(&mut chars).err().unwrap();
+
+            assert!(
+                err.cause
+                    .ends_with(&format!(". Did you mean: {suggestion}?")),
+                "{}",
+                err.cause
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_word_without_suggestion() {
+        let mut chars = "qwertyuiop".chars().enumerate().peekable();
+
+        let err = Token::new(&mut chars).err().unwrap();
+
+        assert_eq!(err.cause, "Unknown reserved word: QWERTYUIOP");
+    }
+
+    #[test]
+    fn line_comment_is_skipped() -> Result<(), TokenError> {
+        let str = "# a comment\n  # another one\n 12 # trailing";
+        let mut chars = str.chars().enumerate().peekable();
+
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::Value(Value::Number(12)))
+        );
+        assert_eq!(Token::new(&mut chars)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hash_in_string_is_not_a_comment() -> Result<(), TokenError> {
+        let str = "\"#1\"";
+        let mut chars = str.chars().enumerate().peekable();
+
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::Value(Value::String("#1".into())))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn duration_literals() -> Result<(), TokenError> {
+        for (str, expected) in [
+            ("45s", TimeDelta::seconds(45)),
+            ("2m", TimeDelta::minutes(2)),
+            ("2h", TimeDelta::hours(2)),
+            ("7d", TimeDelta::days(7)),
+            ("1W", TimeDelta::weeks(1)),
+        ] {
+            let mut chars = str.chars().enumerate().peekable();
+
+            assert_eq!(
+                Token::new(&mut chars)?,
+                Some(Token::Value(Value::Duration(expected))),
+                "{str}"
+            );
+            assert_eq!(Token::new(&mut chars)?, None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn number_followed_by_a_word_is_not_a_duration() {
+        let mut chars = "3days".chars().enumerate().peekable();
+
+        assert_eq!(
+            Token::new(&mut chars).ok(),
+            Some(Some(Token::Value(Value::Number(3))))
+        );
+    }
+
+    #[test]
+    fn block_comment_between_tokens() -> Result<(), TokenError> {
+        let str = "12 /* a\n * comment **/ + /**/ 3";
+        let mut chars = str.chars().enumerate().peekable();
+
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::Value(Value::Number(12)))
+        );
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::BinaryOperator(BinaryOperator::Arithmetic(
+                ArithmeticOperator::Plus
+            )))
+        );
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::Value(Value::Number(3)))
+        );
+        assert_eq!(Token::new(&mut chars)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unterminated_block_comment() {
+        for str in ["/* no end", "/*/", "/* almost *"] {
+            let mut chars = str.chars().enumerate().peekable();
+
+            let err = Token::new(&mut chars).err().unwrap();
+
+            assert_eq!(err.cause, "Unterminated block comment", "{str}");
+        }
+    }
+
+    #[test]
+    fn property_and_method_name_is_a_property() -> Result<(), TokenError> {
+        let mut chars = "extension()".chars().enumerate().peekable();
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::SimpleAccess(Access::Extension))
+        );
+
+        let mut chars = "extension (".chars().enumerate().peekable();
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::SimpleAccess(Access::Extension))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn slash_star_starts_a_comment_only_without_space() -> Result<(), TokenError> {
+        let mut chars = "size/*b".chars().enumerate().peekable();
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::SimpleAccess(Access::Size))
+        );
+        assert!(Token::new(&mut chars).is_err());
+
+        let mut chars = "size / *b".chars().enumerate().peekable();
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::SimpleAccess(Access::Size))
+        );
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::BinaryOperator(BinaryOperator::Arithmetic(
+                ArithmeticOperator::Divide
+            )))
+        );
+        assert_eq!(
+            Token::new(&mut chars)?,
+            Some(Token::BinaryOperator(BinaryOperator::Arithmetic(
+                ArithmeticOperator::Multiply
+            )))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn null_and_empty_are_empty_values() -> Result<(), TokenError> {
+        for str in ["NULL", "null", "Empty"] {
+            let mut chars = str.chars().enumerate().peekable();
+
+            assert_eq!(
+                Token::new(&mut chars)?,
+                Some(Token::Value(Value::Empty)),
+                "{str}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn regex_literal() -> Result<(), TokenError> {
+        for (str, expected) in [
+            ("re/^a.c$/", "^a.c$"),
+            ("RE/a\\/b/", "a/b"),
+            ("re/\\d+/", "\\d+"),
+            ("re/abc/i", "(?i)abc"),
+            ("re/a.b/sm", "(?sm)a.b"),
+        ] {
+            let mut chars = str.chars().enumerate().peekable();
+
+            let Some(Token::Value(Value::Regex(regex))) = Token::new(&mut chars)? else {
+                panic!("{str} is not a regex");
+            };
+
+            assert_eq!(regex.get().as_str(), expected, "{str}");
+            assert!(chars.next().is_none(), "{str}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bad_regex_literal() {
+        for str in ["re/abc", "re/[/", "re/abc/q"] {
+            let mut chars = str.chars().enumerate().peekable();
+
+            assert!(Token::new(&mut chars).is_err(), "{str}");
+        }
+    }
 }
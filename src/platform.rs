@@ -0,0 +1,67 @@
+use std::{
+    fs::Metadata,
+    path::{Path, PathBuf},
+};
+
+/// The permission bits of a file (like `0o644`), if the platform has them.
+#[cfg(unix)]
+pub(crate) fn mode(metadata: &Metadata) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+
+    Some(metadata.permissions().mode())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn mode(_: &Metadata) -> Option<u32> {
+    None
+}
+
+/// The name of the user that owns a file, if the platform has owners.
+#[cfg(unix)]
+pub(crate) fn owner_name(metadata: &Metadata) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+
+    uzers::get_user_by_uid(metadata.uid()).and_then(|u| u.name().to_str().map(str::to_string))
+}
+
+#[cfg(not(unix))]
+pub(crate) fn owner_name(_: &Metadata) -> Option<String> {
+    None
+}
+
+/// The name of the group that owns a file, if the platform has groups.
+#[cfg(unix)]
+pub(crate) fn group_name(metadata: &Metadata) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+
+    uzers::get_group_by_gid(metadata.gid()).and_then(|g| g.name().to_str().map(str::to_string))
+}
+
+#[cfg(not(unix))]
+pub(crate) fn group_name(_: &Metadata) -> Option<String> {
+    None
+}
+
+/// The home directory of the current user, according to the users database.
+#[cfg(unix)]
+pub(crate) fn current_user_home() -> Option<PathBuf> {
+    use uzers::os::unix::UserExt;
+
+    uzers::get_user_by_uid(uzers::get_current_uid()).map(|user| user.home_dir().to_path_buf())
+}
+
+#[cfg(not(unix))]
+pub(crate) fn current_user_home() -> Option<PathBuf> {
+    None
+}
+
+/// The value of an extended attribute of a file, if it has one.
+#[cfg(unix)]
+pub(crate) fn xattr(path: &Path, name: &str) -> Option<Vec<u8>> {
+    ::xattr::get(path, name).ok().flatten()
+}
+
+#[cfg(not(unix))]
+pub(crate) fn xattr(_: &Path, _: &str) -> Option<Vec<u8>> {
+    None
+}
@@ -24,10 +24,14 @@ fn get_syntax_help(term: bool) -> String {
 
 {bold}LITERALS:{reset}
   Numbers:     123  0x1F  0o755  0b1010
+  Floats:      2.5  0.125  (5 / 2 is 2.5)
   Strings:     "text"  "escape: \n \t \""
   Booleans:    true  false
+  Empty:       NULL  EMPTY
   Dates:       @(2025-12-19)  @(19/Dec/2025 14:30)
+  Durations:   45s  30m  2h  7d  1w  (now() - 7d)
   Paths:       @src  @"my file.txt"
+  Regex:       re/^test.*\.rs$/  re/readme/i  (for MATCHES)
   Lists:       [1, 2, 3]  ["a", "b"]
   Classes:     {{:name "value", :count 42}}
 
@@ -38,20 +42,33 @@ fn get_syntax_help(term: bool) -> String {
   path         Full file path as string
   absolute     Absolute path
   size         File size in bytes
+  total_size   Size of everything under a directory
+  count        Number of direct entries (1 for files)
+  child_count  Number of direct entries of a directory
   depth        Directory depth (root = 0)
   content      File content as string (empty if binary/unreadable)
+  bytes        File content as bytes
   created      Creation date/time
+  age          Duration since creation (age > 30d)
   modified     Last modification date/time
   owner        File owner username
   group        File group name
   permission   File permissions (numeric)
+  owner_read   Permission bit (owner/group/others _read/_write/_execute)
+  setuid       Special permission bit (setuid, setgid, sticky)
   parent       Parent directory path
   files        List of files in directory
+  file_list    List of regular files in directory
+  dirs         List of sub directories in directory
   
   IS FILE      True if regular file
   IS DIR       True if directory
   IS LINK      True if symbolic link
   exists       True if file exists
+  is_absolute  True if the path is absolute
+  is_relative  True if the path is relative
+  is_empty_dir True if directory with no entries
+  hidden       True if name starts with a dot
 
 {bold}COMPARISON OPERATORS:{reset}
   =  ==        Equal
@@ -72,7 +89,8 @@ fn get_syntax_help(term: bool) -> String {
   +            Addition
   -            Subtraction
   *            Multiplication
-  /            Division
+  /            Division (always a float)
+  DIV          Integer division (5 DIV 2 = 2)
   %            Modulo (remainder)
   &            Bitwise AND
   |            Bitwise OR
@@ -81,10 +99,16 @@ fn get_syntax_help(term: bool) -> String {
 {bold}STRING OPERATORS:{reset}
   +            Concatenation: "hello" + " " + "world"
   MATCHES      Regular expression: name MATCHES "^test.*\.rs$"
+  GLOB         Shell style pattern: name GLOB "test*.rs"
+  IN           Substring: "TODO" IN content
+
+{bold}LIST OPERATORS:{reset}
+  +            Concatenation: [1, 2] + [3]
+  IN           Membership: extension IN ["rs", "toml"]
 
 {bold}TYPE OPERATORS:{reset}
   IS SOME      Value is not empty
-  IS NONE      Value is empty
+  IS NONE      Value is empty (or IS NULL)
   IS TRUE      Boolean is true
   IS FALSE     Boolean is false
   AS STRING    Convert to string
@@ -92,52 +116,142 @@ fn get_syntax_help(term: bool) -> String {
   AS BOOLEAN   Convert to boolean
   AS DATE      Convert to date
   AS PATH      Convert to path
+  AS LIST      Convert to list (a string is split into lines)
+  AS DURATION  Convert to duration: "1d 2h" AS DURATION
 
 {bold}STRING METHODS:{reset}
   .length()            Number of characters
   .contains("text")    True if contains substring
+  .indicesOf("\n")     Offsets of all the occurrences
   .toLower()           Convert to lowercase
   .toUpper()           Convert to uppercase
   .trim()              Remove leading/trailing whitespace
+  .indent(2)           Indent every non empty line
+  .dedent()            Remove the common indentation
+  .wrap(80)            Word wrap lines to a width
   .split(",")          Split into list
   .lines()             Split by newlines
   .words()             Split by whitespace
+  .toArgs()            Split like a shell command line
   .reverse()           Reverse string
   .hasPrefix("pre")    True if starts with prefix
   .hasSuffix("suf")    True if ends with suffix
+  .isPalindrome()      True if reads the same backward
+  .isAnagram("text")   True if has the same characters
+  .encodeBase64()      Encode as Base64 (also .encodeHex())
+  .decodeBase64()      Decode Base64 to bytes (also .decodeHex())
+  .urlEncode()         Percent encode (also .urlDecode())
+  .jsonGet("$.a[0]")   Field of a JSON document as a string
+  .highlight("re")     Color the regex matches (with --color)
+  .regexReplaceAll(r,s) Replace regex matches ($1 for groups)
+  .parse_date("%Y%m%d") Parse a date (strptime)
 
 {bold}LIST METHODS:{reset}
   .length()                Number of items
   .first()                 First item
   .last()                  Last item
   .contains(x)             True if contains item
+  .contains($x <expr>)     True if any item matches
+  .indicesOf(x)            Indices of all the equal items
   .filter($x <expr>)       Filter items
   .map($x <expr>)          Transform items
+  .filterMap($x <expr>)    Transform items, drop empty
   .sort()                  Sort items
   .sortBy($x <expr>)       Sort by expression
+  .sortBy($x <a>, $y <b>)  Sort by several keys
+  .topN(n, $x <expr>)      n items with largest expression
   .distinct()              Remove duplicates
+  .sortedDistinct()        Sort and remove duplicates
+  .distinctCount()         Number of distinct items
+  .distinctCountBy($x <e>) Number of distinct expressions
   .sum()                   Sum of numbers
   .max()                   Maximum value
   .min()                   Minimum value
   .avg()                   Average value
+  .mode()                  Most common value
+  .percentile(95)          Percentile of numbers (0-100)
+  .variance()              Population variance (also .stddev())
   .take(n)                 First n items
   .skip(n)                 Skip first n items
+  .take_last(n)            Last n items (also .skip_last(n))
+  .splitAt(n)              Class of ::head and ::tail lists
   .join(",")               Join into string
+  .aggregateBy($x k, $g v) Group by key and aggregate
+  .scan(0, $a $x <expr>)   Running accumulation
+  .zipLongest(lst, def)    Pair with another list
+  .zipWith(lst, $a $b ..) Combine with another list
 
 {bold}PATH METHODS:{reset}
-  .lines()     File content as list of lines
-  .words()     File content as list of words
-  .walk()      All descendant files/directories
-  .length()    Size in bytes
+  .lines()               File content as list of lines
+  .words()               File content as list of words
+  .headLines(5)          First 5 lines of the file
+  .tailLines(5)          Last 5 lines of the file
+  .nthLine(3)            Third line of the file
+  .firstLine()           First line of the file
+  .shebang()             Interpreter of a #! script
+  .grep("ERROR")         Lines matching a regex
+  .countMatches("fn")    Number of regex matches
+  .walk()                All descendant files
+  .walk(2)               Descendant files, at most 2 levels deep
+  .walkFiles()           All descendant files
+  .walkDirs()            All descendant directories
+  .length()              Size in bytes
+  .ancestor(2)           Grandparent directory
+  .xattr("user.tag")     Extended attribute value
+  .entropy()             Bytes entropy (0 to 8)
+  .depthIn(@src)         Depth under another root
+  .withExtension("bak")  Path with another extension
+  .addExtension("tmp")   Path with an extra extension
+  .extension()           Extension of a path (or string)
+
+{bold}BYTES METHODS:{reset}
+  .length()            Number of bytes
+  .encodeHex()         Bytes as a hex string
+  .encodeBase64()      Bytes as a Base64 string
+
+{bold}NUMBER METHODS:{reset}
+  .formatSize()        Human readable size ("1.0 GiB")
+  .formatSize("MB")    Size in a specific unit
+
+{bold}DATE METHODS:{reset}
+  .age()               Seconds since the date
+  .format_date("%Y")   Format the date (strftime)
+
+{bold}CLASS METHODS:{reset}
+  .keys()             Field names
+  .values()           Field values
+  .entries()          List of {{:key, :value}}
+  .hasField("name")   True if the field exists
+  .merge(other)       Combine fields, other wins
+  .map_keys($k <new>) Rename the fields
+  .map_values($v <e>) Replace each field value
+
+{bold}ANY TYPE METHODS:{reset}
+  .ifEmpty(value)     Value if empty, otherwise the target
+  .ifPresent(value)   Value if not empty, otherwise empty
 
 {bold}FUNCTIONS:{reset}
   now()                               Current timestamp
+  day_of_year(date)                   Day of the year (1-366)
+  is_leap_year(year)                  True for leap years
+  duration(n, "days")                 Duration of n units
+  format_number(n, 2, "_")            Format with thousands separator
+  is_prime(n)                         True for prime numbers
+  fibonacci(n)                        The nth Fibonacci number
   env("VAR")                          Environment variable
   rand()                              Random number
+  uuid()                              Random UUID
   coalesce(a, b, c)                   First non-empty value
+  coalesce_list(list)                 First non-empty item of a list
+  nullif(a, b)                        Empty if a = b, otherwise a
+  git_root()                          Root of the current git repository
+  git_branch()                        Current git branch
+  home_dir()                          Home directory of the current user
+  temp_dir()                          Temporary directory
   replace(str FROM old TO new)        Replace in string
   replace(str PATTERN regex TO new)   Replace in string
   execute(cmd, args)                  Execute external command
+  exec_capture(cmd, args)             Output of external command
 
 {bold}CONTROL FLOW:{reset}
   IF condition THEN a ELSE b END
@@ -152,7 +266,7 @@ fn get_syntax_help(term: bool) -> String {
   size > 10485760
   
   # Recent files (last 24 hours)
-  modified > now() - 86400
+  modified > now() - 1d
   
   # Find in content
   content.contains("TODO")
@@ -175,6 +289,7 @@ fn get_syntax_help(term: bool) -> String {
   - Methods can chain: content.toLower().contains("todo")
   - Omit parentheses for no-arg methods: name.length vs name.length()
   - Use @path for path literals: @src/main.rs
+  - Group and count the results: findit -w 'IS FILE' --group-by extension --count
 
 {bold}FULL DOCUMENTATION:{reset}
   https://github.com/yift/findit/blob/main/docs/syntax/index.md
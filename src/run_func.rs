@@ -2,13 +2,14 @@ use std::io::Write;
 
 use crate::{
     cli_args::CliArgs,
+    clock::check_now_env,
     errors::FindItError,
     filter::make_filters,
     quick_ref::Executor,
     quick_ref::Pager,
     quick_ref::show_syntax_help,
     quick_ref::{default_executor as executor, default_pager as pager},
-    walker::Walker,
+    walker::Walkers,
 };
 
 /// # Errors
@@ -26,16 +27,26 @@ fn run_with_pager_and_executor<W: Write + 'static>(
     if args.help_syntax {
         show_syntax_help(pager, executor);
     } else {
-        let walker = Walker::try_from(args)?;
+        check_now_env(args.strict)?;
+        let walker = Walkers::try_from(args)?;
         let mut stepper = make_filters(args, writer)?;
         walker.walk(&mut stepper)?;
+        // The order by (if any) only shows the files once it is dropped.
+        drop(stepper);
+        let failures = walker.failures();
+        if !failures.is_empty() {
+            return Err(FindItError::EvaluationFailed(failures));
+        }
     }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{os::unix::process::ExitStatusExt, process::ExitStatus};
+    use std::{
+        cell::RefCell, io::Result as IoResult, os::unix::process::ExitStatusExt,
+        process::ExitStatus, rc::Rc,
+    };
 
     use clap::Parser;
 
@@ -63,4 +74,174 @@ mod tests {
 
         run_with_pager_and_executor(&args, writer, TestPager, TestExecutor)
     }
+    #[test]
+    fn run_with_bad_filter_points_to_the_error() {
+        let args = CliArgs::parse_from(vec!["findit", "-w", "size > > 3"]);
+
+        let err = run(&args, vec![]).err().unwrap().to_string();
+
+        assert!(err.contains("(column 8)"), "{err}");
+        assert!(err.ends_with("  size > > 3\n         ^"), "{err}");
+    }
+    #[test]
+    fn run_with_missing_expression_file_fails() {
+        let args = CliArgs::parse_from(vec!["findit", "--expr-file", "/no/such/file.expr"]);
+
+        assert!(run(&args, vec![]).is_err());
+    }
+    #[test]
+    fn run_with_expression_file_that_includes_a_relative_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let filters = dir.path().join("filters");
+        std::fs::create_dir(&filters)?;
+        std::fs::write(filters.join("rust.expr"), "extension = \"rs\"")?;
+        std::fs::write(
+            filters.join("main.expr"),
+            "INCLUDE \"rust.expr\" AND IS FILE",
+        )?;
+        std::fs::write(dir.path().join("main.rs"), "fn main() {}")?;
+        std::fs::write(dir.path().join("notes.txt"), "notes")?;
+        let expr_file = filters.join("main.expr");
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            dir.path().to_str().unwrap_or_default(),
+            "--expr-file",
+            expr_file.to_str().unwrap_or_default(),
+            "-d",
+            "`name`",
+        ]);
+        let output = Rc::new(RefCell::new(vec![]));
+        struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+        impl Write for SharedOutput {
+            fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+            fn flush(&mut self) -> IoResult<()> {
+                Ok(())
+            }
+        }
+
+        run(&args, SharedOutput(output.clone()))?;
+
+        assert_eq!(String::from_utf8_lossy(&output.borrow()), "main.rs\n");
+        Ok(())
+    }
+
+    #[test]
+    fn run_in_strict_mode_fails_on_unreadable_file() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        let file = dir.path().join("binary.dat");
+        std::fs::write(&file, b"\xff\xfe not utf8")?;
+        let file = file.to_str().unwrap_or_default();
+
+        let args = CliArgs::parse_from(vec!["findit", file, "-w", "content.contains(\"utf8\")"]);
+        assert!(run(&args, vec![]).is_ok());
+
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            file,
+            "--strict",
+            "-w",
+            "content.contains(\"utf8\")",
+        ]);
+        let err = run(&args, vec![]).err();
+
+        let Some(FindItError::EvaluationFailed(failures)) = err else {
+            panic!("Expected evaluation failure, got: {err:?}");
+        };
+        assert_eq!(failures.len(), 2);
+        assert!(failures[0].starts_with(&format!("Can not read the content of [{file}]")));
+        assert!(failures[1].starts_with("Filter `content.contains(\"utf8\")` is empty"));
+        Ok(())
+    }
+
+    #[test]
+    fn run_in_strict_mode_fails_on_bad_regex() {
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            "src",
+            "--strict",
+            "-w",
+            "name MATCHES \"(\"",
+        ]);
+
+        let err = run(&args, vec![]).err().unwrap().to_string();
+
+        assert!(
+            err.starts_with("Evaluation failed:\nBad regular expression [(]"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn run_in_strict_mode_fails_on_bad_glob() {
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            "src",
+            "--strict",
+            "-w",
+            "name GLOB \"[z-a]\"",
+        ]);
+
+        let err = run(&args, vec![]).err().unwrap().to_string();
+
+        assert!(
+            err.starts_with("Evaluation failed:\nBad regular expression [^[z-a]$]"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn run_in_strict_mode_without_failures() -> Result<(), FindItError> {
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            "src",
+            "--strict",
+            "-w",
+            "name MATCHES \"^main\"",
+        ]);
+
+        run(&args, vec![])
+    }
+    #[test]
+    fn run_in_strict_mode_fails_on_empty_filter() -> Result<(), FindItError> {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("main.rs"), "fn main() {}")?;
+        std::fs::write(dir.path().join("Makefile"), "all:")?;
+        let root = dir.path().to_str().unwrap_or_default();
+
+        // The last filter is evaluated first.
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            root,
+            "-w",
+            "extension = \"rs\"",
+            "-w",
+            "IS FILE",
+        ]);
+        assert!(run(&args, vec![]).is_ok());
+
+        let args = CliArgs::parse_from(vec![
+            "findit",
+            root,
+            "--strict",
+            "-w",
+            "extension = \"rs\"",
+            "-w",
+            "IS FILE",
+        ]);
+        let err = run(&args, vec![]).err();
+
+        let Some(FindItError::EvaluationFailed(failures)) = err else {
+            panic!("Expected evaluation failure, got: {err:?}");
+        };
+        assert_eq!(
+            failures,
+            vec![format!(
+                "Filter `extension = \"rs\"` is empty for file: [{}]",
+                dir.path().join("Makefile").display()
+            )]
+        );
+        Ok(())
+    }
 }
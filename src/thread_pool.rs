@@ -0,0 +1,203 @@
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, Mutex, PoisonError,
+        mpsc::{self, Receiver, Sender},
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::file_wrapper::FileWrapper;
+
+type Task<T> = Arc<dyn Fn(&FileWrapper) -> T + Send + Sync>;
+
+/// Runs the same task on files using a fixed number of worker threads. The threads are started
+/// once and live as long as the pool, and the results are returned in the order the files were
+/// scheduled.
+pub(crate) struct ThreadPool<T> {
+    jobs: NonZeroUsize,
+    task: Task<T>,
+    files: Option<Sender<(usize, FileWrapper)>>,
+    results: Receiver<(usize, thread::Result<T>)>,
+    workers: Vec<JoinHandle<()>>,
+    ready: BTreeMap<usize, thread::Result<T>>,
+    scheduled: usize,
+    returned: usize,
+}
+impl<T: Send + 'static> ThreadPool<T> {
+    pub(crate) fn new(jobs: NonZeroUsize, task: Task<T>) -> Self {
+        let (files, receiver) = mpsc::channel::<(usize, FileWrapper)>();
+        let (sender, results) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        // With a single job, the files are evaluated by the caller thread.
+        let workers = if jobs.get() == 1 {
+            vec![]
+        } else {
+            (0..jobs.get())
+                .map(|_| {
+                    let receiver = receiver.clone();
+                    let sender = sender.clone();
+                    let task = task.clone();
+                    thread::spawn(move || {
+                        loop {
+                            let file = receiver
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .recv();
+                            let Ok((index, file)) = file else {
+                                break;
+                            };
+                            let result = panic::catch_unwind(AssertUnwindSafe(|| task(&file)));
+                            if sender.send((index, result)).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                })
+                .collect()
+        };
+        Self {
+            jobs,
+            task,
+            files: Some(files),
+            results,
+            workers,
+            ready: BTreeMap::new(),
+            scheduled: 0,
+            returned: 0,
+        }
+    }
+
+    pub(crate) fn jobs(&self) -> usize {
+        self.jobs.get()
+    }
+
+    /// The number of files that were scheduled and whose result was not returned yet.
+    pub(crate) fn pending(&self) -> usize {
+        self.scheduled - self.returned
+    }
+
+    pub(crate) fn schedule(&mut self, file: FileWrapper) {
+        let index = self.scheduled;
+        self.scheduled += 1;
+        match &self.files {
+            Some(files) if !self.workers.is_empty() => {
+                files
+                    .send((index, file))
+                    .expect("The workers live as long as the pool");
+            }
+            _ => {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| (self.task)(&file)));
+                self.ready.insert(index, result);
+            }
+        }
+    }
+
+    /// The result of the next file, if it is ready.
+    pub(crate) fn try_next(&mut self) -> Option<T> {
+        while let Ok((index, result)) = self.results.try_recv() {
+            self.ready.insert(index, result);
+        }
+        self.take_ready()
+    }
+
+    /// The result of the next file, waiting for it if needed. Returns `None` once all the
+    /// results were returned.
+    pub(crate) fn next(&mut self) -> Option<T> {
+        while self.pending() > 0 && !self.ready.contains_key(&self.returned) {
+            let Ok((index, result)) = self.results.recv() else {
+                break;
+            };
+            self.ready.insert(index, result);
+        }
+        self.take_ready()
+    }
+
+    fn take_ready(&mut self) -> Option<T> {
+        let result = self.ready.remove(&self.returned)?;
+        self.returned += 1;
+        Some(result.unwrap_or_else(|err| panic::resume_unwind(err)))
+    }
+
+    /// Run the task on all the files and return the results in the same order as the files.
+    pub(crate) fn map(&mut self, files: &[FileWrapper]) -> Vec<T> {
+        for file in files {
+            self.schedule(file.clone());
+        }
+        files.iter().filter_map(|_| self.next()).collect()
+    }
+}
+impl<T> Drop for ThreadPool<T> {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers once they are done with their current file.
+        self.files = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn thread_pool_keep_the_order() {
+        let files = (0..100)
+            .map(|i| FileWrapper::new(PathBuf::from(format!("file-{i}")), i))
+            .collect::<Vec<_>>();
+        let mut pool = ThreadPool::new(NonZeroUsize::new(4).unwrap(), Arc::new(|file| file.dept()));
+
+        let depths = pool.map(&files);
+
+        assert_eq!(depths, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn thread_pool_with_single_job() {
+        let files = vec![FileWrapper::new(PathBuf::from("a"), 3)];
+        let mut pool = ThreadPool::new(NonZeroUsize::MIN, Arc::new(|file| file.dept() * 2));
+
+        let depths = pool.map(&files);
+
+        assert_eq!(depths, vec![6]);
+    }
+
+    #[test]
+    fn thread_pool_with_no_files() {
+        let mut pool = ThreadPool::new(NonZeroUsize::new(3).unwrap(), Arc::new(|file| file.dept()));
+
+        let depths = pool.map(&[]);
+
+        assert!(depths.is_empty());
+    }
+
+    #[test]
+    fn thread_pool_return_the_results_one_by_one() {
+        let mut pool = ThreadPool::new(NonZeroUsize::new(2).unwrap(), Arc::new(|file| file.dept()));
+
+        for depth in 0..5 {
+            pool.schedule(FileWrapper::new(PathBuf::from("a"), depth));
+        }
+        assert_eq!(pool.pending(), 5);
+        let depths = std::iter::from_fn(|| pool.next()).collect::<Vec<_>>();
+
+        assert_eq!(depths, (0..5).collect::<Vec<_>>());
+        assert_eq!(pool.pending(), 0);
+        assert_eq!(pool.next(), None);
+    }
+
+    #[test]
+    fn thread_pool_with_single_job_is_ready_at_once() {
+        let mut pool = ThreadPool::new(NonZeroUsize::MIN, Arc::new(|file| file.dept()));
+
+        pool.schedule(FileWrapper::new(PathBuf::from("a"), 7));
+
+        assert_eq!(pool.try_next(), Some(7));
+        assert_eq!(pool.try_next(), None);
+    }
+}
@@ -1,12 +1,16 @@
 use std::{
+    cmp::Ordering,
     ffi::OsStr,
     fmt::Display,
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
-    rc::Rc,
+    sync::Arc,
     time::SystemTime,
 };
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, TimeDelta};
+use regex::Regex;
+use serde::{Serialize, Serializer};
 
 use crate::{
     class_type::{Class, ClassType},
@@ -14,38 +18,212 @@ use crate::{
 };
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
-pub(crate) enum Value {
+pub enum Value {
     String(String),
     Path(PathBuf),
     Number(u64),
+    Float(Float),
     Bool(bool),
     Date(DateTime<Local>),
+    Duration(TimeDelta),
     List(List),
     Class(Class),
+    Bytes(Vec<u8>),
+    Regex(RegexValue),
     Empty,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
-pub(crate) struct List {
+/// The duration of `amount` units, where the unit is one of `s`, `m`, `h`, `d` or `w` (or their full names).
+pub(crate) fn duration_of(amount: u64, unit: &str) -> Option<TimeDelta> {
+    let amount = i64::try_from(amount).ok()?;
+    match unit.to_lowercase().as_str() {
+        "s" | "sec" | "secs" | "second" | "seconds" => TimeDelta::try_seconds(amount),
+        "m" | "min" | "mins" | "minute" | "minutes" => TimeDelta::try_minutes(amount),
+        "h" | "hour" | "hours" => TimeDelta::try_hours(amount),
+        "d" | "day" | "days" => TimeDelta::try_days(amount),
+        "w" | "week" | "weeks" => TimeDelta::try_weeks(amount),
+        _ => None,
+    }
+}
+
+/// Parse a duration like `45s`, `2h` or `1d 2h 30m` (the parts can also be written with no spaces, like `2h30m`).
+pub(crate) fn parse_duration(text: &str) -> Option<TimeDelta> {
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    let mut total = TimeDelta::zero();
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|chr: char| !chr.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return None;
+        }
+        let amount = rest[..digits].parse().ok()?;
+        rest = rest[digits..].trim_start();
+        let letters = rest
+            .find(|chr: char| !chr.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        total = total.checked_add(&duration_of(amount, &rest[..letters])?)?;
+        rest = rest[letters..].trim_start();
+    }
+    Some(total)
+}
+
+fn format_duration(duration: &TimeDelta, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if duration < &TimeDelta::zero() {
+        "-".fmt(f)?;
+    }
+    let seconds = duration.num_seconds().unsigned_abs();
+    let parts = [
+        (seconds / 86400, "d"),
+        (seconds / 3600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+    let mut parts = parts.iter().filter(|(amount, _)| *amount > 0).peekable();
+    if parts.peek().is_none() {
+        return "0s".fmt(f);
+    }
+    for (index, (amount, unit)) in parts.enumerate() {
+        if index > 0 {
+            " ".fmt(f)?;
+        }
+        write!(f, "{amount}{unit}")?;
+    }
+    Ok(())
+}
+
+/// A compiled regular expression, compared (and hashed) by its pattern.
+#[derive(Debug, Clone)]
+pub struct RegexValue(Arc<Regex>);
+impl RegexValue {
+    pub fn new(regex: Regex) -> Self {
+        Self(Arc::new(regex))
+    }
+    pub fn get(&self) -> &Regex {
+        &self.0
+    }
+}
+impl PartialEq for RegexValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+impl Eq for RegexValue {}
+impl PartialOrd for RegexValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for RegexValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_str().cmp(other.0.as_str())
+    }
+}
+impl Hash for RegexValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_str().hash(state);
+    }
+}
+
+/// A finite floating point number, so it can be compared, sorted and hashed like any other value.
+#[derive(Debug, Clone, Copy)]
+pub struct Float(f64);
+impl Float {
+    /// Returns `None` for NaN and infinite numbers.
+    pub fn new(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        // Avoid -0 so it will be equal to 0.
+        Some(Self(if value == 0.0 { 0.0 } else { value }))
+    }
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+}
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Float {}
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Float {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+impl Hash for Float {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+impl Display for Float {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct List {
     items: LazyList<Value>,
-    item_type: Rc<ValueType>,
+    item_type: Arc<ValueType>,
+}
+
+// An empty list literal (`[]`) has no items type, so it can be equal to any other list.
+impl PartialEq for List {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for List {}
+
+impl PartialOrd for List {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for List {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.items.cmp(&other.items).then_with(|| {
+            match (self.item_type.as_ref(), other.item_type.as_ref()) {
+                (ValueType::Empty, _) | (_, ValueType::Empty) => std::cmp::Ordering::Equal,
+                (left, right) => left.cmp(right),
+            }
+        })
+    }
+}
+
+impl std::hash::Hash for List {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.items.hash(state);
+    }
 }
 
 impl List {
     pub(crate) fn new_lazy(
-        item_type: Rc<ValueType>,
-        items: impl Iterator<Item = Value> + 'static,
+        item_type: Arc<ValueType>,
+        items: impl Iterator<Item = Value> + Send + 'static,
     ) -> Self {
-        let items: Box<dyn Iterator<Item = Value>> = Box::new(items);
+        let items: Box<dyn Iterator<Item = Value> + Send> = Box::new(items);
         let items = items.into();
 
         Self { items, item_type }
     }
-    pub(crate) fn new_eager(item_type: Rc<ValueType>, items: impl Iterator<Item = Value>) -> Self {
+    pub(crate) fn new_eager(item_type: Arc<ValueType>, items: impl Iterator<Item = Value>) -> Self {
         let items = items.collect::<Vec<_>>();
         Self::new_from_vec(item_type, items)
     }
-    pub(crate) fn new_from_vec(item_type: Rc<ValueType>, items: Vec<Value>) -> Self {
+    pub(crate) fn new_from_vec(item_type: Arc<ValueType>, items: Vec<Value>) -> Self {
         let items = items.into();
 
         Self { items, item_type }
@@ -60,6 +238,13 @@ impl List {
         self.items
     }
 }
+impl IntoIterator for List {
+    type Item = Value;
+    type IntoIter = Box<dyn Iterator<Item = Value>>;
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.items.into_iter())
+    }
+}
 impl From<&Path> for Value {
     fn from(value: &Path) -> Self {
         Value::Path(value.to_path_buf())
@@ -105,17 +290,33 @@ impl From<u32> for Value {
     }
 }
 
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Float::new(value).map_or(Value::Empty, Value::Float)
+    }
+}
+
 impl From<DateTime<Local>> for Value {
     fn from(value: DateTime<Local>) -> Self {
         Value::Date(value)
     }
 }
+impl From<TimeDelta> for Value {
+    fn from(value: TimeDelta) -> Self {
+        Value::Duration(value)
+    }
+}
 impl From<SystemTime> for Value {
     fn from(value: SystemTime) -> Self {
         let date: DateTime<Local> = value.into();
         date.into()
     }
 }
+impl From<Vec<u8>> for Value {
+    fn from(value: Vec<u8>) -> Self {
+        Value::Bytes(value)
+    }
+}
 impl From<bool> for Value {
     fn from(value: bool) -> Self {
         Value::Bool(value)
@@ -143,24 +344,51 @@ impl Display for Value {
             Value::Empty => Ok(()),
             Value::Bool(b) => write!(f, "{b}"),
             Value::Number(n) => write!(f, "{n}"),
+            Value::Float(n) => write!(f, "{n}"),
             Value::Path(p) => write!(f, "{}", p.as_os_str().to_str().unwrap_or_default()),
             Value::String(s) => write!(f, "{s}"),
             Value::Date(dt) => write!(f, "{}", dt.format("%d/%b/%Y %H:%M:%S")),
+            Value::Duration(duration) => format_duration(duration, f),
             Value::List(lst) => write!(f, "{}", lst.items),
             Value::Class(cls) => write!(f, "{}", cls),
+            Value::Bytes(bytes) => bytes.iter().try_for_each(|b| write!(f, "{b:02x}")),
+            Value::Regex(regex) => write!(f, "{}", regex.get()),
+        }
+    }
+}
+
+/// Values that have a JSON counterpart (strings, numbers, booleans, lists and classes) are
+/// serialized as it, dates as RFC 3339 strings, empty values as null and all the others as
+/// their string representation.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Empty => serializer.serialize_none(),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Number(n) => serializer.serialize_u64(*n),
+            Value::Float(n) => serializer.serialize_f64(n.get()),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Date(dt) => serializer.serialize_str(&dt.to_rfc3339()),
+            Value::List(lst) => serializer.collect_seq(lst.clone()),
+            Value::Class(cls) => serializer.collect_map(cls.fields()),
+            _ => serializer.collect_str(self),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
-pub(crate) enum ValueType {
+pub enum ValueType {
     Bool,
     Number,
+    Float,
     Path,
     String,
     Date,
-    List(Rc<ValueType>),
-    Class(Rc<ClassType>),
+    Duration,
+    List(Arc<ValueType>),
+    Class(Arc<ClassType>),
+    Bytes,
+    Regex,
     Empty,
 }
 
@@ -170,11 +398,15 @@ impl Display for ValueType {
             ValueType::Empty => "empty".fmt(f),
             ValueType::Bool => "boolean".fmt(f),
             ValueType::Date => "date".fmt(f),
+            ValueType::Duration => "duration".fmt(f),
             ValueType::Number => "number".fmt(f),
+            ValueType::Float => "float".fmt(f),
             ValueType::Path => "path".fmt(f),
             ValueType::String => "string".fmt(f),
             ValueType::List(tp) => write!(f, "list<{tp}>"),
             ValueType::Class(tp) => tp.fmt(f),
+            ValueType::Bytes => "bytes".fmt(f),
+            ValueType::Regex => "regex".fmt(f),
         }
     }
 }
@@ -189,18 +421,98 @@ mod tests {
     fn test_display_value_type() -> Result<(), FindItError> {
         assert_eq!(ValueType::Bool.to_string(), "boolean");
         assert_eq!(ValueType::Number.to_string(), "number");
+        assert_eq!(ValueType::Float.to_string(), "float");
         assert_eq!(ValueType::Path.to_string(), "path");
         assert_eq!(ValueType::String.to_string(), "string");
         assert_eq!(ValueType::Date.to_string(), "date");
+        assert_eq!(ValueType::Duration.to_string(), "duration");
         assert_eq!(ValueType::Empty.to_string(), "empty");
+        assert_eq!(ValueType::Bytes.to_string(), "bytes");
+        assert_eq!(ValueType::Regex.to_string(), "regex");
         assert_eq!(
-            ValueType::List(Rc::new(ValueType::Path)).to_string(),
+            ValueType::List(Arc::new(ValueType::Path)).to_string(),
             "list<path>"
         );
         assert_eq!(
-            ValueType::Class(Rc::new(ClassType::new(&[]))).to_string(),
+            ValueType::Class(Arc::new(ClassType::new(&[]))).to_string(),
             "class<>"
         );
         Ok(())
     }
+    #[test]
+    fn test_serialize() -> Result<(), serde_json::Error> {
+        let class = Arc::new(ClassType::new(&[
+            ("name".into(), ValueType::String),
+            ("size".into(), ValueType::Number),
+        ]));
+        let value = Value::List(List::new_from_vec(
+            Arc::new(ValueType::Class(class.clone())),
+            vec![Value::Class(Class::new(
+                &class,
+                vec![Value::String("a.txt".into()), Value::Number(12)],
+            ))],
+        ));
+        assert_eq!(
+            serde_json::to_string(&value)?,
+            r#"[{"name":"a.txt","size":12}]"#
+        );
+        assert_eq!(serde_json::to_string(&Value::from(2.5))?, "2.5");
+        assert_eq!(serde_json::to_string(&Value::Bool(true))?, "true");
+        assert_eq!(serde_json::to_string(&Value::Empty)?, "null");
+        assert_eq!(
+            serde_json::to_string(&Value::Path(PathBuf::from("a/b")))?,
+            r#""a/b""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Value::Duration(TimeDelta::seconds(45)))?,
+            r#""45s""#
+        );
+        Ok(())
+    }
+    #[test]
+    fn test_float() {
+        assert_eq!(Value::from(2.5).to_string(), "2.5");
+        assert_eq!(Value::from(2.0).to_string(), "2");
+        assert_eq!(Value::from(-0.0), Value::from(0.0));
+        assert_eq!(Value::from(f64::NAN), Value::Empty);
+        assert_eq!(Value::from(f64::INFINITY), Value::Empty);
+        assert!(Value::from(-1.5) < Value::from(0.25));
+    }
+    #[test]
+    fn test_display_duration() {
+        let display = |seconds| Value::Duration(TimeDelta::seconds(seconds)).to_string();
+        assert_eq!(display(0), "0s");
+        assert_eq!(display(45), "45s");
+        assert_eq!(display(7 * 86400), "7d");
+        assert_eq!(display(86400 + 2 * 3600 + 30), "1d 2h 30s");
+        assert_eq!(display(-90), "-1m 30s");
+    }
+    #[test]
+    fn test_duration_of() {
+        assert_eq!(duration_of(2, "h"), Some(TimeDelta::hours(2)));
+        assert_eq!(duration_of(3, "Days"), Some(TimeDelta::days(3)));
+        assert_eq!(duration_of(1, "w"), Some(TimeDelta::weeks(1)));
+        assert_eq!(duration_of(1, "y"), None);
+        assert_eq!(duration_of(u64::MAX, "s"), None);
+    }
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("45s"), Some(TimeDelta::seconds(45)));
+        assert_eq!(parse_duration("2h"), Some(TimeDelta::hours(2)));
+        assert_eq!(
+            parse_duration("1d 2h 30m"),
+            Some(TimeDelta::days(1) + TimeDelta::hours(2) + TimeDelta::minutes(30))
+        );
+        assert_eq!(parse_duration("2h30m"), Some(TimeDelta::minutes(150)));
+        assert_eq!(parse_duration(" 3 days "), Some(TimeDelta::days(3)));
+        assert_eq!(parse_duration("2y"), None);
+        assert_eq!(parse_duration("2"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+    #[test]
+    fn test_display_bytes() {
+        assert_eq!(Value::Bytes(vec![0, 15, 16, 255]).to_string(), "000f10ff");
+        assert_eq!(Value::Bytes(vec![]).to_string(), "");
+    }
 }
@@ -1,10 +1,21 @@
-use std::{fs, path::PathBuf, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
+
+use ignore::gitignore::Gitignore;
 
 use crate::{
     cli_args::CliArgs,
-    debugger::{Debugger, create_debugger},
+    content::ContentOptions,
+    debugger::{Debugger, create_debugger, create_strict_debugger},
     errors::FindItError,
     file_wrapper::FileWrapper,
+    output::color_output,
 };
 
 #[derive(Debug)]
@@ -13,23 +24,95 @@ pub(crate) struct Walker {
     depth: usize,
     node_first: bool,
     max_depth: Option<usize>,
-    debugger: Rc<Box<dyn Debugger>>,
+    no_hidden: bool,
+    respect_gitignore: bool,
+    content: ContentOptions,
+    color: bool,
+    debug_methods: bool,
+    ignores: Option<Rc<IgnoreFrame>>,
+    canonical: PathBuf,
+    overlaps: Rc<Overlaps>,
+    debugger: Arc<Box<dyn Debugger>>,
+}
+
+/// The canonical paths of all the roots, so a file that is under more than one root is only
+/// reported once. Only the files that are under more than one root are remembered.
+#[derive(Debug)]
+struct Overlaps {
+    roots: Vec<PathBuf>,
+    seen: RefCell<HashSet<PathBuf>>,
+}
+
+/// All the walkers, one for each root.
+#[derive(Debug)]
+pub(crate) struct Walkers {
+    walkers: Vec<Walker>,
+    debugger: Arc<Box<dyn Debugger>>,
+}
+
+/// The `.gitignore` rules of a directory, linked to the rules of its parent directories.
+#[derive(Debug)]
+struct IgnoreFrame {
+    matcher: Gitignore,
+    previous: Option<Rc<IgnoreFrame>>,
 }
 pub(crate) trait Walk {
     fn step(&mut self, file: &FileWrapper);
     fn enough(&self) -> bool;
+    /// Called once the walk is done, to pass on the files that are still waiting.
+    fn finish(&mut self) {}
 }
+
 impl Walker {
-    pub(crate) fn walk(&self, stepper: &mut Box<dyn Walk>) -> Result<(), FindItError> {
-        if stepper.enough() {
-            return Ok(());
+    fn read_ignores(&self) -> Option<Rc<IgnoreFrame>> {
+        let file = self.root.join(".gitignore");
+        if !self.respect_gitignore || !file.is_file() {
+            return self.ignores.clone();
         }
-        if !self.node_first {
-            stepper.step(&FileWrapper::new_with_debugger(
+        let (matcher, error) = Gitignore::new(&file);
+        if let Some(error) = error {
+            self.debugger
+                .log(&|| format!("Error reading [{}]: {}", file.display(), error));
+        }
+        Some(Rc::new(IgnoreFrame {
+            matcher,
+            previous: self.ignores.clone(),
+        }))
+    }
+
+    fn is_ignored(ignores: &Option<Rc<IgnoreFrame>>, path: &Path) -> bool {
+        let is_dir = path.is_dir();
+        // The closest .gitignore wins, so a negation in a sub directory can un-ignore a file.
+        std::iter::successors(ignores.as_deref(), |frame| frame.previous.as_deref())
+            .map(|frame| frame.matcher.matched(path, is_dir))
+            .find(|m| !m.is_none())
+            .is_some_and(|m| m.is_ignore())
+    }
+
+    fn step(&self, stepper: &mut Box<dyn Walk>) {
+        if !self.overlaps.first_time(&self.canonical) {
+            self.debugger
+                .log(&|| format!("Skipping already seen path: [{}]", self.root.display()));
+            return;
+        }
+        stepper.step(
+            &FileWrapper::new_with_debugger(
                 self.root.clone(),
                 self.depth,
                 &self.debugger,
-            ));
+                self.content,
+            )
+            .with_color(self.color)
+            .with_debug_methods(self.debug_methods),
+        );
+    }
+
+    fn walk(&self, stepper: &mut Box<dyn Walk>) -> Result<(), FindItError> {
+        if stepper.enough() {
+            return Ok(());
+        }
+        if !self.node_first {
+            self.step(stepper);
         }
 
         if self.depth < self.max_depth.unwrap_or(usize::MAX) && self.root.is_dir() {
@@ -40,14 +123,33 @@ impl Walker {
                     self.depth
                 )
             });
+            let ignores = self.read_ignores();
             let paths = fs::read_dir(&self.root)?;
             for path in paths {
                 let path = path?;
+                if self.no_hidden && path.file_name().as_encoded_bytes().starts_with(b".") {
+                    self.debugger
+                        .log(&|| format!("Skipping hidden path: [{}]", path.path().display()));
+                    continue;
+                }
+                if Self::is_ignored(&ignores, &path.path()) {
+                    self.debugger
+                        .log(&|| format!("Skipping ignored path: [{}]", path.path().display()));
+                    continue;
+                }
                 let walker = Walker {
                     depth: self.depth + 1,
                     root: path.path(),
                     node_first: self.node_first,
                     max_depth: self.max_depth,
+                    no_hidden: self.no_hidden,
+                    respect_gitignore: self.respect_gitignore,
+                    content: self.content,
+                    color: self.color,
+                    debug_methods: self.debug_methods,
+                    ignores: ignores.clone(),
+                    canonical: self.canonical.join(path.file_name()),
+                    overlaps: self.overlaps.clone(),
                     debugger: self.debugger.clone(),
                 };
                 walker.walk(stepper)?;
@@ -55,50 +157,451 @@ impl Walker {
         }
 
         if self.node_first && !stepper.enough() {
-            stepper.step(&FileWrapper::new_with_debugger(
-                self.root.clone(),
-                self.depth,
-                &self.debugger,
-            ));
+            self.step(stepper);
         }
 
         Ok(())
     }
 }
-impl TryFrom<&CliArgs> for Walker {
+impl Overlaps {
+    /// False if the path was already reported under another root.
+    fn first_time(&self, path: &Path) -> bool {
+        let under = self
+            .roots
+            .iter()
+            .filter(|root| path.starts_with(root))
+            .count();
+        under < 2 || self.seen.borrow_mut().insert(path.to_path_buf())
+    }
+}
+impl Walkers {
+    pub(crate) fn walk(&self, stepper: &mut Box<dyn Walk>) -> Result<(), FindItError> {
+        for walker in &self.walkers {
+            if stepper.enough() {
+                break;
+            }
+            walker.walk(stepper)?;
+        }
+        stepper.finish();
+        Ok(())
+    }
+
+    /// The evaluation failures of the walk (only kept in strict mode).
+    pub(crate) fn failures(&self) -> Vec<String> {
+        self.debugger.failures()
+    }
+}
+impl TryFrom<&CliArgs> for Walkers {
     type Error = FindItError;
     fn try_from(value: &CliArgs) -> Result<Self, Self::Error> {
-        let root = match &value.root {
-            Some(path) => path.clone(),
-            None => PathBuf::from("."),
+        Self::new(value, value.skip_hidden())
+    }
+}
+
+impl Walkers {
+    /// Creates the walkers of the arguments roots, skipping the hidden files if `no_hidden` is set.
+    fn new(value: &CliArgs, no_hidden: bool) -> Result<Self, FindItError> {
+        let roots = if value.roots.is_empty() {
+            vec![PathBuf::from(".")]
+        } else {
+            value.roots.clone()
         };
+        if let Some(root) = roots.iter().find(|root| !root.exists()) {
+            return Err(FindItError::NoSuchFile(root.clone()));
+        }
         let debugger = create_debugger(value.debug_output_file.as_ref())?;
-        if root.exists() {
-            Ok(Walker {
+        let debugger = Arc::new(if value.strict {
+            create_strict_debugger(debugger)
+        } else {
+            debugger
+        });
+        // Each root is canonicalized once, the paths under it are joined to its canonical path.
+        let canonical = roots
+            .iter()
+            .map(|root| fs::canonicalize(root).unwrap_or_else(|_| root.clone()))
+            .collect::<Vec<_>>();
+        let overlaps = Rc::new(Overlaps {
+            roots: canonical.clone(),
+            seen: RefCell::default(),
+        });
+        let content = ContentOptions {
+            mmap_threshold: value.mmap_threshold,
+            lossy: value.lossy_content,
+        };
+        let walkers = roots
+            .into_iter()
+            .zip(canonical)
+            .map(|(root, canonical)| Walker {
                 root,
                 depth: 0,
                 node_first: value.node_first,
                 max_depth: value.max_depth,
-                debugger: Rc::new(debugger),
+                no_hidden,
+                respect_gitignore: value.respect_gitignore,
+                content,
+                color: color_output(value),
+                debug_methods: value.debug_methods,
+                ignores: None,
+                canonical,
+                overlaps: overlaps.clone(),
+                debugger: debugger.clone(),
             })
-        } else {
-            Err(FindItError::NoSuchFile(root))
-        }
+            .collect();
+        Ok(Walkers { walkers, debugger })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::Path;
+
     use clap::Parser;
 
     use super::*;
+    use crate::cli_args::skip_hidden;
 
     #[test]
     fn try_from_nop_such_file() {
         let args = CliArgs::parse_from(vec!["-", "foo/bar/no/such/file"]);
 
-        let err = Walker::try_from(&args).err();
+        let err = Walkers::try_from(&args).err();
 
         assert!(err.is_some())
     }
+
+    #[test]
+    fn try_from_with_one_missing_root() {
+        let args = CliArgs::parse_from(vec!["-", "src", "foo/bar/no/such/file"]);
+
+        let err = Walkers::try_from(&args).err();
+
+        assert!(err.is_some())
+    }
+
+    #[test]
+    fn try_from_passes_the_content_options() -> Result<(), FindItError> {
+        let args =
+            CliArgs::parse_from(vec!["-", "--mmap-threshold", "0", "--lossy-content", "src"]);
+
+        let walkers = Walkers::try_from(&args)?;
+
+        assert_eq!(
+            walkers.walkers[0].content,
+            ContentOptions {
+                mmap_threshold: 0,
+                lossy: true,
+            }
+        );
+        Ok(())
+    }
+
+    struct Collector {
+        paths: Rc<RefCell<Vec<PathBuf>>>,
+    }
+    impl Walk for Collector {
+        fn step(&mut self, file: &FileWrapper) {
+            self.paths.borrow_mut().push(file.path().to_path_buf());
+        }
+        fn enough(&self) -> bool {
+            false
+        }
+    }
+
+    fn walk_tree(extra_args: &[&str]) -> Result<Vec<PathBuf>, FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git").join("objects"))?;
+        fs::write(root.join(".git").join("HEAD"), "ref: refs/heads/main")?;
+        fs::create_dir_all(root.join("src"))?;
+        fs::write(root.join("src").join("main.rs"), "")?;
+        fs::write(root.join("src").join(".hidden"), "")?;
+        fs::write(root.join(".env"), "")?;
+
+        walk(root, extra_args)
+    }
+
+    fn walk(root: &Path, extra_args: &[&str]) -> Result<Vec<PathBuf>, FindItError> {
+        let mut args = vec!["findit"];
+        args.extend_from_slice(extra_args);
+        args.push(root.to_str().unwrap());
+        walk_roots(root, &args)
+    }
+
+    fn walk_roots(root: &Path, args: &[&str]) -> Result<Vec<PathBuf>, FindItError> {
+        let args = CliArgs::parse_from(args);
+        collect(root, &Walkers::try_from(&args)?)
+    }
+
+    fn collect(root: &Path, walker: &Walkers) -> Result<Vec<PathBuf>, FindItError> {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let mut stepper: Box<dyn Walk> = Box::new(Collector {
+            paths: paths.clone(),
+        });
+        walker.walk(&mut stepper)?;
+
+        let mut found = paths
+            .borrow()
+            .iter()
+            .filter_map(|p| p.strip_prefix(root).ok())
+            .map(|p| p.to_path_buf())
+            .collect::<Vec<_>>();
+        found.sort();
+        Ok(found)
+    }
+
+    #[test]
+    fn walk_skip_hidden_by_default() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".git"))?;
+        fs::create_dir_all(root.join("src"))?;
+        fs::write(root.join("src").join(".hidden"), "")?;
+        fs::write(root.join(".env"), "")?;
+        let args = CliArgs::parse_from(["findit", root.to_str().unwrap()]);
+        // The default without the FINDIT_INCLUDE_HIDDEN environment variable.
+        let no_hidden = skip_hidden(args.hidden, args.no_hidden, None);
+        let walkers = Walkers::new(&args, no_hidden)?;
+
+        assert_eq!(
+            collect(root, &walkers)?,
+            vec![PathBuf::from(""), PathBuf::from("src")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_hidden_include_hidden() -> Result<(), FindItError> {
+        let found = walk_tree(&["--hidden"])?;
+
+        assert!(found.contains(&PathBuf::from(".git/objects")));
+        assert!(found.contains(&PathBuf::from(".env")));
+        assert!(found.contains(&PathBuf::from("src/.hidden")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_no_hidden_skip_hidden_subtree() -> Result<(), FindItError> {
+        let found = walk_tree(&["--no-hidden"])?;
+
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from(""),
+                PathBuf::from("src"),
+                PathBuf::from("src/main.rs"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_no_hidden_with_max_depth() -> Result<(), FindItError> {
+        let found = walk_tree(&["--ignore-hidden", "--max-depth", "1"])?;
+
+        assert_eq!(found, vec![PathBuf::from(""), PathBuf::from("src")]);
+
+        Ok(())
+    }
+
+    fn git_repo() -> Result<tempfile::TempDir, FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::write(root.join(".gitignore"), "target/\n*.log\n!keep.log\n")?;
+        fs::create_dir_all(root.join("target").join("debug"))?;
+        fs::write(root.join("target").join("debug").join("app"), "")?;
+        fs::create_dir_all(root.join("src").join("gen"))?;
+        fs::write(root.join("src").join(".gitignore"), "gen\n!debug.log\n")?;
+        fs::write(root.join("src").join("lib.rs"), "")?;
+        fs::write(root.join("src").join("gen").join("out.rs"), "")?;
+        fs::write(root.join("src").join("debug.log"), "")?;
+        fs::write(root.join("src").join("trace.log"), "")?;
+        fs::write(root.join("build.log"), "")?;
+        fs::write(root.join("keep.log"), "")?;
+        Ok(temp_dir)
+    }
+
+    #[test]
+    fn walk_respect_gitignore() -> Result<(), FindItError> {
+        let temp_dir = git_repo()?;
+
+        let found = walk(temp_dir.path(), &["--respect-gitignore", "--hidden"])?;
+
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from(""),
+                PathBuf::from(".gitignore"),
+                PathBuf::from("keep.log"),
+                PathBuf::from("src"),
+                PathBuf::from("src/.gitignore"),
+                PathBuf::from("src/debug.log"),
+                PathBuf::from("src/lib.rs"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_without_gitignore_list_everything() -> Result<(), FindItError> {
+        let temp_dir = git_repo()?;
+
+        let found = walk(temp_dir.path(), &[])?;
+
+        assert!(found.contains(&PathBuf::from("target/debug/app")));
+        assert!(found.contains(&PathBuf::from("src/gen/out.rs")));
+        assert!(found.contains(&PathBuf::from("build.log")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_multiple_roots() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("one"))?;
+        fs::write(root.join("one").join("a.txt"), "")?;
+        fs::create_dir_all(root.join("two").join("sub"))?;
+        fs::write(root.join("two").join("sub").join("b.txt"), "")?;
+        fs::write(root.join("three.txt"), "")?;
+        let one = root.join("one");
+        let two = root.join("two");
+
+        let found = walk_roots(
+            root,
+            &["findit", one.to_str().unwrap(), two.to_str().unwrap()],
+        )?;
+
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from("one"),
+                PathBuf::from("one/a.txt"),
+                PathBuf::from("two"),
+                PathBuf::from("two/sub"),
+                PathBuf::from("two/sub/b.txt"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_overlapping_roots_report_each_file_once() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("one").join("sub"))?;
+        fs::write(root.join("one").join("sub").join("a.txt"), "")?;
+        fs::write(root.join("one").join("b.txt"), "")?;
+        let one = root.join("one");
+        let sub = root.join("one").join("sub");
+
+        let found = walk_roots(
+            root,
+            &["findit", sub.to_str().unwrap(), one.to_str().unwrap()],
+        )?;
+
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from("one"),
+                PathBuf::from("one/b.txt"),
+                PathBuf::from("one/sub"),
+                PathBuf::from("one/sub/a.txt"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_same_root_twice_report_each_file_once() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("one"))?;
+        fs::write(root.join("one").join("a.txt"), "")?;
+        let one = root.join("one");
+        let again = root.join("one").join(".");
+
+        let found = walk_roots(
+            root,
+            &["findit", one.to_str().unwrap(), again.to_str().unwrap()],
+        )?;
+
+        assert_eq!(
+            found,
+            vec![PathBuf::from("one"), PathBuf::from("one/a.txt")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_multiple_roots_reset_depth() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("one").join("sub"))?;
+        fs::write(root.join("one").join("sub").join("a.txt"), "")?;
+        fs::write(root.join("one").join("b.txt"), "")?;
+        let one = root.join("one");
+        let sub = root.join("one").join("sub");
+
+        let found = walk_roots(
+            root,
+            &[
+                "findit",
+                "--max-depth",
+                "1",
+                one.to_str().unwrap(),
+                sub.to_str().unwrap(),
+            ],
+        )?;
+
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from("one"),
+                PathBuf::from("one/b.txt"),
+                PathBuf::from("one/sub"),
+                PathBuf::from("one/sub/a.txt"),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn walk_hidden_root_inside_another_root() -> Result<(), FindItError> {
+        let temp_dir = tempfile::tempdir()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".github"))?;
+        fs::write(root.join(".github").join("ci.yml"), "")?;
+        fs::write(root.join("a.txt"), "")?;
+        let github = root.join(".github");
+
+        let found = walk_roots(
+            root,
+            &[
+                "findit",
+                "--no-hidden",
+                root.to_str().unwrap(),
+                github.to_str().unwrap(),
+            ],
+        )?;
+
+        assert_eq!(
+            found,
+            vec![
+                PathBuf::from(""),
+                PathBuf::from(".github"),
+                PathBuf::from(".github/ci.yml"),
+                PathBuf::from("a.txt"),
+            ]
+        );
+
+        Ok(())
+    }
 }
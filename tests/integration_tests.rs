@@ -86,6 +86,27 @@ fn test_with_debugger() -> Result<(), FindItError> {
     Ok(())
 }
 
+#[test]
+fn test_fixed_now() -> Result<(), FindItError> {
+    let output = Command::new(env!("CARGO_BIN_EXE_findit"))
+        .env("FINDIT_NOW", "2025-01-01T12:00:00Z")
+        .args([
+            "tests/test_cases/display/test_files/number-122.txt",
+            "--max-depth",
+            "0",
+            "-d",
+            "`format(now() AS \"%s\")` `(@(2024-12-31T12:00:00Z)).age()`",
+        ])
+        .output()?;
+
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "1735732800 86400\n"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn integration_tests() -> Result<(), FindItError> {
     let tests_files = find_integration_tests_files("tests/test_cases")?;
@@ -0,0 +1,150 @@
+use std::{fs, path::Path};
+
+use findit_cli::{FileMetadata, FindItError, Value, evaluate, expression_ast, find};
+
+fn create_tree(root: &Path) -> Result<(), FindItError> {
+    fs::create_dir_all(root.join("src/inner"))?;
+    fs::write(root.join("README.md"), "# Hello\n")?;
+    fs::write(root.join("src/main.rs"), "fn main() {}\n")?;
+    fs::write(
+        root.join("src/inner/lib.rs"),
+        "pub mod one;\npub mod two;\n",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn find_returns_the_paths_of_the_matching_files() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+    create_tree(dir.path())?;
+
+    let mut found = find(dir.path(), "extension = \"rs\"", None)?.collect::<Vec<_>>();
+    found.sort();
+
+    assert_eq!(
+        found,
+        vec![
+            Value::Path(dir.path().join("src/inner/lib.rs")),
+            Value::Path(dir.path().join("src/main.rs")),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn find_returns_the_output_expression() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+    create_tree(dir.path())?;
+
+    let mut found = find(dir.path(), "IS FILE AND size > 10", Some("name.toUpper()"))?
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    found.sort();
+
+    assert_eq!(found, vec!["LIB.RS", "MAIN.RS"]);
+    Ok(())
+}
+
+#[test]
+fn find_returns_typed_values() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+    create_tree(dir.path())?;
+
+    let found = find(dir.path(), "name = \"lib.rs\"", Some("size"))?.collect::<Vec<_>>();
+
+    assert_eq!(found, vec![Value::Number(26)]);
+    Ok(())
+}
+
+#[test]
+fn find_returns_lists_and_classes() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+    create_tree(dir.path())?;
+
+    let found = find(
+        dir.path(),
+        "name = \"lib.rs\"",
+        Some("{:name name, :lines content.lines()}"),
+    )?
+    .collect::<Vec<_>>();
+
+    let [Value::Class(class)] = found.as_slice() else {
+        panic!("Expected a single class, got: {found:?}");
+    };
+    let fields = class.fields().collect::<Vec<_>>();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0], ("name", &Value::String("lib.rs".into())));
+    let ("lines", Value::List(lines)) = fields[1] else {
+        panic!("Expected a list of lines, got: {:?}", fields[1]);
+    };
+    let lines = lines.clone().into_iter().collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            Value::String("pub mod one;".into()),
+            Value::String("pub mod two;".into()),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn find_with_no_match_is_empty() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+    create_tree(dir.path())?;
+
+    assert_eq!(find(dir.path(), "extension = \"py\"", None)?.count(), 0);
+    Ok(())
+}
+
+#[test]
+fn find_with_bad_filter_fails() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+
+    let err = find(dir.path(), "size > > 3", None).err();
+
+    assert!(matches!(err, Some(FindItError::ParserError(_))), "{err:?}");
+    Ok(())
+}
+
+#[test]
+fn find_with_bad_output_fails() -> Result<(), FindItError> {
+    let dir = tempfile::tempdir()?;
+
+    let err = find(dir.path(), "IS FILE", Some("size.toUpper()")).err();
+
+    assert!(
+        matches!(err, Some(FindItError::BadExpression(_))),
+        "{err:?}"
+    );
+    Ok(())
+}
+
+#[test]
+fn find_with_no_such_root_fails() {
+    let err = find(Path::new("/no/such/dir"), "IS FILE", None).err();
+
+    assert!(matches!(err, Some(FindItError::NoSuchFile(_))), "{err:?}");
+}
+
+#[test]
+fn evaluate_uses_the_metadata_instead_of_the_file_system() -> Result<(), FindItError> {
+    let metadata = FileMetadata::from_json(r#"{"size": 12, "content": "a\nb\n"}"#)?;
+
+    let value = evaluate(
+        "size > 10 AND content.lines().length() = 2",
+        Path::new("/no/such/file.txt"),
+        metadata,
+    )?;
+
+    assert_eq!(value, Value::Bool(true));
+    Ok(())
+}
+
+#[test]
+fn expression_ast_returns_json() -> Result<(), FindItError> {
+    let ast = expression_ast("name")?;
+
+    assert_eq!(ast, r#"{"Access":"Name"}"#);
+    Ok(())
+}